@@ -20,8 +20,8 @@ use serde::Serialize;
 use std::ops::Deref;
 
 /// Wrapper type over [`axum::Json`] that validates
-/// requests and responds with a more helpful validation
-/// message.
+/// requests, awaiting the async validations such as `#[validate(custom_async(...))]`,
+/// and responds with a more helpful validation message.
 pub struct Json<T>(pub T);
 
 impl<T> Deref for Json<T> {
@@ -147,7 +147,72 @@ mod test {
                 {
                     "error": "The length of the value must be `<= 3`.",
                     "instance_location": "/name",
-                    "keyword_location": null
+                    "keyword_location": "/properties/name/maxLength"
+                }
+            ]})
+        );
+
+        Ok(())
+    }
+
+    #[cfg(all(not(feature = "jsonschema"), not(feature = "aide")))]
+    #[tokio::test]
+    async fn test_json_with_custom_async() -> TestResult {
+        use axum::{routing::post, Router};
+        use std::sync::Mutex;
+
+        // In-memory stand-in for the database, shared by the validator and the handler.
+        static USER_NAMES: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+        async fn unique_name(name: &String) -> Result<(), serde_valid::validation::Error> {
+            if USER_NAMES.lock().unwrap().contains(name) {
+                Err(serde_valid::validation::Error::Custom(
+                    "The name is already taken.".to_string(),
+                ))
+            } else {
+                Ok(())
+            }
+        }
+
+        #[derive(Deserialize, Validate)]
+        struct User {
+            #[validate(custom_async(unique_name))]
+            name: String,
+        }
+
+        let app = Router::new().route(
+            "/json",
+            post(|user: Json<User>| async move {
+                USER_NAMES.lock().unwrap().push(user.0.name);
+                "hello"
+            }),
+        );
+
+        let body = serde_json::to_vec(&json!({"name": "taro"}))?;
+        let request = || {
+            Request::builder()
+                .method(http::Method::POST)
+                .uri("/json")
+                .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                .body(Body::from(body.clone()))
+        };
+
+        let response = app.clone().oneshot(request()?).await?;
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = app.oneshot(request()?).await?;
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(
+                &axum::body::to_bytes(response.into_body(), 1_000_000).await?,
+            )?,
+            json!({"errors": [
+                {
+                    "error": "The name is already taken.",
+                    "instance_location": "/name",
+                    "keyword_location": "/properties/name/custom"
                 }
             ]})
         );
@@ -187,7 +252,7 @@ mod test {
                 {
                     "error": "The length of the value must be `<= 3`.",
                     "instance_location": "/name",
-                    "keyword_location": null
+                    "keyword_location": "/properties/name/maxLength"
                 }
             ]})
         );
@@ -227,7 +292,7 @@ mod test {
                 {
                     "error": "The length of the value must be `<= 3`.",
                     "instance_location": "/name",
-                    "keyword_location": null
+                    "keyword_location": "/properties/name/maxLength"
                 }
             ]})
         );
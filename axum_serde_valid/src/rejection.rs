@@ -1,6 +1,6 @@
 use axum::{extract::rejection::JsonRejection, http::StatusCode, response::IntoResponse};
 use serde::Serialize;
-use serde_valid::flatten::IntoFlat;
+use serde_valid::validation::{Output, OutputFormat, ToOutput};
 
 use crate::json_pointer::JsonPointer;
 
@@ -73,10 +73,14 @@ impl From<Rejection> for JsonErrorResponse {
             Rejection::SerdeJson(error) => Self::FormatError(error.to_string()),
             Rejection::SerdeUrlEncoded(error) => Self::FormatError(error.to_string()),
             Rejection::SerdeValid(errors) => {
-                let iter = errors.into_flat().into_iter().map(|err| Error {
-                    error: err.error,
-                    instance_location: JsonPointer(err.instance_location.to_string()),
-                    keyword_location: None,
+                let units = match errors.to_output(OutputFormat::Basic) {
+                    Output::Unit(unit) => unit.errors,
+                    Output::Flag { .. } => vec![],
+                };
+                let iter = units.into_iter().map(|unit| Error {
+                    error: unit.error.unwrap_or_default(),
+                    instance_location: JsonPointer(unit.instance_location),
+                    keyword_location: Some(JsonPointer(unit.keyword_location)),
                 });
 
                 Self::ValidationError(JsonSchemaErrorResponse {
@@ -24,7 +24,8 @@ where
 
     match serde_json::from_value::<T>(value) {
         Ok(v) => {
-            v.validate()
+            v.validate_async()
+                .await
                 .map_err(crate::rejection::Rejection::SerdeValid)?;
 
             Ok(v)
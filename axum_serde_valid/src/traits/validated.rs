@@ -1,19 +1,33 @@
 #[cfg(not(any(feature = "jsonschema", feature = "aide")))]
 mod deserialize {
-    pub trait Deserialize: serde::de::DeserializeOwned + serde_valid::Validate {}
+    pub trait Deserialize:
+        serde::de::DeserializeOwned + serde_valid::AsyncValidate + Send + Sync
+    {
+    }
 
-    impl<T> Deserialize for T where T: serde::de::DeserializeOwned + serde_valid::Validate {}
+    impl<T> Deserialize for T where
+        T: serde::de::DeserializeOwned + serde_valid::AsyncValidate + Send + Sync
+    {
+    }
 }
 
 #[cfg(any(feature = "jsonschema", feature = "aide"))]
 mod deserialize {
     pub trait Deserialize:
-        serde::de::DeserializeOwned + serde_valid::Validate + schemars::JsonSchema
+        serde::de::DeserializeOwned
+        + serde_valid::AsyncValidate
+        + Send
+        + Sync
+        + schemars::JsonSchema
     {
     }
 
     impl<T> Deserialize for T where
-        T: serde::de::DeserializeOwned + serde_valid::Validate + schemars::JsonSchema
+        T: serde::de::DeserializeOwned
+            + serde_valid::AsyncValidate
+            + Send
+            + Sync
+            + schemars::JsonSchema
     {
     }
 }
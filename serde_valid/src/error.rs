@@ -1,8 +1,8 @@
 use itertools::Itertools;
 use serde_valid_literal::Literal;
 
-use crate::validation::error::FormatDefault;
-use crate::validation::Number;
+use crate::validation::error::{FormatDefault, ItemVecErrorsMap};
+use crate::validation::{ComparisonOperator, JsonPointer, Number, PathChunk, StringFormat};
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error<E>
@@ -10,7 +10,7 @@ where
     E: 'static + std::error::Error,
 {
     #[error(transparent)]
-    DeserializeError(#[from] E),
+    DeserializeError(DeserializeError<E>),
 
     #[error(transparent)]
     ValidationError(crate::validation::Errors<crate::validation::Error>),
@@ -29,7 +29,15 @@ where
 
     pub fn as_serde_error(&self) -> Option<&E> {
         match self {
-            Self::DeserializeError(error) => Some(error),
+            Self::DeserializeError(error) => Some(error.inner()),
+            Self::ValidationError(_) => None,
+        }
+    }
+
+    /// The location where the deserialization failed.
+    pub fn as_serde_error_path(&self) -> Option<&JsonPointer> {
+        match self {
+            Self::DeserializeError(error) => Some(error.path()),
             Self::ValidationError(_) => None,
         }
     }
@@ -49,6 +57,158 @@ where
     }
 }
 
+impl<E> From<E> for Error<E>
+where
+    E: 'static + std::error::Error,
+{
+    fn from(error: E) -> Self {
+        Self::DeserializeError(DeserializeError::new(JsonPointer::default(), error))
+    }
+}
+
+impl<E> From<DeserializeError<E>> for Error<E>
+where
+    E: 'static + std::error::Error,
+{
+    fn from(error: DeserializeError<E>) -> Self {
+        Self::DeserializeError(error)
+    }
+}
+
+/// The deserialization error with the location where it failed.
+///
+/// ```rust
+/// use serde::Deserialize;
+/// use serde_valid::Validate;
+/// use serde_valid::json::{json, FromJsonValue};
+///
+/// #[derive(Debug, Validate, Deserialize)]
+/// struct TestStruct {
+///     items: Vec<i32>,
+/// }
+///
+/// let err = TestStruct::from_json_value(json!({ "items": [1, "2"] })).unwrap_err();
+///
+/// assert_eq!(err.as_serde_error_path().unwrap().to_string(), "/items/1");
+/// ```
+#[derive(Debug, thiserror::Error)]
+#[error("{inner}")]
+pub struct DeserializeError<E>
+where
+    E: 'static + std::error::Error,
+{
+    path: JsonPointer,
+    #[source]
+    inner: E,
+}
+
+impl<E> DeserializeError<E>
+where
+    E: 'static + std::error::Error,
+{
+    pub fn new(path: JsonPointer, inner: E) -> Self {
+        Self { path, inner }
+    }
+
+    pub fn path(&self) -> &JsonPointer {
+        &self.path
+    }
+
+    pub fn inner(&self) -> &E {
+        &self.inner
+    }
+
+    pub fn into_inner(self) -> E {
+        self.inner
+    }
+}
+
+impl<E> From<serde_path_to_error::Error<E>> for DeserializeError<E>
+where
+    E: 'static + std::error::Error,
+{
+    fn from(error: serde_path_to_error::Error<E>) -> Self {
+        let path = json_pointer(error.path());
+
+        Self::new(path, error.into_inner())
+    }
+}
+
+fn json_pointer(path: &serde_path_to_error::Path) -> JsonPointer {
+    path.iter()
+        .filter_map(|segment| match segment {
+            serde_path_to_error::Segment::Seq { index } => Some(PathChunk::from(*index)),
+            serde_path_to_error::Segment::Map { key } => Some(PathChunk::from(key.as_str())),
+            serde_path_to_error::Segment::Enum { variant } => {
+                Some(PathChunk::from(variant.as_str()))
+            }
+            serde_path_to_error::Segment::Unknown => None,
+        })
+        .collect()
+}
+
+/// Deserialize with tracking the location where it fails.
+pub(crate) fn deserialize_with_path<'de, T, D>(
+    deserializer: D,
+) -> Result<T, DeserializeError<D::Error>>
+where
+    T: serde::Deserialize<'de>,
+    D: serde::Deserializer<'de>,
+    D::Error: 'static,
+{
+    serde_path_to_error::deserialize(deserializer).map_err(DeserializeError::from)
+}
+
+#[cfg(feature = "cbor")]
+thread_local! {
+    static TRACKED_PATH: std::cell::RefCell<JsonPointer> = std::cell::RefCell::new(JsonPointer::default());
+}
+
+/// Deserialize with tracking the location where it fails,
+/// for the formats which do not expose their deserializers, such as CBOR.
+///
+/// `deserialize` must deserialize [`TrackPath<T>`] instead of `T`.
+#[cfg(feature = "cbor")]
+pub(crate) fn deserialize_owned_with_path<T, E>(
+    deserialize: impl FnOnce() -> Result<TrackPath<T>, E>,
+) -> Result<T, DeserializeError<E>>
+where
+    E: 'static + std::error::Error,
+{
+    TRACKED_PATH.with(|path| path.take());
+    deserialize()
+        .map(|TrackPath(model)| model)
+        .map_err(|error| DeserializeError::new(TRACKED_PATH.with(|path| path.take()), error))
+}
+
+/// The wrapper which records the location where the deserialization of `T` fails.
+#[cfg(feature = "cbor")]
+pub(crate) struct TrackPath<T>(T);
+
+#[cfg(feature = "cbor")]
+impl<'de, T> serde::Deserialize<'de> for TrackPath<T>
+where
+    T: serde::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let mut track = serde_path_to_error::Track::new();
+        match T::deserialize(serde_path_to_error::Deserializer::new(
+            deserializer,
+            &mut track,
+        )) {
+            Ok(model) => Ok(Self(model)),
+            Err(error) => {
+                let path = json_pointer(&track.path());
+                TRACKED_PATH.with(|tracked_path| tracked_path.replace(path));
+                Err(error)
+            }
+        }
+    }
+}
+
 macro_rules! struct_error_params {
     (
         #[derive(Debug, Clone)]
@@ -195,6 +355,14 @@ struct_error_params!(
     }
 );
 
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "The value must match the format of \"{}\"."]
+    pub struct FormatError {
+        pub format: StringFormat,
+    }
+);
+
 // Array
 struct_error_params!(
     #[derive(Debug, Clone)]
@@ -218,6 +386,39 @@ struct_error_params!(
     pub struct UniqueItemsError;
 );
 
+#[derive(Debug, Clone)]
+pub struct ContainsError {
+    pub min_contains: usize,
+    pub max_contains: Option<usize>,
+    pub matched: usize,
+}
+
+impl ContainsError {
+    pub fn new(min_contains: usize, max_contains: Option<usize>, matched: usize) -> Self {
+        Self {
+            min_contains,
+            max_contains,
+            matched,
+        }
+    }
+}
+
+impl FormatDefault for ContainsError {
+    #[inline]
+    fn format_default(&self) -> String {
+        match self.max_contains {
+            Some(max_contains) if self.matched > max_contains => format!(
+                "The items must contain `<= {}` matching items, but `{}` matched.",
+                max_contains, self.matched
+            ),
+            _ => format!(
+                "The items must contain `>= {}` matching items, but `{}` matched.",
+                self.min_contains, self.matched
+            ),
+        }
+    }
+}
+
 // Object
 struct_error_params!(
     #[derive(Debug, Clone)]
@@ -243,3 +444,257 @@ struct_error_params!(
         pub enumerate: Vec<Literal>,
     }
 );
+
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "The value must be `{}`."]
+    pub struct ConstError {
+        pub const_value: Literal,
+    }
+);
+
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "The value is required."]
+    pub struct RequiredError;
+);
+
+#[derive(Debug, Clone)]
+pub struct FieldComparisonError {
+    pub operator: ComparisonOperator,
+    pub other_field: String,
+    /// The value of the annotated field, which is `None` when redacted.
+    pub value: Option<serde_json::Value>,
+    /// The value of the other field, which is `None` when redacted.
+    pub other_value: Option<serde_json::Value>,
+}
+
+impl FieldComparisonError {
+    pub fn new(operator: impl Into<ComparisonOperator>, other_field: impl Into<String>) -> Self {
+        Self {
+            operator: operator.into(),
+            other_field: other_field.into(),
+            value: None,
+            other_value: None,
+        }
+    }
+
+    /// Record both values, serialized as JSON.
+    ///
+    /// The value which cannot be serialized as JSON is not recorded.
+    pub fn with_values<T, U>(mut self, value: &T, other_value: &U) -> Self
+    where
+        T: serde::Serialize + ?Sized,
+        U: serde::Serialize + ?Sized,
+    {
+        self.value = serde_json::to_value(value).ok();
+        self.other_value = serde_json::to_value(other_value).ok();
+        self
+    }
+}
+
+impl FormatDefault for FieldComparisonError {
+    #[inline]
+    fn format_default(&self) -> String {
+        format!(
+            "The value must be `{} {}`.",
+            self.operator, self.other_field
+        )
+    }
+}
+
+// Combinator
+fn format_branch_errors(errors: &ItemVecErrorsMap<crate::validation::Error>) -> String {
+    errors
+        .iter()
+        .map(|(index, errors)| format!(" [{index}] {}", errors.iter().join(" ")))
+        .join("")
+}
+
+#[derive(Debug, Clone)]
+pub struct AnyOfError {
+    pub errors: ItemVecErrorsMap<crate::validation::Error>,
+}
+
+impl AnyOfError {
+    pub fn new(errors: ItemVecErrorsMap<crate::validation::Error>) -> Self {
+        Self { errors }
+    }
+}
+
+impl FormatDefault for AnyOfError {
+    #[inline]
+    fn format_default(&self) -> String {
+        format!(
+            "The value must satisfy any of the validations.{}",
+            format_branch_errors(&self.errors)
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AllOfError {
+    pub errors: ItemVecErrorsMap<crate::validation::Error>,
+}
+
+impl AllOfError {
+    pub fn new(errors: ItemVecErrorsMap<crate::validation::Error>) -> Self {
+        Self { errors }
+    }
+}
+
+impl FormatDefault for AllOfError {
+    #[inline]
+    fn format_default(&self) -> String {
+        format!(
+            "The value must satisfy all of the validations.{}",
+            format_branch_errors(&self.errors)
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct OneOfError {
+    pub errors: ItemVecErrorsMap<crate::validation::Error>,
+    pub valid_branches: Vec<usize>,
+}
+
+impl OneOfError {
+    pub fn new(
+        errors: ItemVecErrorsMap<crate::validation::Error>,
+        valid_branches: Vec<usize>,
+    ) -> Self {
+        Self {
+            errors,
+            valid_branches,
+        }
+    }
+}
+
+impl FormatDefault for OneOfError {
+    #[inline]
+    fn format_default(&self) -> String {
+        if self.valid_branches.is_empty() {
+            format!(
+                "The value must satisfy exactly one of the validations.{}",
+                format_branch_errors(&self.errors)
+            )
+        } else {
+            format!(
+                "The value must satisfy exactly one of the validations, but [{}] are satisfied.",
+                self.valid_branches.iter().join(", ")
+            )
+        }
+    }
+}
+
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "The value must not satisfy the validation."]
+    pub struct NotError;
+);
+
+// Options
+#[derive(Debug, Clone)]
+pub struct TruncatedError {
+    pub max_errors: Option<usize>,
+    pub max_error_items: Option<usize>,
+}
+
+impl TruncatedError {
+    pub fn new(max_errors: Option<usize>, max_error_items: Option<usize>) -> Self {
+        Self {
+            max_errors,
+            max_error_items,
+        }
+    }
+}
+
+impl FormatDefault for TruncatedError {
+    #[inline]
+    fn format_default(&self) -> String {
+        match (self.max_errors, self.max_error_items) {
+            (Some(max_errors), _) => {
+                format!("The validation was stopped after `{max_errors}` errors.")
+            }
+            (None, Some(max_error_items)) => {
+                format!("The validation was stopped after `{max_error_items}` items with errors.")
+            }
+            (None, None) => "The validation was stopped.".to_string(),
+        }
+    }
+}
+
+// Deserialization
+#[derive(Debug, Clone)]
+pub struct TypeError {
+    pub expected: String,
+    pub actual: String,
+}
+
+impl TypeError {
+    pub fn new(expected: impl Into<String>, actual: impl Into<String>) -> Self {
+        Self {
+            expected: expected.into(),
+            actual: actual.into(),
+        }
+    }
+}
+
+impl FormatDefault for TypeError {
+    #[inline]
+    fn format_default(&self) -> String {
+        format!(
+            "The value must be of type `{}`, but `{}` was given.",
+            self.expected, self.actual
+        )
+    }
+}
+
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "{}"]
+    pub struct InvalidValueError {
+        pub message: String,
+    }
+);
+
+// Custom
+#[derive(Debug, Clone)]
+pub struct CustomError {
+    pub code: String,
+    pub message: String,
+    pub params: serde_json::Map<String, serde_json::Value>,
+}
+
+impl CustomError {
+    pub fn new(code: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            code: code.into(),
+            message: message.into(),
+            params: serde_json::Map::new(),
+        }
+    }
+
+    pub fn with_param(
+        mut self,
+        key: impl Into<String>,
+        value: impl Into<serde_json::Value>,
+    ) -> Self {
+        self.params.insert(key.into(), value.into());
+        self
+    }
+}
+
+impl FormatDefault for CustomError {
+    #[inline]
+    fn format_default(&self) -> String {
+        self.message.to_string()
+    }
+}
+
+impl std::fmt::Display for CustomError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.format_default())
+    }
+}
@@ -1,9 +1,27 @@
+#[cfg(feature = "cbor")]
+pub mod cbor;
+
+#[cfg(feature = "csv")]
+pub mod csv;
+
+#[cfg(feature = "env")]
+pub mod env;
+
 #[cfg(feature = "flatten")]
 pub mod flatten;
 
 #[cfg(feature = "fluent")]
 pub mod fluent;
 
+#[cfg(feature = "json5")]
+pub mod json5;
+
+#[cfg(feature = "msgpack")]
+pub mod msgpack;
+
+#[cfg(feature = "ron")]
+pub mod ron;
+
 #[cfg(feature = "toml")]
 pub mod toml;
 
@@ -0,0 +1,14 @@
+mod from_cbor_reader;
+mod from_cbor_slice;
+mod to_cbor_vec;
+mod to_cbor_writer;
+
+pub use ciborium::Value;
+
+pub type DecodeError = ciborium::de::Error<std::io::Error>;
+pub type EncodeError = ciborium::ser::Error<std::io::Error>;
+
+pub use from_cbor_reader::FromCborReader;
+pub use from_cbor_slice::FromCborSlice;
+pub use to_cbor_vec::ToCborVec;
+pub use to_cbor_writer::ToCborWriter;
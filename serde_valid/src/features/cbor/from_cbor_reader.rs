@@ -0,0 +1,42 @@
+use super::DecodeError;
+
+pub trait FromCborReader
+where
+    Self: Sized,
+{
+    /// Convert from CBOR reader.
+    ///
+    /// ```rust
+    /// use serde::{Deserialize, Serialize};
+    /// use serde_valid::Validate;
+    /// use serde_valid::cbor::{FromCborReader, ToCborVec};
+    ///
+    /// #[derive(Debug, Validate, Deserialize, Serialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 2000)]
+    ///     val: i32,
+    /// }
+    ///
+    /// let bytes = TestStruct { val: 1234 }.to_cbor_vec().unwrap();
+    /// let s = TestStruct::from_cbor_reader(bytes.as_slice());
+    ///
+    /// assert!(s.is_ok())
+    /// ```
+    fn from_cbor_reader<R>(reader: R) -> Result<Self, crate::Error<DecodeError>>
+    where
+        R: std::io::Read;
+}
+
+impl<T> FromCborReader for T
+where
+    T: serde::de::DeserializeOwned + crate::Validate,
+{
+    fn from_cbor_reader<R>(reader: R) -> Result<Self, crate::Error<DecodeError>>
+    where
+        R: std::io::Read,
+    {
+        let model: T = crate::error::deserialize_owned_with_path(|| ciborium::from_reader(reader))?;
+        model.validate().map_err(crate::Error::ValidationError)?;
+        Ok(model)
+    }
+}
@@ -0,0 +1,37 @@
+use super::DecodeError;
+
+pub trait FromCborSlice
+where
+    Self: Sized,
+{
+    /// Convert from CBOR slice.
+    ///
+    /// ```rust
+    /// use serde::{Deserialize, Serialize};
+    /// use serde_valid::Validate;
+    /// use serde_valid::cbor::{FromCborSlice, ToCborVec};
+    ///
+    /// #[derive(Debug, Validate, Deserialize, Serialize)]
+    /// struct TestStruct {
+    ///     #[validate(min_length = 1)]
+    ///     val: String,
+    /// }
+    ///
+    /// let slice = TestStruct { val: "abcde".to_string() }.to_cbor_vec().unwrap();
+    /// let s = TestStruct::from_cbor_slice(&slice);
+    ///
+    /// assert!(s.is_ok())
+    /// ```
+    fn from_cbor_slice(slice: &[u8]) -> Result<Self, crate::Error<DecodeError>>;
+}
+
+impl<T> FromCborSlice for T
+where
+    T: serde::de::DeserializeOwned + crate::Validate,
+{
+    fn from_cbor_slice(slice: &[u8]) -> Result<Self, crate::Error<DecodeError>> {
+        let model: T = crate::error::deserialize_owned_with_path(|| ciborium::from_reader(slice))?;
+        model.validate().map_err(crate::Error::ValidationError)?;
+        Ok(model)
+    }
+}
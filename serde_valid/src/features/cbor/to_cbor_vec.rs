@@ -0,0 +1,40 @@
+use super::EncodeError;
+
+pub trait ToCborVec {
+    /// Convert to CBOR bytes.
+    ///
+    /// ```rust
+    /// use serde::Serialize;
+    /// use serde_valid::cbor::ToCborVec;
+    /// use serde_valid::Validate;
+    ///
+    /// #[derive(Debug, Validate, Serialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 100)]
+    ///     val: i32,
+    /// }
+    /// let s = TestStruct { val: 10 };
+    ///
+    /// assert_eq!(s.to_cbor_vec().unwrap(), b"\xa1\x63val\x0a");
+    /// ```
+    fn to_cbor_vec(&self) -> Result<Vec<u8>, EncodeError>;
+}
+
+impl<T> ToCborVec for T
+where
+    T: serde::Serialize + crate::Validate,
+{
+    fn to_cbor_vec(&self) -> Result<Vec<u8>, EncodeError> {
+        let mut vec = Vec::new();
+        ciborium::into_writer(self, &mut vec)?;
+        Ok(vec)
+    }
+}
+
+impl ToCborVec for ciborium::Value {
+    fn to_cbor_vec(&self) -> Result<Vec<u8>, EncodeError> {
+        let mut vec = Vec::new();
+        ciborium::into_writer(self, &mut vec)?;
+        Ok(vec)
+    }
+}
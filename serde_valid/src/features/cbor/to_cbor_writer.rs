@@ -0,0 +1,45 @@
+use super::EncodeError;
+
+pub trait ToCborWriter {
+    /// Convert to CBOR writer.
+    ///
+    /// ```rust
+    /// use serde::Serialize;
+    /// use serde_valid::cbor::ToCborWriter;
+    /// use serde_valid::Validate;
+    ///
+    /// #[derive(Debug, Validate, Serialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 100)]
+    ///     val: i32,
+    /// }
+    /// let s = TestStruct { val: 10 };
+    /// let mut writer = Vec::new();
+    ///
+    /// assert!(s.to_cbor_writer(&mut writer).is_ok());
+    /// ```
+    fn to_cbor_writer<W>(&self, writer: W) -> Result<(), EncodeError>
+    where
+        W: std::io::Write;
+}
+
+impl<T> ToCborWriter for T
+where
+    T: serde::Serialize + crate::Validate,
+{
+    fn to_cbor_writer<W>(&self, writer: W) -> Result<(), EncodeError>
+    where
+        W: std::io::Write,
+    {
+        ciborium::into_writer(self, writer)
+    }
+}
+
+impl ToCborWriter for ciborium::Value {
+    fn to_cbor_writer<W>(&self, writer: W) -> Result<(), EncodeError>
+    where
+        W: std::io::Write,
+    {
+        ciborium::into_writer(self, writer)
+    }
+}
@@ -0,0 +1,7 @@
+mod csv_error;
+mod from_csv_reader;
+
+pub use csv::{Reader, ReaderBuilder, StringRecord};
+
+pub use csv_error::{CsvColumnError, CsvError};
+pub use from_csv_reader::{CsvRecords, FromCsvReader};
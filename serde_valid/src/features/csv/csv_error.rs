@@ -0,0 +1,123 @@
+use crate::validation::PathChunk;
+
+/// The error of a CSV row, addressed by the row number.
+///
+/// The row number counts the header row, as spreadsheets show it.
+#[derive(Debug, thiserror::Error)]
+pub struct CsvError {
+    row: u64,
+    columns: Vec<CsvColumnError>,
+    #[source]
+    error: crate::Error<csv::Error>,
+}
+
+impl CsvError {
+    pub(crate) fn new(row: u64, error: crate::Error<csv::Error>) -> Self {
+        let columns = match &error {
+            crate::Error::DeserializeError(error) => vec![CsvColumnError {
+                column: error
+                    .path()
+                    .chunks()
+                    .first()
+                    .map(|chunk| chunk.token().into()),
+                message: match error.inner().kind() {
+                    csv::ErrorKind::Deserialize { err, .. } => err.kind().to_string(),
+                    _ => error.inner().to_string(),
+                },
+            }],
+            crate::Error::ValidationError(errors) => errors
+                .iter()
+                .map(|(location, error)| CsvColumnError {
+                    column: location.chunks().first().map(|chunk| chunk.token().into()),
+                    message: error.to_string(),
+                })
+                .collect(),
+        };
+
+        Self {
+            row,
+            columns,
+            error,
+        }
+    }
+
+    /// The row number, starting from 1.
+    pub fn row(&self) -> u64 {
+        self.row
+    }
+
+    /// The errors with their column names.
+    pub fn columns(&self) -> &[CsvColumnError] {
+        &self.columns
+    }
+
+    pub fn as_error(&self) -> &crate::Error<csv::Error> {
+        &self.error
+    }
+
+    pub fn into_error(self) -> crate::Error<csv::Error> {
+        self.error
+    }
+}
+
+impl std::fmt::Display for CsvError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, column) in self.columns.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "row {}", self.row)?;
+            match &column.column {
+                Some(name) => write!(f, ", column `{}`: {}", name, column.message)?,
+                None => write!(f, ": {}", column.message)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The error of a CSV column.
+///
+/// The column is `None` when the error is not about a specific column,
+/// such as the unequal number of the fields.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvColumnError {
+    column: Option<String>,
+    message: String,
+}
+
+impl CsvColumnError {
+    /// The header name.
+    ///
+    /// Without the headers, the deserialization errors are addressed by the field index.
+    pub fn column(&self) -> Option<&str> {
+        self.column.as_deref()
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+/// The location of the column where the CSV error occurred.
+pub(crate) fn column_location(
+    error: &csv::Error,
+    headers: Option<&csv::StringRecord>,
+) -> crate::validation::JsonPointer {
+    let field = match error.kind() {
+        csv::ErrorKind::Deserialize { err, .. } => err.field(),
+        csv::ErrorKind::Utf8 { err, .. } => Some(err.field() as u64),
+        _ => None,
+    };
+
+    field
+        .map(|field| {
+            let column = headers
+                .and_then(|headers| headers.get(field as usize))
+                .map(ToString::to_string)
+                .unwrap_or_else(|| field.to_string());
+            PathChunk::from(column)
+        })
+        .into_iter()
+        .collect()
+}
@@ -0,0 +1,144 @@
+use std::marker::PhantomData;
+
+use super::csv_error::{column_location, CsvError};
+
+pub trait FromCsvReader
+where
+    Self: Sized,
+{
+    /// Convert from csv reader, row by row.
+    ///
+    /// ```rust
+    /// use serde::Deserialize;
+    /// use serde_valid::Validate;
+    /// use serde_valid::csv::{FromCsvReader, Reader};
+    ///
+    /// #[derive(Debug, Validate, Deserialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 2000)]
+    ///     val: i32,
+    /// }
+    ///
+    /// let mut reader = Reader::from_reader("val\n1234\n5678\n".as_bytes());
+    /// let mut rows = TestStruct::from_csv_reader(&mut reader);
+    ///
+    /// assert!(rows.next().unwrap().is_ok());
+    /// assert_eq!(
+    ///     rows.next().unwrap().unwrap_err().to_string(),
+    ///     "row 3, column `val`: The number must be `<= 2000`."
+    /// );
+    /// assert!(rows.next().is_none());
+    /// ```
+    fn from_csv_reader<R>(reader: &mut csv::Reader<R>) -> CsvRecords<'_, R, Self>
+    where
+        R: std::io::Read;
+}
+
+impl<T> FromCsvReader for T
+where
+    T: serde::de::DeserializeOwned + crate::Validate,
+{
+    fn from_csv_reader<R>(reader: &mut csv::Reader<R>) -> CsvRecords<'_, R, Self>
+    where
+        R: std::io::Read,
+    {
+        CsvRecords {
+            reader,
+            headers: None,
+            record: csv::StringRecord::new(),
+            finished: false,
+            _model: PhantomData,
+        }
+    }
+}
+
+/// The iterator of the validated rows of the csv reader.
+///
+/// The rows which fail are reported and skipped, while the I/O errors end the iteration.
+pub struct CsvRecords<'r, R, T> {
+    reader: &'r mut csv::Reader<R>,
+    headers: Option<csv::StringRecord>,
+    record: csv::StringRecord,
+    finished: bool,
+    _model: PhantomData<fn() -> T>,
+}
+
+impl<R, T> Iterator for CsvRecords<'_, R, T>
+where
+    R: std::io::Read,
+    T: serde::de::DeserializeOwned + crate::Validate,
+{
+    type Item = Result<T, CsvError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        if self.headers.is_none() && self.reader.has_headers() {
+            match self.reader.headers() {
+                Ok(headers) => self.headers = Some(headers.clone()),
+                Err(error) => return Some(Err(self.fail(error))),
+            }
+        }
+
+        match self.reader.read_record(&mut self.record) {
+            Ok(true) => {}
+            Ok(false) => {
+                self.finished = true;
+                return None;
+            }
+            Err(error) => {
+                if error.is_io_error() {
+                    return Some(Err(self.fail(error)));
+                }
+                let row = error
+                    .position()
+                    .map_or(self.reader.position().record(), |position| {
+                        position.record()
+                    });
+                let location = column_location(&error, self.headers.as_ref());
+                return Some(Err(CsvError::new(
+                    row + 1,
+                    crate::DeserializeError::new(location, error).into(),
+                )));
+            }
+        }
+
+        let row = self
+            .record
+            .position()
+            .map_or(0, |position| position.record())
+            + 1;
+
+        Some(
+            validate_record(&self.record, self.headers.as_ref())
+                .map_err(|error| CsvError::new(row, error)),
+        )
+    }
+}
+
+impl<R, T> CsvRecords<'_, R, T>
+where
+    R: std::io::Read,
+{
+    /// End the iteration with the error which cannot be recovered.
+    fn fail(&mut self, error: csv::Error) -> CsvError {
+        self.finished = true;
+        CsvError::new(self.reader.position().record() + 1, error.into())
+    }
+}
+
+fn validate_record<T>(
+    record: &csv::StringRecord,
+    headers: Option<&csv::StringRecord>,
+) -> Result<T, crate::Error<csv::Error>>
+where
+    T: serde::de::DeserializeOwned + crate::Validate,
+{
+    let model: T = record
+        .deserialize(headers)
+        .map_err(|error| crate::DeserializeError::new(column_location(&error, headers), error))?;
+    model.validate().map_err(crate::Error::ValidationError)?;
+    Ok(model)
+}
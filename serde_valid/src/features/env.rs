@@ -0,0 +1,9 @@
+mod deserializer;
+mod env_error;
+mod env_options;
+mod from_env;
+
+pub use deserializer::Error;
+pub use env_error::{EnvError, EnvVarError};
+pub use env_options::EnvOptions;
+pub use from_env::FromEnv;
@@ -0,0 +1,303 @@
+use std::collections::BTreeMap;
+
+use serde::de::value::{MapAccessDeserializer, MapDeserializer, SeqDeserializer};
+use serde::de::{Error as _, IntoDeserializer, Unexpected, Visitor};
+use serde::forward_to_deserialize_any;
+
+use super::EnvOptions;
+use crate::validation::JsonPointer;
+
+/// The deserialization error of the environment variables.
+#[derive(Debug, thiserror::Error)]
+#[error("{0}")]
+pub struct Error(String);
+
+impl serde::de::Error for Error {
+    fn custom<T: std::fmt::Display>(msg: T) -> Self {
+        Self(msg.to_string())
+    }
+}
+
+/// The tree of the environment variables, split into the nested keys.
+///
+/// The values are parsed when the type is known, so `APP_PORT=8080` is a number for `u16`
+/// and a string for `String`. The sequences are the comma-separated values,
+/// or the nested keys of the indices, such as `APP_HOSTS__0`.
+#[derive(Debug, Default)]
+pub(crate) struct Node {
+    value: Option<String>,
+    children: BTreeMap<String, Node>,
+}
+
+impl Node {
+    pub(crate) fn from_vars<I, K, V>(vars: I, options: &EnvOptions) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        let mut root = Self::default();
+        for (name, value) in vars {
+            if let Some(keys) = options.keys(&name.into()) {
+                let mut node = &mut root;
+                for key in keys {
+                    node = node.child_mut(key);
+                }
+                node.value = Some(value.into());
+            }
+        }
+        root
+    }
+
+    /// The keys of the location as written in the variable names.
+    ///
+    /// The keys after a missing one are in uppercase.
+    pub(crate) fn variable_keys(&self, location: &JsonPointer) -> Vec<String> {
+        let mut node = Some(self);
+        location
+            .chunks()
+            .iter()
+            .map(|chunk| {
+                let token = chunk.token();
+                match node.and_then(|node| node.child(&token)) {
+                    Some((key, child)) => {
+                        node = Some(child);
+                        key.clone()
+                    }
+                    None => {
+                        node = None;
+                        token.to_uppercase()
+                    }
+                }
+            })
+            .collect()
+    }
+
+    /// The child of the key, shared with the keys which differ only in case.
+    fn child_mut(&mut self, key: String) -> &mut Node {
+        let key = self
+            .children
+            .keys()
+            .find(|existing| is_same_key(existing, &key))
+            .cloned()
+            .unwrap_or(key);
+        self.children.entry(key).or_default()
+    }
+
+    fn child(&self, name: &str) -> Option<(&String, &Node)> {
+        self.children
+            .get_key_value(name)
+            .or_else(|| self.children.iter().find(|(key, _)| is_same_key(key, name)))
+    }
+
+    /// The children keyed by the serde names, or by the variable keys if none of the names match.
+    fn entries<'a>(
+        &'a self,
+        names: &'static [&'static str],
+    ) -> impl Iterator<Item = (&'a str, &'a Node)> {
+        self.children.iter().map(move |(key, node)| {
+            let name = names
+                .iter()
+                .find(|name| *name == key)
+                .or_else(|| names.iter().find(|name| is_same_key(key, name)))
+                .copied()
+                .unwrap_or(key.as_str());
+            (name, node)
+        })
+    }
+
+    fn leaf<'de, V>(&self, visitor: &V) -> Result<&str, Error>
+    where
+        V: Visitor<'de>,
+    {
+        match &self.value {
+            Some(value) if self.children.is_empty() => Ok(value),
+            _ => Err(Error::invalid_type(Unexpected::Map, visitor)),
+        }
+    }
+}
+
+macro_rules! deserialize_parse {
+    ($($method:ident => $visit:ident,)*) => {
+        $(
+            fn $method<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+            where
+                V: Visitor<'de>,
+            {
+                let value = self.leaf(&visitor)?;
+                match value.parse() {
+                    Ok(parsed) => visitor.$visit(parsed),
+                    Err(_) => Err(Error::invalid_value(Unexpected::Str(value), &visitor)),
+                }
+            }
+        )*
+    };
+}
+
+impl<'de> serde::Deserializer<'de> for &Node {
+    type Error = Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match &self.value {
+            Some(value) if self.children.is_empty() => visitor.visit_str(value),
+            _ => self.deserialize_map(visitor),
+        }
+    }
+
+    deserialize_parse! {
+        deserialize_bool => visit_bool,
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_i128 => visit_i128,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_u128 => visit_u128,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        if self.children.is_empty() {
+            let items = match self.value.as_deref() {
+                Some("") | None => vec![],
+                Some(value) => value
+                    .split(',')
+                    .map(|item| Node {
+                        value: Some(item.trim().to_string()),
+                        children: BTreeMap::new(),
+                    })
+                    .collect(),
+            };
+            return visitor.visit_seq(SeqDeserializer::new(items.iter()));
+        }
+
+        let mut items = self
+            .children
+            .iter()
+            .map(|(key, node)| match key.parse::<usize>() {
+                Ok(index) => Ok((index, node)),
+                Err(_) => Err(Error::invalid_type(Unexpected::Map, &visitor)),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        items.sort_by_key(|(index, _)| *index);
+
+        visitor.visit_seq(SeqDeserializer::new(
+            items.into_iter().map(|(_, node)| node),
+        ))
+    }
+
+    fn deserialize_tuple<V>(self, _len: usize, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_map<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match &self.value {
+            Some(value) if self.children.is_empty() => {
+                Err(Error::invalid_type(Unexpected::Str(value), &visitor))
+            }
+            _ => visitor.visit_map(MapDeserializer::new(
+                self.children
+                    .iter()
+                    .map(|(key, node)| (key.to_lowercase(), node)),
+            )),
+        }
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match &self.value {
+            Some(value) if self.children.is_empty() => {
+                Err(Error::invalid_type(Unexpected::Str(value), &visitor))
+            }
+            _ => visitor.visit_map(MapDeserializer::new(self.entries(fields))),
+        }
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        _name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match &self.value {
+            Some(value) if self.children.is_empty() => {
+                visitor.visit_enum(IntoDeserializer::<Error>::into_deserializer(value.as_str()))
+            }
+            _ => visitor.visit_enum(MapAccessDeserializer::new(MapDeserializer::new(
+                self.entries(variants),
+            ))),
+        }
+    }
+
+    forward_to_deserialize_any! {
+        char str string bytes byte_buf unit unit_struct identifier ignored_any
+    }
+}
+
+fn is_same_key(key: &str, name: &str) -> bool {
+    key.to_lowercase() == name.to_lowercase()
+}
+
+impl<'de, 'a> IntoDeserializer<'de, Error> for &'a Node {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        self
+    }
+}
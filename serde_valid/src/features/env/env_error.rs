@@ -0,0 +1,91 @@
+use super::deserializer::Node;
+use super::EnvOptions;
+
+/// The error of the environment variables, addressed by the variable names.
+#[derive(Debug, thiserror::Error)]
+pub struct EnvError {
+    variables: Vec<EnvVarError>,
+    #[source]
+    error: Box<crate::Error<super::Error>>,
+}
+
+impl EnvError {
+    pub(crate) fn new(
+        error: crate::Error<super::Error>,
+        options: &EnvOptions,
+        node: &Node,
+    ) -> Self {
+        let variables = match &error {
+            crate::Error::DeserializeError(error) => vec![EnvVarError {
+                variable: options.variable(&node.variable_keys(error.path())),
+                message: error.inner().to_string(),
+            }],
+            crate::Error::ValidationError(errors) => errors
+                .iter()
+                .map(|(location, error)| EnvVarError {
+                    variable: options.variable(&node.variable_keys(&location)),
+                    message: error.to_string(),
+                })
+                .collect(),
+        };
+
+        Self {
+            variables,
+            error: Box::new(error),
+        }
+    }
+
+    /// The errors with their variable names.
+    pub fn variables(&self) -> &[EnvVarError] {
+        &self.variables
+    }
+
+    pub fn as_error(&self) -> &crate::Error<super::Error> {
+        &self.error
+    }
+
+    pub fn into_error(self) -> crate::Error<super::Error> {
+        *self.error
+    }
+}
+
+impl std::fmt::Display for EnvError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, variable) in self.variables.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", variable)?;
+        }
+        Ok(())
+    }
+}
+
+/// The error of an environment variable.
+///
+/// The variable is `None` when the error is about the whole settings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EnvVarError {
+    variable: Option<String>,
+    message: String,
+}
+
+impl EnvVarError {
+    /// The variable name, such as `APP_DB__PORT`.
+    pub fn variable(&self) -> Option<&str> {
+        self.variable.as_deref()
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl std::fmt::Display for EnvVarError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.variable {
+            Some(variable) => write!(f, "{}: {}", variable, self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
@@ -0,0 +1,79 @@
+/// Options to map the environment variables to the fields.
+///
+/// The variables with the prefix are split by the separator into the nested keys,
+/// and the keys are matched to the field names of serde case-insensitively,
+/// so that `APP_DB__HOST` is `db.host` with the prefix `APP_`,
+/// and `APP_MAXVALUE` is `maxValue` with `#[serde(rename_all = "camelCase")]`.
+/// The keys of the maps are in lowercase.
+///
+/// ```rust
+/// use serde_valid::env::EnvOptions;
+///
+/// let options = EnvOptions::new().with_prefix("APP_").with_separator("__");
+///
+/// assert_eq!(options.prefix(), "APP_");
+/// assert_eq!(options.separator(), "__");
+/// ```
+#[derive(Debug, Clone)]
+pub struct EnvOptions {
+    prefix: String,
+    separator: String,
+}
+
+impl Default for EnvOptions {
+    fn default() -> Self {
+        Self {
+            prefix: String::new(),
+            separator: "__".to_string(),
+        }
+    }
+}
+
+impl EnvOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Read only the variables with the prefix.
+    pub fn with_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /// Split the variable names into the nested keys by the separator (default `__`).
+    pub fn with_separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    pub fn separator(&self) -> &str {
+        &self.separator
+    }
+
+    /// The nested keys of the variable, or `None` if it does not have the prefix.
+    pub(crate) fn keys(&self, name: &str) -> Option<Vec<String>> {
+        let keys = name
+            .strip_prefix(&self.prefix)?
+            .split(self.separator.as_str())
+            .map(str::to_string)
+            .collect::<Vec<_>>();
+
+        if keys.iter().any(String::is_empty) || self.separator.is_empty() && keys.len() > 1 {
+            return None;
+        }
+        Some(keys)
+    }
+
+    /// The variable name of the nested keys, such as `APP_DB__PORT` for `["DB", "PORT"]`.
+    pub(crate) fn variable(&self, keys: &[String]) -> Option<String> {
+        if keys.is_empty() {
+            return None;
+        }
+
+        Some(format!("{}{}", self.prefix, keys.join(&self.separator)))
+    }
+}
@@ -0,0 +1,77 @@
+use super::deserializer::Node;
+use super::{EnvError, EnvOptions};
+
+pub trait FromEnv
+where
+    Self: Sized,
+{
+    /// Convert from the environment variables of the process.
+    ///
+    /// The variables which are not valid unicode are ignored.
+    fn from_env(options: &EnvOptions) -> Result<Self, EnvError>;
+
+    /// Convert from the environment variables, given in place of the process environment.
+    ///
+    /// ```rust
+    /// use serde::Deserialize;
+    /// use serde_valid::Validate;
+    /// use serde_valid::env::{EnvOptions, FromEnv};
+    ///
+    /// #[derive(Debug, Validate, Deserialize)]
+    /// struct Settings {
+    ///     #[validate]
+    ///     db: Database,
+    /// }
+    ///
+    /// #[derive(Debug, Validate, Deserialize)]
+    /// struct Database {
+    ///     host: String,
+    ///     #[validate(minimum = 1024)]
+    ///     port: u16,
+    /// }
+    ///
+    /// let options = EnvOptions::new().with_prefix("APP_");
+    ///
+    /// let s = Settings::from_env_vars([("APP_DB__HOST", "localhost"), ("APP_DB__PORT", "5432")], &options);
+    /// assert_eq!(s.unwrap().db.port, 5432);
+    ///
+    /// let err = Settings::from_env_vars([("APP_DB__HOST", "localhost"), ("APP_DB__PORT", "80")], &options);
+    /// assert_eq!(err.unwrap_err().to_string(), "APP_DB__PORT: The number must be `>= 1024`.");
+    /// ```
+    fn from_env_vars<I, K, V>(vars: I, options: &EnvOptions) -> Result<Self, EnvError>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>;
+}
+
+impl<T> FromEnv for T
+where
+    T: serde::de::DeserializeOwned + crate::Validate,
+{
+    fn from_env(options: &EnvOptions) -> Result<Self, EnvError> {
+        Self::from_env_vars(
+            std::env::vars_os().filter_map(|(name, value)| {
+                Some((name.into_string().ok()?, value.into_string().ok()?))
+            }),
+            options,
+        )
+    }
+
+    fn from_env_vars<I, K, V>(vars: I, options: &EnvOptions) -> Result<Self, EnvError>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        let node = Node::from_vars(vars, options);
+
+        crate::error::deserialize_with_path(&node)
+            .map_err(crate::Error::from)
+            .and_then(|model: T| {
+                model.validate().map_err(crate::Error::ValidationError)?;
+                Ok(model)
+            })
+            .map_err(|error| EnvError::new(error, options, &node))
+    }
+}
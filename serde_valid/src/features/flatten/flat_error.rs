@@ -1,14 +1,14 @@
-use jsonschema::paths::{JSONPointer, PathChunk};
+use crate::validation::{JsonPointer, PathChunk};
 use serde::Serialize;
 
 #[derive(Debug, PartialEq, Eq, Serialize)]
 pub struct FlatError {
     pub error: String,
-    pub instance_location: JSONPointer,
+    pub instance_location: JsonPointer,
 }
 
 impl FlatError {
-    pub fn new(instance_location: impl Into<JSONPointer>, error: String) -> Self {
+    pub fn new(instance_location: impl Into<JsonPointer>, error: String) -> Self {
         Self {
             error,
             instance_location: instance_location.into(),
@@ -17,7 +17,7 @@ impl FlatError {
 
     pub fn merge_childs(self, instance_location: impl IntoIterator<Item = PathChunk>) -> Self {
         Self::new(
-            JSONPointer::from(
+            JsonPointer::from(
                 instance_location
                     .into_iter()
                     .chain(self.instance_location)
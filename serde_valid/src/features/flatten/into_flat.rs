@@ -1,8 +1,7 @@
-use jsonschema::paths::{JSONPointer, PathChunk};
-
 use crate::validation::error::{
     ArrayErrors, FormatDefault, ItemErrorsMap, Message, ObjectErrors, PropertyErrorsMap,
 };
+use crate::validation::{JsonPointer, PathChunk};
 
 use super::{FlatError, FlatErrors};
 
@@ -11,17 +10,17 @@ where
     Self: Sized,
 {
     fn into_flat(self) -> FlatErrors {
-        self.into_flat_at(&JSONPointer::default())
+        self.into_flat_at(&JsonPointer::default())
     }
 
-    fn into_flat_at(self, path: &JSONPointer) -> FlatErrors;
+    fn into_flat_at(self, path: &JsonPointer) -> FlatErrors;
 }
 
 impl<E> IntoFlat for crate::validation::Errors<E>
 where
     E: IntoFlat,
 {
-    fn into_flat_at(self, path: &JSONPointer) -> FlatErrors {
+    fn into_flat_at(self, path: &JsonPointer) -> FlatErrors {
         match self {
             crate::validation::Errors::Array(errors) => errors.into_flat_at(path),
             crate::validation::Errors::Object(errors) => errors.into_flat_at(path),
@@ -30,8 +29,35 @@ where
     }
 }
 
+impl<E> IntoFlat for crate::Error<E>
+where
+    E: 'static + std::error::Error,
+{
+    fn into_flat_at(self, path: &JsonPointer) -> FlatErrors {
+        match self {
+            crate::Error::DeserializeError(error) => error.into_flat_at(path),
+            crate::Error::ValidationError(errors) => errors.into_flat_at(path),
+        }
+    }
+}
+
+impl<E> IntoFlat for crate::DeserializeError<E>
+where
+    E: 'static + std::error::Error,
+{
+    fn into_flat_at(self, path: &JsonPointer) -> FlatErrors {
+        FlatErrors::new(vec![FlatError::new(
+            path.clone()
+                .into_iter()
+                .chain(self.path().clone())
+                .collect::<JsonPointer>(),
+            self.into_inner().to_string(),
+        )])
+    }
+}
+
 impl IntoFlat for crate::validation::Error {
-    fn into_flat_at(self, path: &JSONPointer) -> FlatErrors {
+    fn into_flat_at(self, path: &JsonPointer) -> FlatErrors {
         match self {
             crate::validation::Error::Minimum(inner) => inner.into_flat_at(path),
             crate::validation::Error::Maximum(inner) => inner.into_flat_at(path),
@@ -41,17 +67,32 @@ impl IntoFlat for crate::validation::Error {
             crate::validation::Error::MinLength(inner) => inner.into_flat_at(path),
             crate::validation::Error::MaxLength(inner) => inner.into_flat_at(path),
             crate::validation::Error::Pattern(inner) => inner.into_flat_at(path),
+            crate::validation::Error::Format(inner) => inner.into_flat_at(path),
             crate::validation::Error::MinItems(inner) => inner.into_flat_at(path),
             crate::validation::Error::MaxItems(inner) => inner.into_flat_at(path),
             crate::validation::Error::UniqueItems(inner) => inner.into_flat_at(path),
+            crate::validation::Error::Contains(inner) => inner.into_flat_at(path),
             crate::validation::Error::MinProperties(inner) => inner.into_flat_at(path),
             crate::validation::Error::MaxProperties(inner) => inner.into_flat_at(path),
             crate::validation::Error::Enumerate(inner) => inner.into_flat_at(path),
+            crate::validation::Error::Const(inner) => inner.into_flat_at(path),
+            crate::validation::Error::Required(inner) => inner.into_flat_at(path),
+            crate::validation::Error::FieldComparison(inner) => (*inner).into_flat_at(path),
+            crate::validation::Error::AnyOf(inner) => (*inner).into_flat_at(path),
+            crate::validation::Error::AllOf(inner) => (*inner).into_flat_at(path),
+            crate::validation::Error::OneOf(inner) => (*inner).into_flat_at(path),
+            crate::validation::Error::Not(inner) => inner.into_flat_at(path),
+            crate::validation::Error::Truncated(inner) => inner.into_flat_at(path),
+            crate::validation::Error::Type(inner) => inner.into_flat_at(path),
+            crate::validation::Error::InvalidValue(inner) => inner.into_flat_at(path),
             crate::validation::Error::Items(inner) => inner.into_flat_at(path),
             crate::validation::Error::Properties(inner) => inner.into_flat_at(path),
             crate::validation::Error::Custom(inner) => {
                 FlatErrors::new(vec![FlatError::new(path.to_owned(), inner)])
             }
+            crate::validation::Error::CustomError(inner) => {
+                FlatErrors::new(vec![FlatError::new(path.to_owned(), inner.to_string())])
+            }
             #[cfg(feature = "fluent")]
             crate::validation::Error::Fluent(inner) => {
                 FlatErrors::new(vec![FlatError::new(path.to_owned(), inner.id.to_string())])
@@ -64,7 +105,7 @@ impl<E> IntoFlat for Vec<E>
 where
     E: IntoFlat,
 {
-    fn into_flat_at(self, path: &JSONPointer) -> FlatErrors {
+    fn into_flat_at(self, path: &JsonPointer) -> FlatErrors {
         FlatErrors::new(self.into_iter().fold(vec![], |pre, error| {
             pre.into_iter()
                 .chain(error.into_flat_at(path))
@@ -77,7 +118,7 @@ impl<E> IntoFlat for ItemErrorsMap<E>
 where
     E: IntoFlat,
 {
-    fn into_flat_at(self, path: &JSONPointer) -> FlatErrors {
+    fn into_flat_at(self, path: &JsonPointer) -> FlatErrors {
         FlatErrors::new(self.into_iter().fold(vec![], |pre, (index, errors)| {
             pre.into_iter()
                 .chain(errors.into_flat().into_iter().map(|e| {
@@ -92,14 +133,14 @@ impl<E> IntoFlat for PropertyErrorsMap<E>
 where
     E: IntoFlat,
 {
-    fn into_flat_at(self, path: &JSONPointer) -> FlatErrors {
+    fn into_flat_at(self, path: &JsonPointer) -> FlatErrors {
         FlatErrors::new(self.into_iter().fold(vec![], |pre, (property, errors)| {
             pre.into_iter()
                 .chain(errors.into_flat().into_iter().map(|error| {
                     error.merge_childs(
                         path.clone()
                             .into_iter()
-                            .chain([PathChunk::Property(property.to_string().into_boxed_str())]),
+                            .chain([PathChunk::from(property.as_str())]),
                     )
                 }))
                 .collect::<Vec<_>>()
@@ -111,7 +152,7 @@ impl<T> IntoFlat for Message<T>
 where
     T: FormatDefault,
 {
-    fn into_flat_at(self, path: &JSONPointer) -> FlatErrors {
+    fn into_flat_at(self, path: &JsonPointer) -> FlatErrors {
         FlatErrors::new(vec![FlatError::new(path.to_owned(), self.to_string())])
     }
 }
@@ -120,7 +161,7 @@ impl<E> IntoFlat for ArrayErrors<E>
 where
     E: IntoFlat,
 {
-    fn into_flat_at(self, path: &JSONPointer) -> FlatErrors {
+    fn into_flat_at(self, path: &JsonPointer) -> FlatErrors {
         FlatErrors::new(
             self.errors
                 .into_flat_at(path)
@@ -135,7 +176,7 @@ impl<E> IntoFlat for ObjectErrors<E>
 where
     E: IntoFlat,
 {
-    fn into_flat_at(self, path: &JSONPointer) -> FlatErrors {
+    fn into_flat_at(self, path: &JsonPointer) -> FlatErrors {
         FlatErrors::new(
             self.errors
                 .into_flat_at(path)
@@ -202,27 +243,27 @@ mod tests {
             .into_flat(),
             FlatErrors::new(vec![
                 FlatError::new(
-                    JSONPointer::default(),
+                    JsonPointer::default(),
                     min_items.format_default(),
                 ),
                 FlatError::new(
-                    JSONPointer::from([PathChunk::from(0)].as_ref()),
+                    JsonPointer::from([PathChunk::from(0)].as_ref()),
                     maximum.format_default(),
                 ),
                 FlatError::new(
-                    JSONPointer::from([PathChunk::from(0), PathChunk::from(2)].as_ref()),
+                    JsonPointer::from([PathChunk::from(0), PathChunk::from(2)].as_ref()),
                     maximum.format_default(),
                 ),
                 FlatError::new(
-                    JSONPointer::from([PathChunk::from(3)].as_ref()),
+                    JsonPointer::from([PathChunk::from(3)].as_ref()),
                     maximum.format_default(),
                 ),
                 FlatError::new(
-                    JSONPointer::from([PathChunk::from(5)].as_ref()),
+                    JsonPointer::from([PathChunk::from(5)].as_ref()),
                     maximum.format_default(),
                 ),
                 FlatError::new(
-                    JSONPointer::from(
+                    JsonPointer::from(
                         [PathChunk::from(5), PathChunk::from("name".to_owned())].as_ref()
                     ),
                     maximum.format_default(),
@@ -110,13 +110,28 @@ impl Localize for crate::validation::Error {
             Self::MinLength(message) => message.localize(bundle),
             Self::MaxLength(message) => message.localize(bundle),
             Self::Pattern(message) => message.localize(bundle),
+            Self::Format(message) => message.localize(bundle),
             Self::MinItems(message) => message.localize(bundle),
             Self::MaxItems(message) => message.localize(bundle),
             Self::UniqueItems(message) => message.localize(bundle),
+            Self::Contains(message) => message.localize(bundle),
             Self::MinProperties(message) => message.localize(bundle),
             Self::MaxProperties(message) => message.localize(bundle),
             Self::Enumerate(message) => message.localize(bundle),
+            Self::Const(message) => message.localize(bundle),
+            Self::Required(message) => message.localize(bundle),
+            Self::FieldComparison(message) => message.localize(bundle),
+            Self::AnyOf(message) => message.localize(bundle),
+            Self::AllOf(message) => message.localize(bundle),
+            Self::OneOf(message) => message.localize(bundle),
+            Self::Not(message) => message.localize(bundle),
+            Self::Truncated(message) => message.localize(bundle),
+            Self::Type(message) => message.localize(bundle),
+            Self::InvalidValue(message) => message.localize(bundle),
             Self::Custom(message) => LocalizedError::String(message.to_string()),
+            Self::CustomError(error) => error
+                .try_localize(bundle)
+                .unwrap_or_else(|_| LocalizedError::String(error.to_string())),
             Self::Items(message) => LocalizedError::Items(message.localize(bundle)),
             Self::Properties(message) => LocalizedError::Properties(message.localize(bundle)),
             Self::Fluent(message) => message.localize(bundle).unwrap_or_else(|| {
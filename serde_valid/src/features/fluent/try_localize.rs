@@ -1,4 +1,4 @@
-use fluent::{bundle::FluentBundle, FluentArgs, FluentError, FluentResource};
+use fluent::{bundle::FluentBundle, FluentArgs, FluentError, FluentResource, FluentValue};
 
 use crate::validation::error::{
     ArrayErrors, Errors, FormatDefault, ItemErrorsMap, ObjectErrors, PropertyErrorsMap, VecErrors,
@@ -178,13 +178,26 @@ impl TryLocalize for crate::validation::Error {
             Self::MinLength(message) => message.try_localize(bundle),
             Self::MaxLength(message) => message.try_localize(bundle),
             Self::Pattern(message) => message.try_localize(bundle),
+            Self::Format(message) => message.try_localize(bundle),
             Self::MinItems(message) => message.try_localize(bundle),
             Self::MaxItems(message) => message.try_localize(bundle),
             Self::UniqueItems(message) => message.try_localize(bundle),
+            Self::Contains(message) => message.try_localize(bundle),
             Self::MinProperties(message) => message.try_localize(bundle),
             Self::MaxProperties(message) => message.try_localize(bundle),
             Self::Enumerate(message) => message.try_localize(bundle),
+            Self::Const(message) => message.try_localize(bundle),
+            Self::Required(message) => message.try_localize(bundle),
+            Self::FieldComparison(message) => message.try_localize(bundle),
+            Self::AnyOf(message) => message.try_localize(bundle),
+            Self::AllOf(message) => message.try_localize(bundle),
+            Self::OneOf(message) => message.try_localize(bundle),
+            Self::Not(message) => message.try_localize(bundle),
+            Self::Truncated(message) => message.try_localize(bundle),
+            Self::Type(message) => message.try_localize(bundle),
+            Self::InvalidValue(message) => message.try_localize(bundle),
             Self::Custom(message) => Ok(LocalizedError::String(message.to_string())),
+            Self::CustomError(error) => error.try_localize(bundle),
             Self::Items(message) => Ok(LocalizedError::Items(message.try_localize(bundle)?)),
             Self::Properties(message) => {
                 Ok(LocalizedError::Properties(message.try_localize(bundle)?))
@@ -246,6 +259,45 @@ impl TryLocalize for crate::features::fluent::Message {
     }
 }
 
+/// The custom error code is used as the fluent id, and the params as the fluent args.
+impl TryLocalize for crate::validation::error::CustomError {
+    type Target = LocalizedError;
+
+    fn try_localize<M>(
+        &self,
+        bundle: &FluentBundle<FluentResource, M>,
+    ) -> Result<Self::Target, Vec<FluentError>>
+    where
+        M: fluent::memoizer::MemoizerKind,
+    {
+        if let Some(msg) = bundle.get_message(&self.code) {
+            if let Some(pattern) = msg.value() {
+                let mut errors = vec![];
+                let args = FluentArgs::from_iter(self.params.iter().map(|(key, value)| {
+                    let value = match value {
+                        serde_json::Value::String(string) => FluentValue::from(string.as_str()),
+                        serde_json::Value::Number(number) => match number.as_f64() {
+                            Some(number) => FluentValue::from(number),
+                            None => FluentValue::from(number.to_string()),
+                        },
+                        value => FluentValue::from(value.to_string()),
+                    };
+                    (key.as_str(), value)
+                }));
+                let value = bundle
+                    .format_pattern(pattern, Some(&args), &mut errors)
+                    .to_string();
+                if errors.is_empty() {
+                    return Ok(LocalizedError::String(value));
+                } else {
+                    return Err(errors);
+                }
+            }
+        }
+        Ok(LocalizedError::String(self.message.to_string()))
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::fluent::Message;
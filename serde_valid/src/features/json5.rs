@@ -0,0 +1,11 @@
+mod from_json5_reader;
+mod from_json5_str;
+mod to_json5_string;
+mod to_json5_writer;
+
+pub use json5::{Error, Location};
+
+pub use from_json5_reader::FromJson5Reader;
+pub use from_json5_str::FromJson5Str;
+pub use to_json5_string::ToJson5String;
+pub use to_json5_writer::ToJson5Writer;
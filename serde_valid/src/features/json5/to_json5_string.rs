@@ -0,0 +1,28 @@
+pub trait ToJson5String {
+    /// Convert to json5 string.
+    ///
+    /// ```rust
+    /// use serde::Serialize;
+    /// use serde_valid::json5::ToJson5String;
+    /// use serde_valid::Validate;
+    ///
+    /// #[derive(Debug, Validate, Serialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 100)]
+    ///     val: i32,
+    /// }
+    /// let s = TestStruct { val: 10 };
+    ///
+    /// assert_eq!(s.to_json5_string().unwrap(), r#"{"val":10}"#);
+    /// ```
+    fn to_json5_string(&self) -> Result<String, json5::Error>;
+}
+
+impl<T> ToJson5String for T
+where
+    T: serde::Serialize + crate::Validate,
+{
+    fn to_json5_string(&self) -> Result<String, json5::Error> {
+        json5::to_string(self)
+    }
+}
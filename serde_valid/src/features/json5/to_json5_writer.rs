@@ -0,0 +1,42 @@
+use serde::ser::Error;
+
+use super::ToJson5String;
+
+pub trait ToJson5Writer {
+    /// Convert to json5 writer.
+    ///
+    /// ```rust
+    /// use serde::Serialize;
+    /// use serde_valid::json5::ToJson5Writer;
+    /// use serde_valid::Validate;
+    ///
+    /// #[derive(Debug, Validate, Serialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 100)]
+    ///     val: i32,
+    /// }
+    /// let s = TestStruct { val: 10 };
+    /// let mut writer = Vec::new();
+    ///
+    /// assert!(s.to_json5_writer(&mut writer).is_ok());
+    /// ```
+    fn to_json5_writer<W>(&self, writer: W) -> Result<(), json5::Error>
+    where
+        W: std::io::Write;
+}
+
+impl<T> ToJson5Writer for T
+where
+    T: serde::Serialize + crate::Validate,
+{
+    fn to_json5_writer<W>(&self, writer: W) -> Result<(), json5::Error>
+    where
+        W: std::io::Write,
+    {
+        let mut writer = writer;
+        match writer.write_all(&self.to_json5_string()?.into_bytes()) {
+            Ok(_) => Ok(()),
+            Err(err) => Err(json5::Error::custom(err.to_string())),
+        }
+    }
+}
@@ -0,0 +1,12 @@
+mod from_msgpack_reader;
+mod from_msgpack_slice;
+mod to_msgpack_vec;
+mod to_msgpack_writer;
+
+pub use rmp_serde::decode::Error as DecodeError;
+pub use rmp_serde::encode::Error as EncodeError;
+
+pub use from_msgpack_reader::FromMsgpackReader;
+pub use from_msgpack_slice::FromMsgpackSlice;
+pub use to_msgpack_vec::ToMsgpackVec;
+pub use to_msgpack_writer::ToMsgpackWriter;
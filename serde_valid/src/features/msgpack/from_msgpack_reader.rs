@@ -0,0 +1,42 @@
+pub trait FromMsgpackReader
+where
+    Self: Sized,
+{
+    /// Convert from MessagePack reader.
+    ///
+    /// ```rust
+    /// use serde::{Deserialize, Serialize};
+    /// use serde_valid::Validate;
+    /// use serde_valid::msgpack::{FromMsgpackReader, ToMsgpackVec};
+    ///
+    /// #[derive(Debug, Validate, Deserialize, Serialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 2000)]
+    ///     val: i32,
+    /// }
+    ///
+    /// let bytes = TestStruct { val: 1234 }.to_msgpack_vec().unwrap();
+    /// let s = TestStruct::from_msgpack_reader(bytes.as_slice());
+    ///
+    /// assert!(s.is_ok())
+    /// ```
+    fn from_msgpack_reader<R>(reader: R) -> Result<Self, crate::Error<rmp_serde::decode::Error>>
+    where
+        R: std::io::Read;
+}
+
+impl<T> FromMsgpackReader for T
+where
+    for<'de> T: serde::de::Deserialize<'de>,
+    T: crate::Validate,
+{
+    fn from_msgpack_reader<R>(reader: R) -> Result<Self, crate::Error<rmp_serde::decode::Error>>
+    where
+        R: std::io::Read,
+    {
+        let model: T =
+            crate::error::deserialize_with_path(&mut rmp_serde::Deserializer::new(reader))?;
+        model.validate().map_err(crate::Error::ValidationError)?;
+        Ok(model)
+    }
+}
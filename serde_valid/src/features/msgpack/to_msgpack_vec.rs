@@ -0,0 +1,30 @@
+pub trait ToMsgpackVec {
+    /// Convert to MessagePack bytes.
+    ///
+    /// The structs are serialized as the maps with the field names.
+    ///
+    /// ```rust
+    /// use serde::Serialize;
+    /// use serde_valid::msgpack::ToMsgpackVec;
+    /// use serde_valid::Validate;
+    ///
+    /// #[derive(Debug, Validate, Serialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 100)]
+    ///     val: i32,
+    /// }
+    /// let s = TestStruct { val: 10 };
+    ///
+    /// assert_eq!(s.to_msgpack_vec().unwrap(), b"\x81\xa3val\x0a");
+    /// ```
+    fn to_msgpack_vec(&self) -> Result<Vec<u8>, rmp_serde::encode::Error>;
+}
+
+impl<T> ToMsgpackVec for T
+where
+    T: serde::Serialize + crate::Validate,
+{
+    fn to_msgpack_vec(&self) -> Result<Vec<u8>, rmp_serde::encode::Error> {
+        rmp_serde::to_vec_named(self)
+    }
+}
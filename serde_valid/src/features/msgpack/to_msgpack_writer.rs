@@ -0,0 +1,36 @@
+pub trait ToMsgpackWriter {
+    /// Convert to MessagePack writer.
+    ///
+    /// The structs are serialized as the maps with the field names.
+    ///
+    /// ```rust
+    /// use serde::Serialize;
+    /// use serde_valid::msgpack::ToMsgpackWriter;
+    /// use serde_valid::Validate;
+    ///
+    /// #[derive(Debug, Validate, Serialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 100)]
+    ///     val: i32,
+    /// }
+    /// let s = TestStruct { val: 10 };
+    /// let mut writer = Vec::new();
+    ///
+    /// assert!(s.to_msgpack_writer(&mut writer).is_ok());
+    /// ```
+    fn to_msgpack_writer<W>(&self, writer: W) -> Result<(), rmp_serde::encode::Error>
+    where
+        W: std::io::Write;
+}
+
+impl<T> ToMsgpackWriter for T
+where
+    T: serde::Serialize + crate::Validate,
+{
+    fn to_msgpack_writer<W>(&self, mut writer: W) -> Result<(), rmp_serde::encode::Error>
+    where
+        W: std::io::Write,
+    {
+        rmp_serde::encode::write_named(&mut writer, self)
+    }
+}
@@ -0,0 +1,35 @@
+mod from_ron_reader;
+mod from_ron_str;
+mod from_ron_value;
+mod to_ron_string;
+mod to_ron_writer;
+
+pub use ron::error::{Position, SpannedError};
+pub use ron::{Error, Map, Number, Value};
+
+pub use from_ron_reader::FromRonReader;
+pub use from_ron_str::FromRonStr;
+pub use from_ron_value::FromRonValue;
+pub use to_ron_string::ToRonString;
+pub use to_ron_writer::ToRonWriter;
+
+/// Deserialize with tracking the location where it fails,
+/// and keep the line and column of the error.
+fn deserialize_with_position<'de, T>(bytes: &'de [u8]) -> Result<T, crate::Error<SpannedError>>
+where
+    T: serde::de::Deserialize<'de>,
+{
+    let mut deserializer = ron::Deserializer::from_bytes(bytes)?;
+
+    let model = crate::error::deserialize_with_path(&mut deserializer).map_err(|error| {
+        crate::DeserializeError::new(
+            error.path().clone(),
+            deserializer.span_error(error.into_inner()),
+        )
+    })?;
+    deserializer
+        .end()
+        .map_err(|error| deserializer.span_error(error))?;
+
+    Ok(model)
+}
@@ -0,0 +1,45 @@
+pub trait FromRonReader
+where
+    Self: Sized,
+{
+    /// Convert from ron reader.
+    ///
+    /// ```rust
+    /// use serde::Deserialize;
+    /// use serde_valid::Validate;
+    /// use serde_valid::ron::FromRonReader;
+    ///
+    /// #[derive(Debug, Validate, Deserialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 2000)]
+    ///     val: i32,
+    /// }
+    ///
+    /// let s = TestStruct::from_ron_reader("(val: 1234)".as_bytes());
+    ///
+    /// assert!(s.is_ok())
+    /// ```
+    fn from_ron_reader<R>(reader: R) -> Result<Self, crate::Error<ron::error::SpannedError>>
+    where
+        R: std::io::Read;
+}
+
+impl<T> FromRonReader for T
+where
+    T: serde::de::DeserializeOwned + crate::Validate,
+{
+    fn from_ron_reader<R>(reader: R) -> Result<Self, crate::Error<ron::error::SpannedError>>
+    where
+        R: std::io::Read,
+    {
+        let mut buffer = Vec::new();
+        let mut reader = reader;
+        reader
+            .read_to_end(&mut buffer)
+            .map_err(ron::error::SpannedError::from)?;
+
+        let model: T = super::deserialize_with_position(&buffer)?;
+        model.validate().map_err(crate::Error::ValidationError)?;
+        Ok(model)
+    }
+}
@@ -0,0 +1,34 @@
+pub trait FromRonValue
+where
+    Self: Sized,
+{
+    /// Convert from [`ron::Value`](ron::Value).
+    ///
+    /// ```rust
+    /// use serde::Deserialize;
+    /// use serde_valid::Validate;
+    /// use serde_valid::ron::{FromRonValue, Value};
+    ///
+    /// #[derive(Debug, Validate, Deserialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 2000)]
+    ///     val: i32,
+    /// }
+    ///
+    /// let s = TestStruct::from_ron_value(ron::from_str::<Value>("(val: 5)").unwrap());
+    ///
+    /// assert!(s.is_ok())
+    /// ```
+    fn from_ron_value(value: ron::Value) -> Result<Self, crate::Error<ron::Error>>;
+}
+
+impl<T> FromRonValue for T
+where
+    T: serde::de::DeserializeOwned + crate::Validate,
+{
+    fn from_ron_value(value: ron::Value) -> Result<Self, crate::Error<ron::Error>> {
+        let model: T = crate::error::deserialize_with_path(value)?;
+        model.validate().map_err(crate::Error::ValidationError)?;
+        Ok(model)
+    }
+}
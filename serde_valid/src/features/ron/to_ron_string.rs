@@ -0,0 +1,34 @@
+pub trait ToRonString {
+    /// Convert to ron string.
+    ///
+    /// ```rust
+    /// use serde::Serialize;
+    /// use serde_valid::ron::ToRonString;
+    /// use serde_valid::Validate;
+    ///
+    /// #[derive(Debug, Validate, Serialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 100)]
+    ///     val: i32,
+    /// }
+    /// let s = TestStruct { val: 10 };
+    ///
+    /// assert_eq!(s.to_ron_string().unwrap(), "(val:10)");
+    /// ```
+    fn to_ron_string(&self) -> Result<String, ron::Error>;
+}
+
+impl<T> ToRonString for T
+where
+    T: serde::Serialize + crate::Validate,
+{
+    fn to_ron_string(&self) -> Result<String, ron::Error> {
+        ron::to_string(self)
+    }
+}
+
+impl ToRonString for ron::Value {
+    fn to_ron_string(&self) -> Result<String, ron::Error> {
+        ron::to_string(self)
+    }
+}
@@ -0,0 +1,43 @@
+pub trait ToRonWriter {
+    /// Convert to ron writer.
+    ///
+    /// ```rust
+    /// use serde::Serialize;
+    /// use serde_valid::ron::ToRonWriter;
+    /// use serde_valid::Validate;
+    ///
+    /// #[derive(Debug, Validate, Serialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 100)]
+    ///     val: i32,
+    /// }
+    /// let s = TestStruct { val: 10 };
+    /// let mut writer = Vec::new();
+    ///
+    /// assert!(s.to_ron_writer(&mut writer).is_ok());
+    /// ```
+    fn to_ron_writer<W>(&self, writer: W) -> Result<(), ron::Error>
+    where
+        W: std::io::Write;
+}
+
+impl<T> ToRonWriter for T
+where
+    T: serde::Serialize + crate::Validate,
+{
+    fn to_ron_writer<W>(&self, writer: W) -> Result<(), ron::Error>
+    where
+        W: std::io::Write,
+    {
+        ron::ser::to_writer(writer, self)
+    }
+}
+
+impl ToRonWriter for ron::Value {
+    fn to_ron_writer<W>(&self, writer: W) -> Result<(), ron::Error>
+    where
+        W: std::io::Write,
+    {
+        ron::ser::to_writer(writer, self)
+    }
+}
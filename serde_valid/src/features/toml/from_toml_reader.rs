@@ -41,7 +41,7 @@ where
             .read_to_string(&mut buffer)
             .map_err(serde_toml::de::Error::custom)?;
 
-        let model: T = serde_toml::from_str(&buffer)?;
+        let model: T = crate::error::deserialize_with_path(serde_toml::Deserializer::new(&buffer))?;
         model.validate().map_err(crate::Error::ValidationError)?;
         Ok(model)
     }
@@ -27,7 +27,7 @@ where
     T: serde::de::Deserialize<'de> + crate::Validate,
 {
     fn from_toml_slice(slice: &'de [u8]) -> Result<Self, crate::Error<serde_toml::de::Error>> {
-        let model = T::deserialize(serde_toml::Deserializer::new(
+        let model: T = crate::error::deserialize_with_path(serde_toml::Deserializer::new(
             // unwrap for backward compatibility.
             // `toml` crate no longer provides `from_slice`.
             std::str::from_utf8(slice).unwrap(),
@@ -27,7 +27,7 @@ where
     T: serde::de::Deserialize<'de> + crate::Validate,
 {
     fn from_toml_str(str: &'de str) -> Result<Self, crate::Error<serde_toml::de::Error>> {
-        let model = T::deserialize(serde_toml::Deserializer::new(str))?;
+        let model: T = crate::error::deserialize_with_path(serde_toml::Deserializer::new(str))?;
         model.validate().map_err(crate::Error::ValidationError)?;
         Ok(model)
     }
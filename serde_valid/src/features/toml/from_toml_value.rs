@@ -31,7 +31,7 @@ where
     fn from_toml_value(
         value: serde_toml::Value,
     ) -> Result<Self, crate::Error<serde_toml::de::Error>> {
-        let model: T = serde::Deserialize::deserialize(value)?;
+        let model: T = crate::error::deserialize_with_path(value)?;
         model.validate().map_err(crate::Error::ValidationError)?;
         Ok(model)
     }
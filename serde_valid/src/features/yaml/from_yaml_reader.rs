@@ -34,7 +34,8 @@ where
     where
         R: std::io::Read,
     {
-        let model: T = serde_yaml::from_reader(reader)?;
+        let model: T =
+            crate::error::deserialize_with_path(serde_yaml::Deserializer::from_reader(reader))?;
         model.validate().map_err(crate::Error::ValidationError)?;
         Ok(model)
     }
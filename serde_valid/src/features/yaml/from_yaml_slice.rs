@@ -27,7 +27,8 @@ where
     T: serde::de::Deserialize<'de> + crate::Validate,
 {
     fn from_yaml_slice(slice: &'de [u8]) -> Result<Self, crate::Error<serde_yaml::Error>> {
-        let model: T = serde_yaml::from_slice(slice)?;
+        let model: T =
+            crate::error::deserialize_with_path(serde_yaml::Deserializer::from_slice(slice))?;
         model.validate().map_err(crate::Error::ValidationError)?;
         Ok(model)
     }
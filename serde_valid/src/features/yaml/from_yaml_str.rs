@@ -27,7 +27,8 @@ where
     T: serde::de::Deserialize<'de> + crate::Validate,
 {
     fn from_yaml_str(str: &'de str) -> Result<Self, crate::Error<serde_yaml::Error>> {
-        let model: T = serde_yaml::from_str(str)?;
+        let model: T =
+            crate::error::deserialize_with_path(serde_yaml::Deserializer::from_str(str))?;
         model.validate().map_err(crate::Error::ValidationError)?;
         Ok(model)
     }
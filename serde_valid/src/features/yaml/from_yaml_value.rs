@@ -27,7 +27,7 @@ where
     T: serde::de::DeserializeOwned + crate::Validate,
 {
     fn from_yaml_value(value: serde_yaml::Value) -> Result<Self, crate::Error<serde_yaml::Error>> {
-        let model: T = serde_yaml::from_value(value)?;
+        let model: T = crate::error::deserialize_with_path(value)?;
         model.validate().map_err(crate::Error::ValidationError)?;
         Ok(model)
     }
@@ -2,16 +2,19 @@ mod from_json_reader;
 mod from_json_slice;
 mod from_json_str;
 mod from_json_value;
+mod lenient;
+mod stream_validator;
 mod to_json_string;
 mod to_json_value;
 mod to_json_writer;
 
 pub use serde_json::{json, Map, Value};
 
-pub use from_json_reader::FromJsonReader;
-pub use from_json_slice::FromJsonSlice;
-pub use from_json_str::FromJsonStr;
-pub use from_json_value::FromJsonValue;
+pub use from_json_reader::{FromJsonReader, FromJsonReaderWithContext};
+pub use from_json_slice::{FromJsonSlice, FromJsonSliceWithContext};
+pub use from_json_str::{FromJsonStr, FromJsonStrWithContext};
+pub use from_json_value::{FromJsonValue, FromJsonValueWithContext};
+pub use stream_validator::StreamValidator;
 pub use to_json_string::ToJsonString;
 pub use to_json_value::ToJsonValue;
 pub use to_json_writer::ToJsonWriter;
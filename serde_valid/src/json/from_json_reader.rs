@@ -33,8 +33,72 @@ where
     where
         R: std::io::Read,
     {
-        let model: T = serde_json::from_reader(reader)?;
+        let mut deserializer = serde_json::Deserializer::from_reader(reader);
+        let model: T = crate::error::deserialize_with_path(&mut deserializer)?;
+        deserializer.end()?;
         model.validate().map_err(crate::Error::ValidationError)?;
         Ok(model)
     }
 }
+
+pub trait FromJsonReaderWithContext<Ctx>
+where
+    Self: Sized,
+    Ctx: ?Sized,
+{
+    /// Convert from json reader, and validate with the context.
+    ///
+    /// ```should_panic
+    /// use std::fs::File;
+    /// use serde::Deserialize;
+    /// use serde_valid::Validate;
+    /// use serde_valid::json::FromJsonReaderWithContext;
+    ///
+    /// fn maximum(val: &i32, max: &i32) -> Result<(), serde_valid::validation::Error> {
+    ///     if val <= max {
+    ///         Ok(())
+    ///     } else {
+    ///         Err(serde_valid::validation::Error::Custom("too large.".to_string()))
+    ///     }
+    /// }
+    ///
+    /// #[derive(Debug, Validate, Deserialize)]
+    /// #[validate(context = i32)]
+    /// struct TestStruct {
+    ///     #[validate(custom(maximum))]
+    ///     val: i32,
+    /// }
+    ///
+    /// let s = TestStruct::from_json_reader_with(File::open("foo.txt").unwrap(), &2000);
+    ///
+    /// assert!(s.is_ok())
+    /// ```
+    fn from_json_reader_with<R>(
+        reader: R,
+        context: &Ctx,
+    ) -> Result<Self, crate::Error<serde_json::Error>>
+    where
+        R: std::io::Read;
+}
+
+impl<T, Ctx> FromJsonReaderWithContext<Ctx> for T
+where
+    T: serde::de::DeserializeOwned + crate::ValidateWithContext<Ctx>,
+    Ctx: ?Sized,
+{
+    fn from_json_reader_with<R>(
+        reader: R,
+        context: &Ctx,
+    ) -> Result<Self, crate::Error<serde_json::Error>>
+    where
+        R: std::io::Read,
+    {
+        let mut deserializer = serde_json::Deserializer::from_reader(reader);
+        let model: T = crate::error::deserialize_with_path(&mut deserializer)?;
+        deserializer.end()?;
+        model
+            .validate_with_context(context)
+            .map_err(crate::Error::ValidationError)?;
+        Ok(model)
+    }
+}
@@ -27,8 +27,66 @@ where
     T: serde::de::Deserialize<'de> + crate::Validate,
 {
     fn from_json_slice(slice: &'de [u8]) -> Result<Self, crate::Error<serde_json::Error>> {
-        let model: T = serde_json::from_slice(slice)?;
+        let mut deserializer = serde_json::Deserializer::from_slice(slice);
+        let model: T = crate::error::deserialize_with_path(&mut deserializer)?;
+        deserializer.end()?;
         model.validate().map_err(crate::Error::ValidationError)?;
         Ok(model)
     }
 }
+
+pub trait FromJsonSliceWithContext<'de, Ctx>
+where
+    Self: Sized,
+    Ctx: ?Sized,
+{
+    /// Convert from json slice, and validate with the context.
+    ///
+    /// ```rust
+    /// use serde::Deserialize;
+    /// use serde_valid::Validate;
+    /// use serde_valid::json::FromJsonSliceWithContext;
+    ///
+    /// fn max_length(val: &&str, max: &usize) -> Result<(), serde_valid::validation::Error> {
+    ///     if val.len() <= *max {
+    ///         Ok(())
+    ///     } else {
+    ///         Err(serde_valid::validation::Error::Custom("too long.".to_string()))
+    ///     }
+    /// }
+    ///
+    /// #[derive(Debug, Validate, Deserialize)]
+    /// #[validate(context = usize)]
+    /// struct TestStruct<'a> {
+    ///     #[validate(custom(max_length))]
+    ///     val: &'a str,
+    /// }
+    ///
+    /// let s = TestStruct::from_json_slice_with(br#"{ "val": "abcde" }"#, &5);
+    ///
+    /// assert!(s.is_ok())
+    /// ```
+    fn from_json_slice_with(
+        slice: &'de [u8],
+        context: &Ctx,
+    ) -> Result<Self, crate::Error<serde_json::Error>>;
+}
+
+impl<'de, T, Ctx> FromJsonSliceWithContext<'de, Ctx> for T
+where
+    T: serde::de::Deserialize<'de> + crate::ValidateWithContext<Ctx>,
+    Ctx: ?Sized,
+{
+    fn from_json_slice_with(
+        slice: &'de [u8],
+        context: &Ctx,
+    ) -> Result<Self, crate::Error<serde_json::Error>> {
+        let mut deserializer = serde_json::Deserializer::from_slice(slice);
+        let model: Self = crate::error::deserialize_with_path(&mut deserializer)?;
+        deserializer.end()?;
+        model
+            .validate_with_context(context)
+            .map_err(crate::Error::ValidationError)?;
+        Ok(model)
+    }
+}
@@ -27,8 +27,66 @@ where
     T: serde::de::Deserialize<'de> + crate::Validate,
 {
     fn from_json_str(str: &'de str) -> Result<Self, crate::Error<serde_json::Error>> {
-        let model: Self = serde_json::from_str(str)?;
+        let mut deserializer = serde_json::Deserializer::from_str(str);
+        let model: Self = crate::error::deserialize_with_path(&mut deserializer)?;
+        deserializer.end()?;
         model.validate().map_err(crate::Error::ValidationError)?;
         Ok(model)
     }
 }
+
+pub trait FromJsonStrWithContext<'de, Ctx>
+where
+    Self: Sized,
+    Ctx: ?Sized,
+{
+    /// Convert from json str, and validate with the context.
+    ///
+    /// ```rust
+    /// use serde::Deserialize;
+    /// use serde_valid::Validate;
+    /// use serde_valid::json::FromJsonStrWithContext;
+    ///
+    /// fn max_length(val: &&str, max: &usize) -> Result<(), serde_valid::validation::Error> {
+    ///     if val.len() <= *max {
+    ///         Ok(())
+    ///     } else {
+    ///         Err(serde_valid::validation::Error::Custom("too long.".to_string()))
+    ///     }
+    /// }
+    ///
+    /// #[derive(Debug, Validate, Deserialize)]
+    /// #[validate(context = usize)]
+    /// struct TestStruct<'a> {
+    ///     #[validate(custom(max_length))]
+    ///     val: &'a str,
+    /// }
+    ///
+    /// let s = TestStruct::from_json_str_with(r#"{ "val": "abcde" }"#, &5);
+    ///
+    /// assert!(s.is_ok())
+    /// ```
+    fn from_json_str_with(
+        str: &'de str,
+        context: &Ctx,
+    ) -> Result<Self, crate::Error<serde_json::Error>>;
+}
+
+impl<'de, T, Ctx> FromJsonStrWithContext<'de, Ctx> for T
+where
+    T: serde::de::Deserialize<'de> + crate::ValidateWithContext<Ctx>,
+    Ctx: ?Sized,
+{
+    fn from_json_str_with(
+        str: &'de str,
+        context: &Ctx,
+    ) -> Result<Self, crate::Error<serde_json::Error>> {
+        let mut deserializer = serde_json::Deserializer::from_str(str);
+        let model: Self = crate::error::deserialize_with_path(&mut deserializer)?;
+        deserializer.end()?;
+        model
+            .validate_with_context(context)
+            .map_err(crate::Error::ValidationError)?;
+        Ok(model)
+    }
+}
@@ -20,6 +20,86 @@ where
     /// assert!(s.is_ok())
     /// ```
     fn from_json_value(value: serde_json::Value) -> Result<Self, crate::Error<serde_json::Error>>;
+
+    /// Convert from [`serde_json::Value`](serde_json::Value), and report all the errors in one pass.
+    ///
+    /// The type mismatches, the missing fields and the values rejected by `Deserialize`
+    /// are reported as the validation errors at their locations,
+    /// together with the validation errors of the rest of the fields.
+    ///
+    /// Types that buffer the input, such as `#[serde(flatten)]` and untagged enums,
+    /// are reported as a whole.
+    ///
+    /// ```rust
+    /// use serde::Deserialize;
+    /// use serde_valid::Validate;
+    /// use serde_valid::json::{json, FromJsonValue};
+    ///
+    /// #[derive(Debug, Validate, Deserialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 2000)]
+    ///     val: i32,
+    ///     #[validate(max_length = 4)]
+    ///     name: String,
+    /// }
+    ///
+    /// let err = TestStruct::from_json_value_lenient(json!({ "val": "1234", "name": "alice" }))
+    ///     .unwrap_err();
+    ///
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     json!({
+    ///         "errors": [],
+    ///         "properties": {
+    ///             "name": { "errors": ["The length of the value must be `<= 4`."] },
+    ///             "val": { "errors": ["The value must be of type `integer`, but `string` was given."] }
+    ///         }
+    ///     })
+    ///     .to_string()
+    /// );
+    /// ```
+    fn from_json_value_lenient(
+        value: serde_json::Value,
+    ) -> Result<Self, crate::Error<serde_json::Error>>;
+
+    /// Convert from [`serde_json::Value`](serde_json::Value) leniently, and validate with the options.
+    ///
+    /// Each value rejected by `Deserialize`, such as a missing field, deserializes the input once more,
+    /// so `max_errors` also stops replacing them, and reports the errors without the model.
+    ///
+    /// ```rust
+    /// use serde::Deserialize;
+    /// use serde_valid::Validate;
+    /// use serde_valid::json::{json, FromJsonValue};
+    /// use serde_valid::validation::ValidationOptions;
+    ///
+    /// #[derive(Debug, Validate, Deserialize)]
+    /// struct TestStruct {
+    ///     a: i32,
+    ///     b: i32,
+    /// }
+    ///
+    /// let err = TestStruct::from_json_value_lenient_with(
+    ///     json!({}),
+    ///     &ValidationOptions::new().with_max_errors(1),
+    /// )
+    /// .unwrap_err();
+    ///
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     json!({
+    ///         "errors": ["The validation was stopped after `1` errors."],
+    ///         "properties": {
+    ///             "a": { "errors": ["The value is required."] }
+    ///         }
+    ///     })
+    ///     .to_string()
+    /// );
+    /// ```
+    fn from_json_value_lenient_with(
+        value: serde_json::Value,
+        options: &crate::validation::ValidationOptions,
+    ) -> Result<Self, crate::Error<serde_json::Error>>;
 }
 
 impl<T> FromJsonValue for T
@@ -27,8 +107,84 @@ where
     T: serde::de::DeserializeOwned + crate::Validate,
 {
     fn from_json_value(value: serde_json::Value) -> Result<Self, crate::Error<serde_json::Error>> {
-        let model: T = serde_json::from_value(value)?;
+        let model: T = crate::error::deserialize_with_path(value)?;
         model.validate().map_err(crate::Error::ValidationError)?;
         Ok(model)
     }
+
+    fn from_json_value_lenient(
+        value: serde_json::Value,
+    ) -> Result<Self, crate::Error<serde_json::Error>> {
+        Self::from_json_value_lenient_with(value, &Default::default())
+    }
+
+    fn from_json_value_lenient_with(
+        value: serde_json::Value,
+        options: &crate::validation::ValidationOptions,
+    ) -> Result<Self, crate::Error<serde_json::Error>> {
+        let (model, errors) =
+            super::lenient::deserialize_lenient::<T>(&value, options.max_errors())?;
+        let Some(model) = model else {
+            return Err(crate::Error::ValidationError(
+                super::lenient::truncated_errors(errors, options.max_errors()),
+            ));
+        };
+        super::lenient::merge_errors(errors, model.validate_with(options))
+            .map_err(crate::Error::ValidationError)?;
+        Ok(model)
+    }
+}
+
+pub trait FromJsonValueWithContext<Ctx>
+where
+    Self: Sized,
+    Ctx: ?Sized,
+{
+    /// Convert from [`serde_json::Value`](serde_json::Value), and validate with the context.
+    ///
+    /// ```rust
+    /// use serde::Deserialize;
+    /// use serde_valid::Validate;
+    /// use serde_valid::json::{json, FromJsonValueWithContext};
+    ///
+    /// fn maximum(val: &i32, max: &i32) -> Result<(), serde_valid::validation::Error> {
+    ///     if val <= max {
+    ///         Ok(())
+    ///     } else {
+    ///         Err(serde_valid::validation::Error::Custom("too large.".to_string()))
+    ///     }
+    /// }
+    ///
+    /// #[derive(Debug, Validate, Deserialize)]
+    /// #[validate(context = i32)]
+    /// struct TestStruct {
+    ///     #[validate(custom(maximum))]
+    ///     val: i32,
+    /// }
+    ///
+    /// let s = TestStruct::from_json_value_with(json!({ "val": 1234 }), &2000);
+    ///
+    /// assert!(s.is_ok())
+    /// ```
+    fn from_json_value_with(
+        value: serde_json::Value,
+        context: &Ctx,
+    ) -> Result<Self, crate::Error<serde_json::Error>>;
+}
+
+impl<T, Ctx> FromJsonValueWithContext<Ctx> for T
+where
+    T: serde::de::DeserializeOwned + crate::ValidateWithContext<Ctx>,
+    Ctx: ?Sized,
+{
+    fn from_json_value_with(
+        value: serde_json::Value,
+        context: &Ctx,
+    ) -> Result<Self, crate::Error<serde_json::Error>> {
+        let model: T = crate::error::deserialize_with_path(value)?;
+        model
+            .validate_with_context(context)
+            .map_err(crate::Error::ValidationError)?;
+        Ok(model)
+    }
 }
@@ -0,0 +1,1182 @@
+//! Deserialization that reports the invalid values as the validation errors
+//! instead of aborting at the first one.
+//!
+//! The type mismatches are replaced with the placeholder values, such as `0` and `""`,
+//! so that the rest of the fields can be deserialized.
+//! The missing fields and the values rejected by the `Deserialize` impls are replaced
+//! in the same way, by deserializing again with the placeholders at their locations.
+
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+
+use indexmap::IndexMap;
+use serde::de::{self, DeserializeSeed, Deserializer, Error as _, IntoDeserializer, Visitor};
+use serde_json::{Map, Value};
+
+use crate::validation::error::{Format, Message};
+use crate::validation::{ArrayErrors, Error, Errors, JsonPointer, ObjectErrors, PathChunk};
+use crate::{DeserializeError, InvalidValueError, RequiredError, TruncatedError, TypeError};
+
+static NULL: Value = Value::Null;
+
+/// The errors with their locations.
+type LocatedErrors = Vec<(JsonPointer, Error)>;
+
+/// Deserialize leniently, and return the model with the errors of the replaced values.
+///
+/// Each value rejected by the `Deserialize` impls, such as a missing field, costs one more pass,
+/// so the replacements stop at `max_errors` without the model.
+pub(crate) fn deserialize_lenient<T>(
+    value: &Value,
+    max_errors: Option<usize>,
+) -> Result<(Option<T>, LocatedErrors), DeserializeError<serde_json::Error>>
+where
+    T: serde::de::DeserializeOwned,
+{
+    let mut replaced = Replacements::default();
+
+    loop {
+        let state = State {
+            replaced: &replaced,
+            type_errors: RefCell::new(vec![]),
+        };
+
+        match T::deserialize(LenientDeserializer::new(
+            value,
+            JsonPointer::default(),
+            &state,
+        )) {
+            Ok(model) => {
+                let type_errors = state.type_errors.into_inner();
+                let mut errors = replaced.into_errors();
+                errors.extend(type_errors);
+                return Ok((Some(model), errors));
+            }
+            Err(error) => {
+                let path = error.path.unwrap_or_default();
+
+                // The placeholder is also rejected, so it cannot be recovered.
+                if replaced.values.contains_key(&path) {
+                    return Err(DeserializeError::new(
+                        path,
+                        <serde_json::Error as de::Error>::custom(error.message),
+                    ));
+                }
+
+                if max_errors.is_some_and(|max_errors| replaced.values.len() >= max_errors) {
+                    return Ok((None, replaced.into_errors()));
+                }
+
+                replaced.insert(
+                    path,
+                    Replaced {
+                        is_key: error.is_key,
+                        error: match error.missing_field {
+                            Some(_) => {
+                                Error::Required(Message::new(RequiredError, Format::Default))
+                            }
+                            None => Error::InvalidValue(Message::new(
+                                InvalidValueError::new(error.message),
+                                Format::Default,
+                            )),
+                        },
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// The errors of the replaced values, when the replacements reach `max_errors`.
+pub(crate) fn truncated_errors(
+    deserialize_errors: LocatedErrors,
+    max_errors: Option<usize>,
+) -> Errors {
+    let truncated = Errors::NewType(vec![Error::Truncated(Message::new(
+        TruncatedError::new(max_errors, None),
+        Format::Default,
+    ))]);
+
+    match merge_errors(deserialize_errors, Ok(())) {
+        Err(mut errors) => {
+            errors.merge(truncated);
+            errors
+        }
+        Ok(()) => truncated,
+    }
+}
+
+/// Merge the deserialization errors into the validation errors.
+///
+/// The validation errors of the replaced values are dropped,
+/// because the placeholders are not the input values.
+pub(crate) fn merge_errors(
+    deserialize_errors: LocatedErrors,
+    validation_result: Result<(), Errors>,
+) -> Result<(), Errors> {
+    let replaced = deserialize_errors
+        .iter()
+        .map(|(path, _)| path.clone())
+        .collect::<HashSet<_>>();
+
+    let mut merged = validation_result
+        .err()
+        .and_then(|errors| retain_errors(errors, &JsonPointer::default(), &replaced));
+
+    for (path, error) in deserialize_errors {
+        let errors = errors_at(path.chunks(), error);
+        match merged.as_mut() {
+            Some(merged) => merged.merge(errors),
+            None => merged = Some(errors),
+        }
+    }
+
+    match merged {
+        Some(errors) => Err(errors),
+        None => Ok(()),
+    }
+}
+
+fn errors_at(path: &[PathChunk], error: Error) -> Errors {
+    match path.split_first() {
+        None => Errors::NewType(vec![error]),
+        Some((PathChunk::Index(index), rest)) => Errors::Array(ArrayErrors::new(
+            vec![],
+            IndexMap::from([(*index, errors_at(rest, error))]),
+        )),
+        Some((PathChunk::Property(property), rest)) => Errors::Object(ObjectErrors::new(
+            vec![],
+            IndexMap::from([(property.to_string(), errors_at(rest, error))]),
+        )),
+    }
+}
+
+fn retain_errors(
+    errors: Errors,
+    location: &JsonPointer,
+    replaced: &HashSet<JsonPointer>,
+) -> Option<Errors> {
+    // The parent locations are already checked by the callers.
+    if replaced.contains(location) {
+        return None;
+    }
+
+    match errors {
+        Errors::Array(array) => retain_array_errors(array, location, replaced).map(Errors::Array),
+        Errors::Object(object) => {
+            retain_object_errors(object, location, replaced).map(Errors::Object)
+        }
+        Errors::NewType(errors) => {
+            let errors = retain_vec_errors(errors, location, replaced);
+            (!errors.is_empty()).then_some(Errors::NewType(errors))
+        }
+    }
+}
+
+fn retain_array_errors(
+    array: ArrayErrors,
+    location: &JsonPointer,
+    replaced: &HashSet<JsonPointer>,
+) -> Option<ArrayErrors> {
+    let errors = retain_vec_errors(array.errors, location, replaced);
+    let items = array
+        .items
+        .into_iter()
+        .filter_map(|(index, errors)| {
+            retain_errors(errors, &location.join(index), replaced).map(|errors| (index, errors))
+        })
+        .collect::<IndexMap<_, _>>();
+
+    (!errors.is_empty() || !items.is_empty()).then_some(ArrayErrors::new(errors, items))
+}
+
+fn retain_object_errors(
+    object: ObjectErrors,
+    location: &JsonPointer,
+    replaced: &HashSet<JsonPointer>,
+) -> Option<ObjectErrors> {
+    let errors = retain_vec_errors(object.errors, location, replaced);
+    let properties = object
+        .properties
+        .into_iter()
+        .filter_map(|(property, errors)| {
+            retain_errors(errors, &location.join(property.as_str()), replaced)
+                .map(|errors| (property, errors))
+        })
+        .collect::<IndexMap<_, _>>();
+
+    (!errors.is_empty() || !properties.is_empty()).then_some(ObjectErrors::new(errors, properties))
+}
+
+fn retain_vec_errors(
+    errors: Vec<Error>,
+    location: &JsonPointer,
+    replaced: &HashSet<JsonPointer>,
+) -> Vec<Error> {
+    errors
+        .into_iter()
+        .filter_map(|error| match error {
+            Error::Items(array) => retain_array_errors(array, location, replaced).map(Error::Items),
+            Error::Properties(object) => {
+                retain_object_errors(object, location, replaced).map(Error::Properties)
+            }
+            error => Some(error),
+        })
+        .collect()
+}
+
+/// The value replaced with the placeholder.
+struct Replaced {
+    /// Whether the key of the entry is rejected, then the entry is skipped.
+    is_key: bool,
+    error: Error,
+}
+
+/// The values replaced with the placeholders, keyed by the path.
+#[derive(Default)]
+struct Replacements {
+    values: IndexMap<JsonPointer, Replaced>,
+    /// The fields filled with the placeholders, keyed by the path of the object.
+    fields: HashMap<JsonPointer, Vec<Box<str>>>,
+}
+
+impl Replacements {
+    fn insert(&mut self, path: JsonPointer, replaced: Replaced) {
+        if let Some((PathChunk::Property(field), parent)) = path.chunks().split_last() {
+            if !replaced.is_key {
+                self.fields
+                    .entry(JsonPointer::from(parent))
+                    .or_default()
+                    .push(field.clone());
+            }
+        }
+        self.values.insert(path, replaced);
+    }
+
+    fn into_errors(self) -> LocatedErrors {
+        self.values
+            .into_iter()
+            .map(|(path, replaced)| (path, replaced.error))
+            .collect()
+    }
+}
+
+struct State<'a> {
+    replaced: &'a Replacements,
+    type_errors: RefCell<LocatedErrors>,
+}
+
+impl<'a> State<'a> {
+    fn is_replaced(&self, path: &JsonPointer) -> bool {
+        self.replaced
+            .values
+            .get(path)
+            .is_some_and(|replaced| !replaced.is_key)
+    }
+
+    fn is_skipped_key(&self, path: &JsonPointer) -> bool {
+        self.replaced
+            .values
+            .get(path)
+            .is_some_and(|replaced| replaced.is_key)
+    }
+
+    /// The missing fields of the object, which are filled with the placeholders.
+    fn missing_fields<'m>(
+        &'m self,
+        path: &'m JsonPointer,
+        object: &'m Map<String, Value>,
+    ) -> impl Iterator<Item = &'a str> + 'm {
+        self.replaced
+            .fields
+            .get(path)
+            .into_iter()
+            .flatten()
+            .map(AsRef::as_ref)
+            .filter(move |field| !object.contains_key(*field))
+    }
+}
+
+#[derive(Debug)]
+struct LenientError {
+    message: String,
+    missing_field: Option<&'static str>,
+    path: Option<JsonPointer>,
+    is_key: bool,
+}
+
+impl LenientError {
+    /// Set the location of the error, if the inner deserializer has not set it yet.
+    fn at(mut self, path: &JsonPointer) -> Self {
+        if self.path.is_none() {
+            self.path = Some(match self.missing_field {
+                Some(field) => path.join(field),
+                None => path.clone(),
+            });
+        }
+        self
+    }
+
+    fn at_key(mut self, path: &JsonPointer) -> Self {
+        if self.path.is_none() {
+            self.path = Some(path.clone());
+            self.is_key = true;
+        }
+        self
+    }
+}
+
+impl std::fmt::Display for LenientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for LenientError {}
+
+impl de::Error for LenientError {
+    fn custom<T: std::fmt::Display>(message: T) -> Self {
+        Self {
+            message: message.to_string(),
+            missing_field: None,
+            path: None,
+            is_key: false,
+        }
+    }
+
+    fn missing_field(field: &'static str) -> Self {
+        Self {
+            missing_field: Some(field),
+            ..Self::custom(format_args!("missing field `{field}`"))
+        }
+    }
+}
+
+fn value_type(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(number) if number.is_f64() => "number",
+        Value::Number(_) => "integer",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+struct LenientDeserializer<'a> {
+    value: &'a Value,
+    path: JsonPointer,
+    state: &'a State<'a>,
+}
+
+impl<'a> LenientDeserializer<'a> {
+    fn new(value: &'a Value, path: JsonPointer, state: &'a State<'a>) -> Self {
+        Self { value, path, state }
+    }
+
+    fn is_replaced(&self) -> bool {
+        self.state.is_replaced(&self.path)
+    }
+
+    fn visit<T>(&self, result: Result<T, LenientError>) -> Result<T, LenientError> {
+        result.map_err(|error| error.at(&self.path))
+    }
+
+    /// Deserialize the placeholder instead, and record the type error unless already replaced.
+    fn placeholder<T>(
+        &self,
+        expected: &str,
+        deserialize: impl FnOnce(Placeholder) -> Result<T, LenientError>,
+    ) -> Result<T, LenientError> {
+        let error = Error::Type(Message::new(
+            TypeError::new(expected, value_type(self.value)),
+            Format::Default,
+        ));
+        self.replace(error, deserialize)
+    }
+
+    fn replace<T>(
+        &self,
+        error: Error,
+        deserialize: impl FnOnce(Placeholder) -> Result<T, LenientError>,
+    ) -> Result<T, LenientError> {
+        if !self.is_replaced() {
+            self.state
+                .type_errors
+                .borrow_mut()
+                .push((self.path.clone(), error));
+        }
+        self.visit(deserialize(Placeholder))
+    }
+
+    fn seq(&self, array: &'a [Value]) -> LenientSeqAccess<'a> {
+        LenientSeqAccess {
+            iter: array.iter().enumerate(),
+            path: self.path.clone(),
+            state: self.state,
+        }
+    }
+
+    fn map(&self, object: &'a Map<String, Value>) -> LenientMapAccess<'a> {
+        let mut entries = object
+            .iter()
+            .filter(|(key, _)| !self.state.is_skipped_key(&self.path.join(key.as_str())))
+            .map(|(key, value)| (key.as_str(), value))
+            .collect::<Vec<_>>();
+        entries.extend(
+            self.state
+                .missing_fields(&self.path, object)
+                .map(|field| (field, &NULL)),
+        );
+
+        LenientMapAccess {
+            iter: entries.into_iter(),
+            value: None,
+            path: self.path.clone(),
+            state: self.state,
+        }
+    }
+}
+
+macro_rules! deserialize_number {
+    ($($method:ident => $visit:ident($type:ty, $expected:literal);)*) => {
+        $(
+            fn $method<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+            where
+                V: Visitor<'de>,
+            {
+                match <$type as serde::Deserialize>::deserialize(self.value) {
+                    Ok(value) if !self.is_replaced() => self.visit(visitor.$visit(value)),
+                    // The number is out of the range of the type.
+                    Err(error) if value_type(self.value) == $expected => {
+                        let error = Error::InvalidValue(Message::new(
+                            InvalidValueError::new(error.to_string()),
+                            Format::Default,
+                        ));
+                        self.replace(error, |p| p.$method(visitor))
+                    }
+                    _ => self.placeholder($expected, |p| p.$method(visitor)),
+                }
+            }
+        )*
+    };
+}
+
+impl<'de, 'a> Deserializer<'de> for LenientDeserializer<'a> {
+    type Error = LenientError;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        if self.is_replaced() {
+            return self.visit(Placeholder.deserialize_any(visitor));
+        }
+
+        let result = match self.value {
+            Value::Null => visitor.visit_unit(),
+            Value::Bool(value) => visitor.visit_bool(*value),
+            Value::Number(number) => match (number.as_u64(), number.as_i64(), number.as_f64()) {
+                (Some(value), _, _) => visitor.visit_u64(value),
+                (None, Some(value), _) => visitor.visit_i64(value),
+                (None, None, Some(value)) => visitor.visit_f64(value),
+                (None, None, None) => visitor.visit_str(&number.to_string()),
+            },
+            Value::String(value) => visitor.visit_str(value),
+            Value::Array(array) => visitor.visit_seq(self.seq(array)),
+            Value::Object(object) => visitor.visit_map(self.map(object)),
+        };
+        self.visit(result)
+    }
+
+    fn deserialize_bool<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.value {
+            Value::Bool(value) if !self.is_replaced() => self.visit(visitor.visit_bool(*value)),
+            _ => self.placeholder("boolean", |p| p.deserialize_bool(visitor)),
+        }
+    }
+
+    deserialize_number! {
+        deserialize_i8 => visit_i8(i8, "integer");
+        deserialize_i16 => visit_i16(i16, "integer");
+        deserialize_i32 => visit_i32(i32, "integer");
+        deserialize_i64 => visit_i64(i64, "integer");
+        deserialize_i128 => visit_i128(i128, "integer");
+        deserialize_u8 => visit_u8(u8, "integer");
+        deserialize_u16 => visit_u16(u16, "integer");
+        deserialize_u32 => visit_u32(u32, "integer");
+        deserialize_u64 => visit_u64(u64, "integer");
+        deserialize_u128 => visit_u128(u128, "integer");
+        deserialize_f32 => visit_f32(f32, "number");
+        deserialize_f64 => visit_f64(f64, "number");
+    }
+
+    fn deserialize_char<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_str<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.value {
+            Value::String(value) if !self.is_replaced() => self.visit(visitor.visit_str(value)),
+            _ => self.placeholder("string", |p| p.deserialize_str(visitor)),
+        }
+    }
+
+    fn deserialize_string<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_bytes<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.value {
+            Value::String(value) if !self.is_replaced() => {
+                self.visit(visitor.visit_bytes(value.as_bytes()))
+            }
+            Value::Array(array) if !self.is_replaced() => {
+                self.visit(visitor.visit_seq(self.seq(array)))
+            }
+            _ => self.placeholder("string", |p| p.deserialize_bytes(visitor)),
+        }
+    }
+
+    fn deserialize_byte_buf<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_bytes(visitor)
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.value {
+            _ if self.is_replaced() => self.visit(Placeholder.deserialize_option(visitor)),
+            Value::Null => self.visit(visitor.visit_none()),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_unit<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.value {
+            Value::Null if !self.is_replaced() => self.visit(visitor.visit_unit()),
+            _ => self.placeholder("null", |p| p.deserialize_unit(visitor)),
+        }
+    }
+
+    fn deserialize_unit_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_unit(visitor)
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        let path = self.path.clone();
+        visitor
+            .visit_newtype_struct(self)
+            .map_err(|error| error.at(&path))
+    }
+
+    fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.value {
+            Value::Array(array) if !self.is_replaced() => {
+                self.visit(visitor.visit_seq(self.seq(array)))
+            }
+            _ => self.placeholder("array", |p| p.deserialize_seq(visitor)),
+        }
+    }
+
+    fn deserialize_tuple<V>(self, len: usize, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.value {
+            Value::Array(array) if !self.is_replaced() => {
+                self.visit(visitor.visit_seq(self.seq(array)))
+            }
+            _ => self.placeholder("array", |p| p.deserialize_tuple(len, visitor)),
+        }
+    }
+
+    fn deserialize_tuple_struct<V>(
+        self,
+        _name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_tuple(len, visitor)
+    }
+
+    fn deserialize_map<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.value {
+            Value::Object(object) if !self.is_replaced() => {
+                self.visit(visitor.visit_map(self.map(object)))
+            }
+            _ => self.placeholder("object", |p| p.deserialize_map(visitor)),
+        }
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.value {
+            Value::Object(object) if !self.is_replaced() => {
+                self.visit(visitor.visit_map(self.map(object)))
+            }
+            Value::Array(array) if !self.is_replaced() => {
+                self.visit(visitor.visit_seq(self.seq(array)))
+            }
+            _ => self.placeholder("object", |p| p.deserialize_struct(name, fields, visitor)),
+        }
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        let (variant, value) = match self.value {
+            Value::String(variant) if !self.is_replaced() => (variant, None),
+            Value::Object(object) if !self.is_replaced() => {
+                let mut iter = object.iter();
+                match (iter.next(), iter.next()) {
+                    (Some((variant, value)), None) => (variant, Some(value)),
+                    _ => {
+                        return Err(LenientError::custom(
+                            "invalid type: map, expected map with a single key",
+                        )
+                        .at(&self.path))
+                    }
+                }
+            }
+            _ => {
+                return self.placeholder("string", |p| p.deserialize_enum(name, variants, visitor))
+            }
+        };
+
+        self.visit(visitor.visit_enum(LenientEnumAccess {
+            variant,
+            value,
+            path: self.path.clone(),
+            state: self.state,
+        }))
+    }
+
+    fn deserialize_identifier<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_ignored_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_unit()
+    }
+}
+
+struct LenientSeqAccess<'a> {
+    iter: std::iter::Enumerate<std::slice::Iter<'a, Value>>,
+    path: JsonPointer,
+    state: &'a State<'a>,
+}
+
+impl<'de, 'a> de::SeqAccess<'de> for LenientSeqAccess<'a> {
+    type Error = LenientError;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        self.iter
+            .next()
+            .map(|(index, value)| {
+                seed.deserialize(LenientDeserializer::new(
+                    value,
+                    self.path.join(index),
+                    self.state,
+                ))
+            })
+            .transpose()
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.iter.len())
+    }
+}
+
+struct LenientMapAccess<'a> {
+    iter: std::vec::IntoIter<(&'a str, &'a Value)>,
+    value: Option<(JsonPointer, &'a Value)>,
+    path: JsonPointer,
+    state: &'a State<'a>,
+}
+
+impl<'de, 'a> de::MapAccess<'de> for LenientMapAccess<'a> {
+    type Error = LenientError;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        let Some((key, value)) = self.iter.next() else {
+            return Ok(None);
+        };
+
+        let path = self.path.join(key);
+        let key = seed
+            .deserialize(KeyDeserializer(key))
+            .map_err(|error| error.at_key(&path))?;
+        self.value = Some((path, value));
+        Ok(Some(key))
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        match self.value.take() {
+            Some((path, value)) => {
+                seed.deserialize(LenientDeserializer::new(value, path, self.state))
+            }
+            None => Err(LenientError::custom("value is missing")),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.iter.len())
+    }
+}
+
+struct LenientEnumAccess<'a> {
+    variant: &'a str,
+    value: Option<&'a Value>,
+    path: JsonPointer,
+    state: &'a State<'a>,
+}
+
+impl<'de, 'a> de::EnumAccess<'de> for LenientEnumAccess<'a> {
+    type Error = LenientError;
+    type Variant = LenientDeserializer<'a>;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self::Variant), Self::Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let variant = seed.deserialize(KeyDeserializer(self.variant))?;
+
+        // The unit variant has no value, which is regarded as `null`.
+        Ok((
+            variant,
+            LenientDeserializer::new(
+                self.value.unwrap_or(&NULL),
+                self.path.join(self.variant),
+                self.state,
+            ),
+        ))
+    }
+}
+
+impl<'de, 'a> de::VariantAccess<'de> for LenientDeserializer<'a> {
+    type Error = LenientError;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        de::Deserialize::deserialize(self)
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value, Self::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V>(self, len: usize, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        Deserializer::deserialize_tuple(self, len, visitor)
+    }
+
+    fn struct_variant<V>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        Deserializer::deserialize_struct(self, "", fields, visitor)
+    }
+}
+
+/// The deserializer of the object keys and the enum variants,
+/// which parses the numbers and the booleans from the strings.
+struct KeyDeserializer<'a>(&'a str);
+
+macro_rules! deserialize_key {
+    ($($method:ident => $visit:ident($type:ty);)*) => {
+        $(
+            fn $method<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+            where
+                V: Visitor<'de>,
+            {
+                match self.0.parse::<$type>() {
+                    Ok(value) => visitor.$visit(value),
+                    Err(_) => Err(LenientError::invalid_type(de::Unexpected::Str(self.0), &visitor)),
+                }
+            }
+        )*
+    };
+}
+
+impl<'de, 'a> Deserializer<'de> for KeyDeserializer<'a> {
+    type Error = LenientError;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_str(self.0)
+    }
+
+    deserialize_key! {
+        deserialize_bool => visit_bool(bool);
+        deserialize_i8 => visit_i8(i8);
+        deserialize_i16 => visit_i16(i16);
+        deserialize_i32 => visit_i32(i32);
+        deserialize_i64 => visit_i64(i64);
+        deserialize_i128 => visit_i128(i128);
+        deserialize_u8 => visit_u8(u8);
+        deserialize_u16 => visit_u16(u16);
+        deserialize_u32 => visit_u32(u32);
+        deserialize_u64 => visit_u64(u64);
+        deserialize_u128 => visit_u128(u128);
+        deserialize_f32 => visit_f32(f32);
+        deserialize_f64 => visit_f64(f64);
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_enum(IntoDeserializer::<LenientError>::into_deserializer(self.0))
+    }
+
+    serde::forward_to_deserialize_any! {
+        char str string bytes byte_buf option unit unit_struct seq tuple
+        tuple_struct map struct identifier ignored_any
+    }
+}
+
+/// The deserializer of the placeholder values, such as `0`, `""` and the empty arrays.
+///
+/// The structs are filled with the placeholder fields, and the enums with the first variant.
+struct Placeholder;
+
+macro_rules! deserialize_placeholder {
+    ($($method:ident => $visit:ident($value:expr);)*) => {
+        $(
+            fn $method<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+            where
+                V: Visitor<'de>,
+            {
+                visitor.$visit($value)
+            }
+        )*
+    };
+}
+
+impl<'de> Deserializer<'de> for Placeholder {
+    type Error = LenientError;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_unit()
+    }
+
+    deserialize_placeholder! {
+        deserialize_bool => visit_bool(false);
+        deserialize_i8 => visit_i8(0);
+        deserialize_i16 => visit_i16(0);
+        deserialize_i32 => visit_i32(0);
+        deserialize_i64 => visit_i64(0);
+        deserialize_i128 => visit_i128(0);
+        deserialize_u8 => visit_u8(0);
+        deserialize_u16 => visit_u16(0);
+        deserialize_u32 => visit_u32(0);
+        deserialize_u64 => visit_u64(0);
+        deserialize_u128 => visit_u128(0);
+        deserialize_f32 => visit_f32(0.0);
+        deserialize_f64 => visit_f64(0.0);
+        deserialize_char => visit_char('\0');
+        deserialize_str => visit_str("");
+        deserialize_string => visit_str("");
+        deserialize_bytes => visit_bytes(&[]);
+        deserialize_byte_buf => visit_bytes(&[]);
+        deserialize_identifier => visit_str("");
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_none()
+    }
+
+    fn deserialize_unit<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_seq(PlaceholderSeqAccess(0))
+    }
+
+    fn deserialize_tuple<V>(self, len: usize, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_seq(PlaceholderSeqAccess(len))
+    }
+
+    fn deserialize_tuple_struct<V>(
+        self,
+        _name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_seq(PlaceholderSeqAccess(len))
+    }
+
+    fn deserialize_map<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_map(PlaceholderMapAccess([].iter()))
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_map(PlaceholderMapAccess(fields.iter()))
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        _name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_enum(PlaceholderEnumAccess(variants))
+    }
+
+    fn deserialize_ignored_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_unit()
+    }
+}
+
+struct PlaceholderSeqAccess(usize);
+
+impl<'de> de::SeqAccess<'de> for PlaceholderSeqAccess {
+    type Error = LenientError;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        if self.0 == 0 {
+            return Ok(None);
+        }
+        self.0 -= 1;
+        seed.deserialize(Placeholder).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.0)
+    }
+}
+
+struct PlaceholderMapAccess(std::slice::Iter<'static, &'static str>);
+
+impl<'de> de::MapAccess<'de> for PlaceholderMapAccess {
+    type Error = LenientError;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        self.0
+            .next()
+            .map(|field| seed.deserialize(KeyDeserializer(field)))
+            .transpose()
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        seed.deserialize(Placeholder)
+    }
+}
+
+struct PlaceholderEnumAccess(&'static [&'static str]);
+
+impl<'de> de::EnumAccess<'de> for PlaceholderEnumAccess {
+    type Error = LenientError;
+    type Variant = Placeholder;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self::Variant), Self::Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        match self.0.first() {
+            Some(variant) => Ok((seed.deserialize(KeyDeserializer(variant))?, Placeholder)),
+            None => Err(LenientError::custom("no variants")),
+        }
+    }
+}
+
+impl<'de> de::VariantAccess<'de> for Placeholder {
+    type Error = LenientError;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value, Self::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V>(self, len: usize, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_seq(PlaceholderSeqAccess(len))
+    }
+
+    fn struct_variant<V>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_map(PlaceholderMapAccess(fields.iter()))
+    }
+}
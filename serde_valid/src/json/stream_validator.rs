@@ -0,0 +1,344 @@
+use std::io::{BufRead, BufReader, Read};
+use std::marker::PhantomData;
+
+use serde::de::Error as _;
+
+/// The layout of the records in the stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Layout {
+    /// Detect from the first non-whitespace character.
+    Detect,
+    /// Newline-delimited JSON, one record per line.
+    Lines,
+    /// A top-level JSON array of records.
+    Array,
+}
+
+/// Deserialize and validate the records of the JSON stream one by one,
+/// without loading the whole stream into memory.
+///
+/// The stream is either newline-delimited JSON or a top-level JSON array.
+/// Each item is the validated record, or the error with the index of the record.
+/// The malformed records, including the malformed elements of the array, are reported and skipped.
+/// The unterminated array, the characters after the array and the I/O errors end the iteration.
+///
+/// The elements of the array are split at the commas outside of the brackets and the strings,
+/// so an unbalanced `{` or `[` reads the rest of the stream as the same element,
+/// and ends the iteration at the end of the stream.
+/// [`StreamValidator::with_max_record_size`] bounds the buffered record,
+/// and ends the iteration when a record exceeds it.
+///
+/// ```rust
+/// use serde::Deserialize;
+/// use serde_valid::Validate;
+/// use serde_valid::json::StreamValidator;
+///
+/// #[derive(Debug, Validate, Deserialize)]
+/// struct TestStruct {
+///     #[validate(maximum = 10)]
+///     val: i32,
+/// }
+///
+/// let input = "{ \"val\": 1 }\n{ \"val\": 20 }\n{ \"val\": 3 }\n";
+/// let mut records = StreamValidator::<TestStruct>::from_reader(input.as_bytes());
+///
+/// assert_eq!(records.next().unwrap().unwrap().val, 1);
+///
+/// let (index, err) = records.next().unwrap().unwrap_err();
+/// assert_eq!(index, 1);
+/// assert_eq!(records.line(), 2);
+/// assert!(err.is_validation_errors());
+///
+/// assert_eq!(records.next().unwrap().unwrap().val, 3);
+/// assert!(records.next().is_none());
+/// ```
+pub struct StreamValidator<'a, T> {
+    reader: Box<dyn BufRead + 'a>,
+    layout: Layout,
+    buffer: Vec<u8>,
+    max_record_size: Option<usize>,
+    index: usize,
+    cursor_line: usize,
+    record_line: usize,
+    opened: bool,
+    closed: bool,
+    finished: bool,
+    _model: PhantomData<fn() -> T>,
+}
+
+impl<'a, T> StreamValidator<'a, T> {
+    /// Read newline-delimited JSON, or a top-level JSON array if the stream starts with `[`.
+    pub fn from_reader<R>(reader: R) -> Self
+    where
+        R: std::io::Read + 'a,
+    {
+        Self::new(reader, Layout::Detect)
+    }
+
+    /// Read newline-delimited JSON.
+    pub fn from_ndjson_reader<R>(reader: R) -> Self
+    where
+        R: std::io::Read + 'a,
+    {
+        Self::new(reader, Layout::Lines)
+    }
+
+    /// Read a top-level JSON array.
+    pub fn from_array_reader<R>(reader: R) -> Self
+    where
+        R: std::io::Read + 'a,
+    {
+        Self::new(reader, Layout::Array)
+    }
+
+    fn new<R>(reader: R, layout: Layout) -> Self
+    where
+        R: std::io::Read + 'a,
+    {
+        Self {
+            reader: Box::new(BufReader::new(reader)),
+            layout,
+            buffer: vec![],
+            max_record_size: None,
+            index: 0,
+            cursor_line: 1,
+            record_line: 0,
+            opened: false,
+            closed: false,
+            finished: false,
+            _model: PhantomData,
+        }
+    }
+
+    /// Limit the bytes of a record, excluding the newline and the comma.
+    ///
+    /// The record exceeding the limit ends the iteration, because the stream cannot be resynchronized.
+    pub fn with_max_record_size(mut self, max_record_size: usize) -> Self {
+        self.max_record_size = Some(max_record_size);
+        self
+    }
+
+    /// The line number (starting from 1) where the last yielded record starts.
+    ///
+    /// It is `0` before the first record is yielded.
+    pub fn line(&self) -> usize {
+        self.record_line
+    }
+
+    /// Skip the whitespaces, and peek the next byte.
+    fn peek_non_whitespace(&mut self) -> std::io::Result<Option<u8>> {
+        loop {
+            let chunk = self.reader.fill_buf()?;
+            if chunk.is_empty() {
+                return Ok(None);
+            }
+            match chunk.iter().position(|byte| !byte.is_ascii_whitespace()) {
+                Some(position) => {
+                    let byte = chunk[position];
+                    self.cursor_line += bytecount(&chunk[..position]);
+                    self.reader.consume(position);
+                    return Ok(Some(byte));
+                }
+                None => {
+                    let length = chunk.len();
+                    self.cursor_line += bytecount(chunk);
+                    self.reader.consume(length);
+                }
+            }
+        }
+    }
+
+    /// Read the next non-blank line into the buffer.
+    fn read_line(&mut self) -> Result<bool, serde_json::Error> {
+        // Read one byte more than the limit and the newline, to find the records exceeding it.
+        let limit = self
+            .max_record_size
+            .map_or(u64::MAX, |max_record_size| max_record_size as u64 + 2);
+
+        loop {
+            self.buffer.clear();
+            if (&mut self.reader)
+                .take(limit)
+                .read_until(b'\n', &mut self.buffer)
+                .map_err(serde_json::Error::io)?
+                == 0
+            {
+                return Ok(false);
+            }
+            self.record_line = self.cursor_line;
+            let record = self.buffer.strip_suffix(b"\n").unwrap_or(&self.buffer);
+            check_record_size(record.len(), self.max_record_size)?;
+            self.cursor_line += 1;
+            if !self.buffer.iter().all(u8::is_ascii_whitespace) {
+                return Ok(true);
+            }
+        }
+    }
+
+    /// Read the next element of the array into the buffer.
+    ///
+    /// Returns `false` when the end of the array is reached.
+    fn read_element(&mut self) -> Result<bool, serde_json::Error> {
+        if !self.opened {
+            if self.peek_non_whitespace().map_err(serde_json::Error::io)? != Some(b'[') {
+                return Err(serde_json::Error::custom(
+                    "expected `[` at the start of the stream",
+                ));
+            }
+            self.reader.consume(1);
+            self.opened = true;
+
+            if self.peek_non_whitespace().map_err(serde_json::Error::io)? == Some(b']') {
+                self.reader.consume(1);
+                self.closed = true;
+            }
+        }
+
+        if self.closed {
+            return self.read_end().map(|_| false);
+        }
+
+        self.peek_non_whitespace().map_err(serde_json::Error::io)?;
+        self.buffer.clear();
+        self.record_line = self.cursor_line;
+
+        let mut depth = 0usize;
+        let mut in_string = false;
+        let mut escaped = false;
+        loop {
+            let chunk = self.reader.fill_buf().map_err(serde_json::Error::io)?;
+            if chunk.is_empty() {
+                return Err(serde_json::Error::custom("EOF while parsing the array"));
+            }
+
+            let mut delimiter = None;
+            for (position, &byte) in chunk.iter().enumerate() {
+                if in_string {
+                    match byte {
+                        _ if escaped => escaped = false,
+                        b'\\' => escaped = true,
+                        b'"' => in_string = false,
+                        _ => {}
+                    }
+                    continue;
+                }
+                match byte {
+                    b'"' => in_string = true,
+                    b'{' | b'[' => depth += 1,
+                    b'}' | b']' if depth > 0 => depth -= 1,
+                    b',' | b']' if depth == 0 => {
+                        delimiter = Some((position, byte));
+                        break;
+                    }
+                    _ => {}
+                }
+            }
+
+            let length = delimiter.map_or(chunk.len(), |(position, _)| position);
+            check_record_size(self.buffer.len() + length, self.max_record_size)?;
+
+            match delimiter {
+                Some((position, byte)) => {
+                    self.buffer.extend_from_slice(&chunk[..position]);
+                    self.cursor_line += bytecount(&chunk[..=position]);
+                    self.reader.consume(position + 1);
+                    self.closed = byte == b']';
+                    return Ok(true);
+                }
+                None => {
+                    let length = chunk.len();
+                    self.buffer.extend_from_slice(chunk);
+                    self.cursor_line += bytecount(chunk);
+                    self.reader.consume(length);
+                }
+            }
+        }
+    }
+
+    /// Check that nothing follows the array.
+    fn read_end(&mut self) -> Result<(), serde_json::Error> {
+        match self.peek_non_whitespace().map_err(serde_json::Error::io)? {
+            Some(_) => Err(serde_json::Error::custom(
+                "trailing characters after the array",
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<'a, T> Iterator for StreamValidator<'a, T>
+where
+    T: serde::de::DeserializeOwned + crate::Validate,
+{
+    type Item = Result<T, (usize, crate::Error<serde_json::Error>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        if self.layout == Layout::Detect {
+            self.layout = match self.peek_non_whitespace() {
+                Ok(Some(b'[')) => Layout::Array,
+                Ok(_) => Layout::Lines,
+                Err(error) => return Some(Err(self.fail(serde_json::Error::io(error)))),
+            };
+        }
+
+        let read = match self.layout {
+            Layout::Lines => self.read_line(),
+            _ => self.read_element(),
+        };
+        match read {
+            Ok(true) => {}
+            Ok(false) => {
+                self.finished = true;
+                return None;
+            }
+            Err(error) => {
+                self.record_line = self.cursor_line;
+                return Some(Err(self.fail(error)));
+            }
+        }
+
+        let index = self.index;
+        self.index += 1;
+
+        Some(validate_record(&self.buffer).map_err(|error| (index, error)))
+    }
+}
+
+impl<'a, T> StreamValidator<'a, T> {
+    /// End the iteration with the error which cannot be recovered.
+    fn fail(&mut self, error: serde_json::Error) -> (usize, crate::Error<serde_json::Error>) {
+        self.finished = true;
+        (self.index, crate::Error::from(error))
+    }
+}
+
+fn validate_record<T>(record: &[u8]) -> Result<T, crate::Error<serde_json::Error>>
+where
+    T: serde::de::DeserializeOwned + crate::Validate,
+{
+    let mut deserializer = serde_json::Deserializer::from_slice(record);
+    let model: T = crate::error::deserialize_with_path(&mut deserializer)?;
+    deserializer.end()?;
+    model.validate().map_err(crate::Error::ValidationError)?;
+    Ok(model)
+}
+
+fn check_record_size(
+    record_size: usize,
+    max_record_size: Option<usize>,
+) -> Result<(), serde_json::Error> {
+    match max_record_size {
+        Some(max_record_size) if record_size > max_record_size => Err(serde_json::Error::custom(
+            format!("the record exceeds `{max_record_size}` bytes"),
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn bytecount(bytes: &[u8]) -> usize {
+    bytes.iter().filter(|&&byte| byte == b'\n').count()
+}
@@ -38,6 +38,12 @@
 //!
 //! - `toml` - provide serialization/deserialization in `toml` format.
 //! - `yaml` - provide serialization/deserialization in `yaml` format.
+//! - `msgpack` - provide serialization/deserialization in `MessagePack` format.
+//! - `cbor` - provide serialization/deserialization in `CBOR` format.
+//! - `json5` - provide serialization/deserialization in `JSON5` format.
+//! - `ron` - provide serialization/deserialization in `RON` format.
+//! - `csv` - provide validation of `CSV` records row by row.
+//! - `env` - provide deserialization from the environment variables.
 //! - `i128` - support `i128`/`u128` type (default).
 //! - `flatten` - change formatting to flattened error messages ( [jsonschema](https://docs.rs/jsonschema/latest/jsonschema/) crate style).
 //!
@@ -50,6 +56,7 @@
 //! | String  | `#[validate(max_length = 5)]`        | [`ValidateMaxLength`]        | [maxLength](https://json-schema.org/understanding-json-schema/reference/string#length)        |
 //! | String  | `#[validate(min_length = 5)]`        | [`ValidateMinLength`]        | [minLength](https://json-schema.org/understanding-json-schema/reference/string#length)        |
 //! | String  | `#[validate(pattern = r"^\d{5}$")]`  | [`ValidatePattern`]          | [pattern](https://json-schema.org/understanding-json-schema/reference/string#regexp)          |
+//! | String  | `#[validate(format = "email")]`      | [`ValidateFormat`]           | [format](https://json-schema.org/understanding-json-schema/reference/string#format)           |
 //! | Numeric | `#[validate(maximum = 5)]`           | [`ValidateMaximum`]          | [maximum](https://json-schema.org/understanding-json-schema/reference/numeric#range)          |
 //! | Numeric | `#[validate(minimum = 5)]`           | [`ValidateMinimum`]          | [minimum](https://json-schema.org/understanding-json-schema/reference/numeric#range)          |
 //! | Numeric | `#[validate(exclusive_maximum = 5)]` | [`ValidateExclusiveMaximum`] | [exclusiveMaximum](https://json-schema.org/understanding-json-schema/reference/numeric#range) |
@@ -60,7 +67,41 @@
 //! | Array   | `#[validate(max_items = 5)]`         | [`ValidateMaxItems`]         | [maxItems](https://json-schema.org/understanding-json-schema/reference/array#length)          |
 //! | Array   | `#[validate(min_items = 5)]`         | [`ValidateMinItems`]         | [minItems](https://json-schema.org/understanding-json-schema/reference/array#length)          |
 //! | Array   | `#[validate(unique_items)]`          | [`ValidateUniqueItems`]      | [uniqueItems](https://json-schema.org/understanding-json-schema/reference/array#uniqueItems)  |
+//! | Array   | `#[validate(contains(custom(f)))]`   | [`ValidateContains`]         | [contains](https://json-schema.org/understanding-json-schema/reference/array#contains)        |
 //! | Generic | `#[validate(enumerate(5, 10, 15))]`  | [`ValidateEnumerate`]        | [enum](https://json-schema.org/understanding-json-schema/reference/enum)                      |
+//! | Generic | `#[validate(const = 5)]`             | [`ValidateConst`]            | [const](https://json-schema.org/understanding-json-schema/reference/const)                    |
+//! | Generic | `#[validate(required)]`              | [`ValidateRequired`]         | [required](https://json-schema.org/understanding-json-schema/reference/object#required)       |
+//!
+//! The numeric limits, such as `max_length`, `maximum`, `min_items` and `multiple_of`,
+//! also accept consts, statics, const expressions and `fn() -> T` getters,
+//! so that the limits can be shared or loaded from the configuration at startup.
+//!
+//! ```rust
+//! use serde_valid::Validate;
+//!
+//! const MAX_NAME_LENGTH: usize = 8;
+//!
+//! fn max_tags() -> usize {
+//!     // load from the configuration here.
+//!     2
+//! }
+//!
+//! #[derive(Validate)]
+//! struct User {
+//!     #[validate(max_length = MAX_NAME_LENGTH)]
+//!     #[validate(min_length = MAX_NAME_LENGTH / 4)]
+//!     name: String,
+//!     #[validate(max_items = max_tags)]
+//!     tags: Vec<String>,
+//! }
+//!
+//! let s = User {
+//!     name: "taro".to_owned(),
+//!     tags: vec!["a".to_owned(), "b".to_owned(), "c".to_owned()],
+//! };
+//!
+//! assert!(s.validate().is_err());
+//! ```
 //!
 //! ## Complete Constructor (Deserialization)
 //!
@@ -98,6 +139,18 @@
 //! You can force validation by only deserialization through `serde_valid`, and removing
 //! `serde_json` from `Cargo.toml` of your project.
 //!
+//! When the deserialization fails, `as_serde_error_path` returns the [JSON Pointer](validation::JsonPointer)
+//! to the invalid value, such as `/items/1`, in the same format as the validation errors.
+//!
+//! To report all the errors in one pass, use `from_json_value_lenient`. The type mismatches,
+//! the missing fields and the values rejected by `Deserialize` are returned as the validation errors
+//! at their locations, together with the validation errors of the rest of the fields.
+//! `from_json_value_lenient_with` takes the `ValidationOptions`, whose `max_errors` also limits the replaced values.
+//!
+//! For newline-delimited JSON and large top-level JSON arrays, [`serde_valid::json::StreamValidator`](json::StreamValidator)
+//! deserializes and validates the records one by one without loading the whole input,
+//! and yields each failure with the index of the record.
+//!
 //! ## Serialization
 //!
 //! For serialization, provides [`serde_valid::json::ToJsonString`](json::ToJsonString) trait.
@@ -238,6 +291,158 @@
 //! assert!(s.validate().is_ok());
 //! ```
 //!
+//! ## Validation Groups
+//! You can restrict a field validation to groups with `groups(...)`, and select the groups at call time
+//! with `validate_group` or `validate_with`. Validations without groups always run,
+//! and nested `#[validate]` fields are validated with the same groups.
+//!
+//! ```rust
+//! use serde_valid::{Validate, ValidationOptions};
+//!
+//! #[derive(Validate)]
+//! struct User {
+//!     #[validate(max_length = 0, groups(create))]
+//!     id: String,
+//!     #[validate(min_length = 1, groups(create))]
+//!     #[validate(max_length = 32)]
+//!     name: String,
+//! }
+//!
+//! let s = User {
+//!     id: "".to_owned(),
+//!     name: "".to_owned(),
+//! };
+//!
+//! assert!(s.validate().is_ok());
+//! assert!(s.validate_group("update").is_ok());
+//! assert!(s.validate_group("create").is_err());
+//! assert!(s.validate_with(&ValidationOptions::new().with_group("create")).is_err());
+//! ```
+//!
+//! ## Fail Fast
+//! For large inputs, you can stop the validation at the first error with `with_fail_fast`,
+//! after N errors with `with_max_errors`, or after N items of `Vec`, `HashMap` and `IndexMap` with errors
+//! with `with_max_error_items`. When some validations are skipped, the top-level errors end with
+//! the `truncated` error.
+//!
+//! ```rust
+//! use serde_json::json;
+//! use serde_valid::{Validate, ValidationOptions};
+//!
+//! #[derive(Validate)]
+//! struct Record {
+//!     #[validate(max_length = 3)]
+//!     name: String,
+//!     #[validate(maximum = 10)]
+//!     value: i32,
+//! }
+//!
+//! let s = vec![
+//!     Record { name: "taro".to_owned(), value: 100 },
+//!     Record { name: "jiro".to_owned(), value: 100 },
+//! ];
+//!
+//! assert_eq!(
+//!     s.validate_with(&ValidationOptions::new().with_max_error_items(1))
+//!         .unwrap_err()
+//!         .to_string(),
+//!     json!({
+//!         "errors": ["The validation was stopped after `1` items with errors."],
+//!         "items": {
+//!             "0": {
+//!                 "errors": [],
+//!                 "properties": {
+//!                     "name": {"errors": ["The length of the value must be `<= 3`."]},
+//!                     "value": {"errors": ["The number must be `<= 10`."]}
+//!                 }
+//!             }
+//!         }
+//!     })
+//!     .to_string()
+//! );
+//! ```
+//!
+//! ## Context Validation
+//! Validations that need runtime data can use `#[validate(context = MyContext)]` on the container.
+//! Then the type implements `ValidateWithContext<MyContext>` instead of `Validate`,
+//! the `custom` validations receive `&MyContext`, and nested `#[validate]` fields get the same context.
+//! The trait is not named `ValidateWith<Ctx>`, because its methods would be confused with `Validate::validate_with(options)`.
+//!
+//! The types deriving `Validate` without the context can be nested with `#[validate]`,
+//! while the types implementing `Validate` manually need `#[validate(nested_without_context)]`,
+//! which runs their `Validate` validations.
+//!
+//! ```rust
+//! use serde_valid::json::{json, FromJsonValueWithContext};
+//! use serde_valid::{Validate, ValidateWithContext};
+//!
+//! struct Plan {
+//!     max_items: usize,
+//! }
+//!
+//! fn max_items_by_plan(items: &[String], plan: &Plan) -> Result<(), serde_valid::validation::Error> {
+//!     if items.len() <= plan.max_items {
+//!         Ok(())
+//!     } else {
+//!         Err(serde_valid::validation::Error::Custom(
+//!             "Too many items for the plan.".to_owned(),
+//!         ))
+//!     }
+//! }
+//!
+//! #[derive(Validate, serde::Deserialize)]
+//! #[validate(context = Plan)]
+//! struct Order {
+//!     #[validate(custom(|items, plan| max_items_by_plan(items, plan)))]
+//!     items: Vec<String>,
+//! }
+//!
+//! let plan = Plan { max_items: 1 };
+//! let s = Order {
+//!     items: vec!["a".to_owned()],
+//! };
+//!
+//! assert!(s.validate_with_context(&plan).is_ok());
+//! assert!(Order::from_json_value_with(json!({ "items": ["a", "b"] }), &plan).is_err());
+//! ```
+//!
+//! ## Async Validation
+//! Validations that need I/O, such as the uniqueness in the database, can use `#[validate(custom_async(...))]` with an async function.
+//! They run only with `AsyncValidate::validate_async`, and the errors are merged with the other validations.
+//! Use `#[validate(nested_async)]` instead of `#[validate]` to await the async validations of the nested field.
+//! `#[derive(Validate)]` implements `AsyncValidate` for every type, and `axum_serde_valid::Json` awaits it during the extraction.
+//!
+//! ```rust
+//! use serde_valid::{AsyncValidate, Validate};
+//!
+//! async fn unique_name(name: &String) -> Result<(), serde_valid::validation::Error> {
+//!     // query the database here.
+//!     if name != "taken" {
+//!         Ok(())
+//!     } else {
+//!         Err(serde_valid::validation::Error::Custom(
+//!             "The name is already taken.".to_owned(),
+//!         ))
+//!     }
+//! }
+//!
+//! #[derive(Validate)]
+//! struct User {
+//!     #[validate(max_length = 8)]
+//!     #[validate(custom_async(unique_name))]
+//!     name: String,
+//! }
+//!
+//! # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+//! let s = User {
+//!     name: "taken".to_owned(),
+//! };
+//!
+//! assert!(s.validate().is_ok());
+//! assert!(s.validate_async().await.is_err());
+//! # });
+//! ```
+//!
 //! ## Multi Fields Validation
 //! ### Custom Validation
 //! Now, you can use `#[validate(custom)]` for multi fields validation.
@@ -575,6 +780,202 @@
 //!     .to_string()
 //! );
 //! ```
+//!
+//! ### Tagged Enum
+//! `#[serde(tag = "...", content = "...")]` (adjacently tagged) enums output the variant errors
+//! under the content key, like the serialized format.
+//! `#[serde(tag = "...")]` (internally tagged) and `#[serde(untagged)]` enums output them like the enums above.
+//!
+//! ```rust
+//! use serde::Deserialize;
+//! use serde_json::json;
+//! use serde_valid::Validate;
+//!
+//! #[derive(Validate, Deserialize)]
+//! #[serde(tag = "type", content = "data")]
+//! enum Data {
+//!     Named {
+//!         #[validate(maximum = 5)]
+//!         a: i32,
+//!     },
+//! }
+//!
+//! let s = Data::Named { a: 6 };
+//!
+//! assert_eq!(
+//!     s.validate().unwrap_err().to_string(),
+//!     json!({
+//!         "errors": [],
+//!         "properties": {
+//!             "data": {
+//!                 "errors": [],
+//!                 "properties": {
+//!                     "a": {
+//!                         "errors": ["The number must be `<= 5`."]
+//!                     }
+//!                 }
+//!             }
+//!         }
+//!     })
+//!     .to_string()
+//! );
+//! ```
+//!
+//! The externally tagged enums, serde's default, output the variant errors under the variant name
+//! with `#[validate(externally_tagged)]`. The variant name follows `#[serde(rename = "...")]`
+//! and `#[serde(rename_all = "...")]`.
+//!
+//! ```rust
+//! use serde::Deserialize;
+//! use serde_json::json;
+//! use serde_valid::Validate;
+//!
+//! #[derive(Validate, Deserialize)]
+//! #[serde(rename_all = "snake_case")]
+//! #[validate(externally_tagged)]
+//! enum Data {
+//!     NewType(#[validate(maximum = 5)] i32),
+//! }
+//!
+//! let s = Data::NewType(6);
+//!
+//! assert_eq!(
+//!     s.validate().unwrap_err().to_string(),
+//!     json!({
+//!         "errors": [],
+//!         "properties": {
+//!             "new_type": {
+//!                 "errors": ["The number must be `<= 5`."]
+//!             }
+//!         }
+//!     })
+//!     .to_string()
+//! );
+//! ```
+//!
+//! ### Error Codes
+//! `Errors::to_detailed_json` outputs each error with a stable keyword code and its typed params,
+//! so that clients can render their own messages.
+//! Custom validations can supply their own code and params by returning `CustomError`.
+//! With the `fluent` feature, the code is also used as the fluent id and the params as the fluent args.
+//!
+//! ```rust
+//! use serde_json::json;
+//! use serde_valid::{CustomError, Validate};
+//!
+//! fn unique_name(name: &str) -> Result<(), CustomError> {
+//!     Err(CustomError::new("unique_name", "The name is already taken.").with_param("name", name))
+//! }
+//!
+//! #[derive(Validate)]
+//! struct Data {
+//!     #[validate(maximum = 100)]
+//!     val: i32,
+//!     #[validate(custom(unique_name))]
+//!     name: String,
+//! }
+//!
+//! let s = Data {
+//!     val: 101,
+//!     name: "alice".to_string(),
+//! };
+//!
+//! assert_eq!(
+//!     s.validate().unwrap_err().to_detailed_json(),
+//!     json!({
+//!         "errors": [],
+//!         "properties": {
+//!             "val": {
+//!                 "errors": [{
+//!                     "code": "maximum",
+//!                     "params": { "maximum": 100 },
+//!                     "message": "The number must be `<= 100`."
+//!                 }]
+//!             },
+//!             "name": {
+//!                 "errors": [{
+//!                     "code": "unique_name",
+//!                     "params": { "name": "alice" },
+//!                     "message": "The name is already taken."
+//!                 }]
+//!             }
+//!         }
+//!     })
+//! );
+//! ```
+//!
+//! ### JSON Schema Output
+//! `ToOutput` converts the errors into the [JSON Schema output formats](https://json-schema.org/draft/2020-12/json-schema-core#name-output-formats)
+//! (`flag`, `basic`, `detailed` and `verbose`), with the `keywordLocation` of the validation that failed.
+//!
+//! ```rust
+//! use serde_json::json;
+//! use serde_valid::validation::{OutputFormat, ToOutput};
+//! use serde_valid::Validate;
+//!
+//! #[derive(Validate)]
+//! struct Data {
+//!     #[validate(max_length = 3)]
+//!     name: String,
+//! }
+//!
+//! let s = Data {
+//!     name: "taro".to_string(),
+//! };
+//!
+//! assert_eq!(
+//!     json!(s.validate().unwrap_err().to_output(OutputFormat::Basic)),
+//!     json!({
+//!         "valid": false,
+//!         "keywordLocation": "",
+//!         "instanceLocation": "",
+//!         "errors": [{
+//!             "valid": false,
+//!             "keywordLocation": "/properties/name/maxLength",
+//!             "instanceLocation": "/name",
+//!             "error": "The length of the value must be `<= 3`."
+//!         }]
+//!     })
+//! );
+//! ```
+//!
+//! ### Error Locations
+//! `Errors` can be walked without matching the nested errors by hand.
+//! `iter` yields the errors with their [JSON Pointer](https://datatracker.ietf.org/doc/html/rfc6901) instance locations,
+//! `get` looks up the errors at a location, `count` counts them, and `visit` accepts a `Visitor`.
+//!
+//! ```rust
+//! use serde_valid::Validate;
+//!
+//! #[derive(Validate)]
+//! struct TestStruct {
+//!     #[validate]
+//!     items: Vec<TestItem>,
+//! }
+//!
+//! #[derive(Validate)]
+//! struct TestItem {
+//!     #[validate(max_length = 3)]
+//!     name: String,
+//! }
+//!
+//! let s = TestStruct {
+//!     items: vec![
+//!         TestItem { name: "abc".to_owned() },
+//!         TestItem { name: "abcd".to_owned() },
+//!     ],
+//! };
+//! let errors = s.validate().unwrap_err();
+//!
+//! assert_eq!(errors.count(), 1);
+//! assert_eq!(
+//!     errors.get("/items/1/name")[0].to_string(),
+//!     "The length of the value must be `<= 3`."
+//! );
+//! for (instance_location, error) in errors.iter() {
+//!     assert_eq!(instance_location.to_string(), "/items/1/name");
+//! }
+//! ```
 
 pub mod error;
 mod features;
@@ -585,17 +986,21 @@ pub mod validation;
 use std::collections::HashMap;
 
 use indexmap::IndexMap;
+use validation::ErrorBudget;
 
 pub use error::{
-    EnumerateError, Error, ExclusiveMaximumError, ExclusiveMinimumError, MaxItemsError,
-    MaxLengthError, MaxPropertiesError, MaximumError, MinItemsError, MinLengthError,
-    MinPropertiesError, MinimumError, MultipleOfError, PatternError, UniqueItemsError,
+    AllOfError, AnyOfError, ConstError, ContainsError, CustomError, DeserializeError,
+    EnumerateError, Error, ExclusiveMaximumError, ExclusiveMinimumError, FieldComparisonError,
+    FormatError, InvalidValueError, MaxItemsError, MaxLengthError, MaxPropertiesError,
+    MaximumError, MinItemsError, MinLengthError, MinPropertiesError, MinimumError, MultipleOfError,
+    NotError, OneOfError, PatternError, RequiredError, TruncatedError, TypeError, UniqueItemsError,
 };
 pub use validation::{
-    ValidateEnumerate, ValidateExclusiveMaximum, ValidateExclusiveMinimum, ValidateMaxItems,
-    ValidateMaxLength, ValidateMaxProperties, ValidateMaximum, ValidateMinItems, ValidateMinLength,
-    ValidateMinProperties, ValidateMinimum, ValidateMultipleOf, ValidatePattern,
-    ValidateUniqueItems,
+    ValidateConst, ValidateContains, ValidateEnumerate, ValidateExclusiveMaximum,
+    ValidateExclusiveMinimum, ValidateFieldEquality, ValidateFieldOrdering, ValidateFormat,
+    ValidateMaxItems, ValidateMaxLength, ValidateMaxProperties, ValidateMaximum, ValidateMinItems,
+    ValidateMinLength, ValidateMinProperties, ValidateMinimum, ValidateMultipleOf, ValidatePattern,
+    ValidateRequired, ValidateUniqueItems, ValidationOptions,
 };
 
 #[allow(unused_imports)]
@@ -609,6 +1014,49 @@ pub mod export {
 
 pub trait Validate {
     fn validate(&self) -> std::result::Result<(), self::validation::Errors>;
+
+    /// Validate with the options, such as the selected validation groups.
+    ///
+    /// The default implementation ignores the options.
+    fn validate_with(
+        &self,
+        _options: &ValidationOptions,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        self.validate()
+    }
+
+    /// Validate with the error budget of the outermost validation.
+    ///
+    /// The nested validations are called by this method,
+    /// and the default implementation ignores the error budget.
+    #[doc(hidden)]
+    fn validate_with_budget(
+        &self,
+        options: &ValidationOptions,
+        _budget: &ErrorBudget,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        self.validate_with(options)
+    }
+
+    /// Validate with the selected validation group.
+    ///
+    /// ```rust
+    /// use serde_valid::Validate;
+    ///
+    /// #[derive(Validate)]
+    /// struct User {
+    ///     #[validate(min_length = 1, groups(create))]
+    ///     name: String,
+    /// }
+    ///
+    /// let s = User { name: "".to_string() };
+    ///
+    /// assert!(s.validate().is_ok());
+    /// assert!(s.validate_group("create").is_err());
+    /// ```
+    fn validate_group(&self, group: &str) -> std::result::Result<(), self::validation::Errors> {
+        self.validate_with(&ValidationOptions::new().with_group(group))
+    }
 }
 
 impl<T> Validate for Vec<T>
@@ -616,11 +1064,33 @@ where
     T: Validate,
 {
     fn validate(&self) -> std::result::Result<(), self::validation::Errors> {
+        self.validate_with(&ValidationOptions::default())
+    }
+
+    fn validate_with(
+        &self,
+        options: &ValidationOptions,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        helpers::validate_with_error_budget(options, |budget| {
+            self.validate_with_budget(options, budget)
+        })
+    }
+
+    fn validate_with_budget(
+        &self,
+        options: &ValidationOptions,
+        budget: &ErrorBudget,
+    ) -> std::result::Result<(), self::validation::Errors> {
         let mut items = IndexMap::new();
 
         for (index, item) in self.iter().enumerate() {
-            if let Err(errors) = item.validate() {
+            if budget.skip() {
+                break;
+            }
+
+            if let Err(errors) = item.validate_with_budget(options, budget) {
                 items.insert(index, errors);
+                budget.add_error_item();
             }
         }
 
@@ -639,11 +1109,33 @@ where
     T: Validate,
 {
     fn validate(&self) -> std::result::Result<(), self::validation::Errors> {
+        self.validate_with(&ValidationOptions::default())
+    }
+
+    fn validate_with(
+        &self,
+        options: &ValidationOptions,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        helpers::validate_with_error_budget(options, |budget| {
+            self.validate_with_budget(options, budget)
+        })
+    }
+
+    fn validate_with_budget(
+        &self,
+        options: &ValidationOptions,
+        budget: &ErrorBudget,
+    ) -> std::result::Result<(), self::validation::Errors> {
         let mut items = IndexMap::new();
 
         for (index, item) in self.iter().enumerate() {
-            if let Err(errors) = item.validate() {
+            if budget.skip() {
+                break;
+            }
+
+            if let Err(errors) = item.validate_with_budget(options, budget) {
                 items.insert(index, errors);
+                budget.add_error_item();
             }
         }
 
@@ -663,11 +1155,33 @@ where
     for<'a> &'a K: Into<String>,
 {
     fn validate(&self) -> std::result::Result<(), self::validation::Errors> {
+        self.validate_with(&ValidationOptions::default())
+    }
+
+    fn validate_with(
+        &self,
+        options: &ValidationOptions,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        helpers::validate_with_error_budget(options, |budget| {
+            self.validate_with_budget(options, budget)
+        })
+    }
+
+    fn validate_with_budget(
+        &self,
+        options: &ValidationOptions,
+        budget: &ErrorBudget,
+    ) -> std::result::Result<(), self::validation::Errors> {
         let mut items = IndexMap::new();
 
         for (key, value) in self.iter() {
-            if let Err(errors) = value.validate() {
+            if budget.skip() {
+                break;
+            }
+
+            if let Err(errors) = value.validate_with_budget(options, budget) {
                 items.insert(key.into(), errors);
+                budget.add_error_item();
             }
         }
 
@@ -687,11 +1201,33 @@ where
     for<'a> &'a K: Into<String>,
 {
     fn validate(&self) -> std::result::Result<(), self::validation::Errors> {
+        self.validate_with(&ValidationOptions::default())
+    }
+
+    fn validate_with(
+        &self,
+        options: &ValidationOptions,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        helpers::validate_with_error_budget(options, |budget| {
+            self.validate_with_budget(options, budget)
+        })
+    }
+
+    fn validate_with_budget(
+        &self,
+        options: &ValidationOptions,
+        budget: &ErrorBudget,
+    ) -> std::result::Result<(), self::validation::Errors> {
         let mut items = IndexMap::new();
 
         for (key, value) in self.iter() {
-            if let Err(errors) = value.validate() {
+            if budget.skip() {
+                break;
+            }
+
+            if let Err(errors) = value.validate_with_budget(options, budget) {
                 items.insert(key.into(), errors);
+                budget.add_error_item();
             }
         }
 
@@ -710,17 +1246,599 @@ where
     T: Validate,
 {
     fn validate(&self) -> std::result::Result<(), self::validation::Errors> {
+        self.validate_with(&ValidationOptions::default())
+    }
+
+    fn validate_with(
+        &self,
+        options: &ValidationOptions,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        match self {
+            Some(value) => value.validate_with(options),
+            None => Ok(()),
+        }
+    }
+
+    fn validate_with_budget(
+        &self,
+        options: &ValidationOptions,
+        budget: &ErrorBudget,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        match self {
+            Some(value) => value.validate_with_budget(options, budget),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Validation with the runtime context, such as the tenant settings.
+///
+/// `#[derive(Validate)]` with `#[validate(context = MyContext)]` implements this trait
+/// instead of [`Validate`], and the `custom` validations receive `&MyContext`.
+/// Types deriving [`Validate`] without the context implement this trait for any context,
+/// so they can be nested in the context-aware types.
+/// Types implementing [`Validate`] manually do not, so nest them with `#[validate(nested_without_context)]`.
+///
+/// ```rust
+/// use serde_valid::ValidateWithContext;
+///
+/// struct Tenant {
+///     currencies: Vec<String>,
+/// }
+///
+/// fn enabled_currency(
+///     currency: &String,
+///     tenant: &Tenant,
+/// ) -> Result<(), serde_valid::validation::Error> {
+///     if tenant.currencies.contains(currency) {
+///         Ok(())
+///     } else {
+///         Err(serde_valid::validation::Error::Custom(
+///             "The currency is not enabled.".to_string(),
+///         ))
+///     }
+/// }
+///
+/// #[derive(serde_valid::Validate)]
+/// #[validate(context = Tenant)]
+/// struct Payment {
+///     #[validate(custom(enabled_currency))]
+///     currency: String,
+/// }
+///
+/// let tenant = Tenant {
+///     currencies: vec!["JPY".to_string()],
+/// };
+///
+/// assert!(Payment { currency: "JPY".to_string() }
+///     .validate_with_context(&tenant)
+///     .is_ok());
+/// assert!(Payment { currency: "USD".to_string() }
+///     .validate_with_context(&tenant)
+///     .is_err());
+/// ```
+pub trait ValidateWithContext<Ctx: ?Sized> {
+    fn validate_with_context(
+        &self,
+        context: &Ctx,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        self.validate_with_context_and_options(context, &ValidationOptions::default())
+    }
+
+    fn validate_with_context_and_options(
+        &self,
+        context: &Ctx,
+        options: &ValidationOptions,
+    ) -> std::result::Result<(), self::validation::Errors>;
+
+    /// Validate with the error budget of the outermost validation.
+    ///
+    /// The nested validations are called by this method,
+    /// and the default implementation ignores the error budget.
+    #[doc(hidden)]
+    fn validate_with_context_and_budget(
+        &self,
+        context: &Ctx,
+        options: &ValidationOptions,
+        _budget: &ErrorBudget,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        self.validate_with_context_and_options(context, options)
+    }
+}
+
+impl<T, Ctx> ValidateWithContext<Ctx> for Vec<T>
+where
+    T: ValidateWithContext<Ctx>,
+    Ctx: ?Sized,
+{
+    fn validate_with_context_and_options(
+        &self,
+        context: &Ctx,
+        options: &ValidationOptions,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        helpers::validate_with_error_budget(options, |budget| {
+            self.validate_with_context_and_budget(context, options, budget)
+        })
+    }
+
+    fn validate_with_context_and_budget(
+        &self,
+        context: &Ctx,
+        options: &ValidationOptions,
+        budget: &ErrorBudget,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        let mut items = IndexMap::new();
+
+        for (index, item) in self.iter().enumerate() {
+            if budget.skip() {
+                break;
+            }
+
+            if let Err(errors) = item.validate_with_context_and_budget(context, options, budget) {
+                items.insert(index, errors);
+                budget.add_error_item();
+            }
+        }
+
+        if items.is_empty() {
+            Ok(())
+        } else {
+            Err(self::validation::Errors::Array(
+                validation::error::ArrayErrors::new(vec![], items),
+            ))
+        }
+    }
+}
+
+impl<T, Ctx, const N: usize> ValidateWithContext<Ctx> for [T; N]
+where
+    T: ValidateWithContext<Ctx>,
+    Ctx: ?Sized,
+{
+    fn validate_with_context_and_options(
+        &self,
+        context: &Ctx,
+        options: &ValidationOptions,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        helpers::validate_with_error_budget(options, |budget| {
+            self.validate_with_context_and_budget(context, options, budget)
+        })
+    }
+
+    fn validate_with_context_and_budget(
+        &self,
+        context: &Ctx,
+        options: &ValidationOptions,
+        budget: &ErrorBudget,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        let mut items = IndexMap::new();
+
+        for (index, item) in self.iter().enumerate() {
+            if budget.skip() {
+                break;
+            }
+
+            if let Err(errors) = item.validate_with_context_and_budget(context, options, budget) {
+                items.insert(index, errors);
+                budget.add_error_item();
+            }
+        }
+
+        if items.is_empty() {
+            Ok(())
+        } else {
+            Err(self::validation::Errors::Array(
+                validation::error::ArrayErrors::new(vec![], items),
+            ))
+        }
+    }
+}
+
+impl<K, V, Ctx> ValidateWithContext<Ctx> for HashMap<K, V>
+where
+    V: ValidateWithContext<Ctx>,
+    for<'a> &'a K: Into<String>,
+    Ctx: ?Sized,
+{
+    fn validate_with_context_and_options(
+        &self,
+        context: &Ctx,
+        options: &ValidationOptions,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        helpers::validate_with_error_budget(options, |budget| {
+            self.validate_with_context_and_budget(context, options, budget)
+        })
+    }
+
+    fn validate_with_context_and_budget(
+        &self,
+        context: &Ctx,
+        options: &ValidationOptions,
+        budget: &ErrorBudget,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        let mut items = IndexMap::new();
+
+        for (key, value) in self.iter() {
+            if budget.skip() {
+                break;
+            }
+
+            if let Err(errors) = value.validate_with_context_and_budget(context, options, budget) {
+                items.insert(key.into(), errors);
+                budget.add_error_item();
+            }
+        }
+
+        if items.is_empty() {
+            Ok(())
+        } else {
+            Err(self::validation::Errors::Object(
+                validation::error::ObjectErrors::new(vec![], items),
+            ))
+        }
+    }
+}
+
+impl<K, V, Ctx> ValidateWithContext<Ctx> for IndexMap<K, V>
+where
+    V: ValidateWithContext<Ctx>,
+    for<'a> &'a K: Into<String>,
+    Ctx: ?Sized,
+{
+    fn validate_with_context_and_options(
+        &self,
+        context: &Ctx,
+        options: &ValidationOptions,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        helpers::validate_with_error_budget(options, |budget| {
+            self.validate_with_context_and_budget(context, options, budget)
+        })
+    }
+
+    fn validate_with_context_and_budget(
+        &self,
+        context: &Ctx,
+        options: &ValidationOptions,
+        budget: &ErrorBudget,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        let mut items = IndexMap::new();
+
+        for (key, value) in self.iter() {
+            if budget.skip() {
+                break;
+            }
+
+            if let Err(errors) = value.validate_with_context_and_budget(context, options, budget) {
+                items.insert(key.into(), errors);
+                budget.add_error_item();
+            }
+        }
+
+        if items.is_empty() {
+            Ok(())
+        } else {
+            Err(self::validation::Errors::Object(
+                validation::ObjectErrors::new(vec![], items),
+            ))
+        }
+    }
+}
+
+impl<T, Ctx> ValidateWithContext<Ctx> for Option<T>
+where
+    T: ValidateWithContext<Ctx>,
+    Ctx: ?Sized,
+{
+    fn validate_with_context_and_options(
+        &self,
+        context: &Ctx,
+        options: &ValidationOptions,
+    ) -> std::result::Result<(), self::validation::Errors> {
         match self {
-            Some(value) => value.validate(),
+            Some(value) => value.validate_with_context_and_options(context, options),
             None => Ok(()),
         }
     }
+
+    fn validate_with_context_and_budget(
+        &self,
+        context: &Ctx,
+        options: &ValidationOptions,
+        budget: &ErrorBudget,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        match self {
+            Some(value) => value.validate_with_context_and_budget(context, options, budget),
+            None => Ok(()),
+        }
+    }
+}
+
+/// The future of [`AsyncValidate`].
+pub type ValidateFuture<'a> = std::pin::Pin<
+    Box<
+        dyn std::future::Future<Output = std::result::Result<(), self::validation::Errors>>
+            + Send
+            + 'a,
+    >,
+>;
+
+/// Validation awaiting the I/O-backed rules, such as the uniqueness in the database.
+///
+/// `#[derive(Validate)]` implements this trait for every type without `#[validate(context = ...)]`,
+/// so that extractors such as `axum_serde_valid::Json` can always await the validations.
+/// The types with `#[validate(custom_async(...))]` or `#[validate(nested_async)]` fields
+/// merge the async errors into the same [`validation::Errors`] as the other validations.
+/// The `custom_async` validations are skipped by [`Validate::validate`],
+/// and the nested fields with `#[validate]` run only [`Validate`].
+///
+/// The default implementation runs [`Validate::validate_with`],
+/// so the types implementing [`Validate`] manually can implement this trait with `impl AsyncValidate for MyType {}`.
+///
+/// ```rust
+/// use serde_valid::{AsyncValidate, Validate};
+///
+/// async fn unique_name(name: &String) -> Result<(), serde_valid::validation::Error> {
+///     if name != "taken" {
+///         Ok(())
+///     } else {
+///         Err(serde_valid::validation::Error::Custom(
+///             "The name is already taken.".to_string(),
+///         ))
+///     }
+/// }
+///
+/// #[derive(Validate)]
+/// struct User {
+///     #[validate(max_length = 8)]
+///     #[validate(custom_async(unique_name))]
+///     name: String,
+/// }
+///
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// let s = User { name: "taken".to_string() };
+///
+/// assert!(s.validate().is_ok());
+/// assert!(s.validate_async().await.is_err());
+/// # });
+/// ```
+pub trait AsyncValidate: Validate {
+    fn validate_async(&self) -> ValidateFuture<'_>
+    where
+        Self: Sync,
+    {
+        self.validate_async_with(&ValidationOptions::default())
+    }
+
+    /// Validate asynchronously with the options, such as the selected validation groups.
+    fn validate_async_with<'a>(&'a self, options: &ValidationOptions) -> ValidateFuture<'a>
+    where
+        Self: Sync,
+    {
+        let options = options.clone();
+
+        Box::pin(async move {
+            let budget = ErrorBudget::new(&options);
+            let result = self.validate_async_with_budget(&options, &budget).await;
+
+            helpers::report_truncation(&budget, result)
+        })
+    }
+
+    /// Validate asynchronously with the error budget of the outermost validation.
+    ///
+    /// The nested validations are called by this method,
+    /// and the default implementation runs [`Validate::validate_with_budget`].
+    #[doc(hidden)]
+    fn validate_async_with_budget<'a>(
+        &'a self,
+        options: &'a ValidationOptions,
+        budget: &'a ErrorBudget,
+    ) -> ValidateFuture<'a> {
+        Box::pin(std::future::ready(
+            self.validate_with_budget(options, budget),
+        ))
+    }
+}
+
+impl<T> AsyncValidate for Vec<T>
+where
+    T: AsyncValidate + Sync,
+{
+    fn validate_async_with_budget<'a>(
+        &'a self,
+        options: &'a ValidationOptions,
+        budget: &'a ErrorBudget,
+    ) -> ValidateFuture<'a> {
+        Box::pin(async move {
+            let mut items = IndexMap::new();
+
+            for (index, item) in self.iter().enumerate() {
+                if budget.skip() {
+                    break;
+                }
+
+                if let Err(errors) = item.validate_async_with_budget(options, budget).await {
+                    items.insert(index, errors);
+                    budget.add_error_item();
+                }
+            }
+
+            if items.is_empty() {
+                Ok(())
+            } else {
+                Err(self::validation::Errors::Array(
+                    validation::error::ArrayErrors::new(vec![], items),
+                ))
+            }
+        })
+    }
+}
+
+impl<T, const N: usize> AsyncValidate for [T; N]
+where
+    T: AsyncValidate + Sync,
+{
+    fn validate_async_with_budget<'a>(
+        &'a self,
+        options: &'a ValidationOptions,
+        budget: &'a ErrorBudget,
+    ) -> ValidateFuture<'a> {
+        Box::pin(async move {
+            let mut items = IndexMap::new();
+
+            for (index, item) in self.iter().enumerate() {
+                if budget.skip() {
+                    break;
+                }
+
+                if let Err(errors) = item.validate_async_with_budget(options, budget).await {
+                    items.insert(index, errors);
+                    budget.add_error_item();
+                }
+            }
+
+            if items.is_empty() {
+                Ok(())
+            } else {
+                Err(self::validation::Errors::Array(
+                    validation::error::ArrayErrors::new(vec![], items),
+                ))
+            }
+        })
+    }
+}
+
+impl<K, V> AsyncValidate for HashMap<K, V>
+where
+    K: Sync,
+    V: AsyncValidate + Sync,
+    for<'a> &'a K: Into<String>,
+{
+    fn validate_async_with_budget<'a>(
+        &'a self,
+        options: &'a ValidationOptions,
+        budget: &'a ErrorBudget,
+    ) -> ValidateFuture<'a> {
+        Box::pin(async move {
+            let mut items = IndexMap::new();
+
+            for (key, value) in self.iter() {
+                if budget.skip() {
+                    break;
+                }
+
+                if let Err(errors) = value.validate_async_with_budget(options, budget).await {
+                    items.insert(key.into(), errors);
+                    budget.add_error_item();
+                }
+            }
+
+            if items.is_empty() {
+                Ok(())
+            } else {
+                Err(self::validation::Errors::Object(
+                    validation::error::ObjectErrors::new(vec![], items),
+                ))
+            }
+        })
+    }
+}
+
+impl<K, V> AsyncValidate for IndexMap<K, V>
+where
+    K: Sync,
+    V: AsyncValidate + Sync,
+    for<'a> &'a K: Into<String>,
+{
+    fn validate_async_with_budget<'a>(
+        &'a self,
+        options: &'a ValidationOptions,
+        budget: &'a ErrorBudget,
+    ) -> ValidateFuture<'a> {
+        Box::pin(async move {
+            let mut items = IndexMap::new();
+
+            for (key, value) in self.iter() {
+                if budget.skip() {
+                    break;
+                }
+
+                if let Err(errors) = value.validate_async_with_budget(options, budget).await {
+                    items.insert(key.into(), errors);
+                    budget.add_error_item();
+                }
+            }
+
+            if items.is_empty() {
+                Ok(())
+            } else {
+                Err(self::validation::Errors::Object(
+                    validation::ObjectErrors::new(vec![], items),
+                ))
+            }
+        })
+    }
+}
+
+impl<T> AsyncValidate for Option<T>
+where
+    T: AsyncValidate,
+{
+    fn validate_async_with_budget<'a>(
+        &'a self,
+        options: &'a ValidationOptions,
+        budget: &'a ErrorBudget,
+    ) -> ValidateFuture<'a> {
+        match self {
+            Some(value) => value.validate_async_with_budget(options, budget),
+            None => Box::pin(std::future::ready(Ok(()))),
+        }
+    }
 }
 
 pub use serde_valid_derive::Validate;
 
 #[doc(hidden)]
 pub mod helpers {
+    use indexmap::IndexMap;
+
+    use crate::validation::ErrorBudget;
+    use crate::ValidationOptions;
+
+    /// The limit of the validation, such as `#[validate(max_length = MAX_LENGTH)]`.
+    ///
+    /// The limit is the value itself, or the result of the getter function.
+    pub trait Limit<T> {
+        fn limit(self) -> T;
+    }
+
+    macro_rules! impl_limit {
+        ($($ty:ty),*) => {
+            $(
+                impl Limit<$ty> for $ty {
+                    #[inline]
+                    fn limit(self) -> $ty {
+                        self
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_limit!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, f32, f64);
+    #[cfg(feature = "i128")]
+    impl_limit!(i128, u128);
+
+    impl<T, F> Limit<T> for F
+    where
+        F: FnOnce() -> T,
+    {
+        #[inline]
+        fn limit(self) -> T {
+            self()
+        }
+    }
+
     /// This function is used to avoid [rustc(E0282)](https://doc.rust-lang.org/error_codes/E0282.html) error in `#[validate(custom)]` validator on the struct.
     #[inline]
     pub fn wrap_closure_validation<T>(
@@ -729,6 +1847,158 @@ pub mod helpers {
     ) -> Result<(), crate::validation::Error> {
         f(data)
     }
+
+    /// This function is used to avoid [rustc(E0282)](https://doc.rust-lang.org/error_codes/E0282.html) error in `#[validate(custom)]` validator on the struct with `#[validate(context = ...)]`.
+    #[inline]
+    pub fn wrap_closure_validation_with_context<T, Ctx: ?Sized>(
+        data: &T,
+        context: &Ctx,
+        f: impl FnOnce(&T, &Ctx) -> Result<(), crate::validation::Error>,
+    ) -> Result<(), crate::validation::Error> {
+        f(data, context)
+    }
+
+    /// This function is used to avoid [rustc(E0282)](https://doc.rust-lang.org/error_codes/E0282.html) error in `#[validate(custom_async)]` validator.
+    #[inline]
+    pub fn wrap_closure_async_validation<'a, T: ?Sized, F>(
+        data: &'a T,
+        f: impl FnOnce(&'a T) -> F,
+    ) -> F
+    where
+        F: std::future::Future<Output = Result<(), crate::validation::Error>>,
+    {
+        f(data)
+    }
+    /// Run the outermost validation with the new error budget,
+    /// so the options can be reused across the calls and the threads.
+    pub fn validate_with_error_budget(
+        options: &ValidationOptions,
+        validate: impl FnOnce(&ErrorBudget) -> Result<(), crate::validation::Errors>,
+    ) -> Result<(), crate::validation::Errors> {
+        let budget = ErrorBudget::new(options);
+        let result = validate(&budget);
+
+        report_truncation(&budget, result)
+    }
+
+    /// Report the validations skipped by the error budget at the outermost validation.
+    pub fn report_truncation(
+        budget: &ErrorBudget,
+        result: Result<(), crate::validation::Errors>,
+    ) -> Result<(), crate::validation::Errors> {
+        if !budget.is_truncated() {
+            return result;
+        }
+
+        let error = crate::validation::Error::Truncated(crate::validation::error::Message::new(
+            crate::TruncatedError::new(budget.max_errors(), budget.max_error_items()),
+            crate::validation::error::Format::Default,
+        ));
+        match result {
+            Ok(()) => Err(crate::validation::Errors::NewType(vec![error])),
+            Err(crate::validation::Errors::Array(mut errors)) => {
+                errors.errors.push(error);
+                Err(crate::validation::Errors::Array(errors))
+            }
+            Err(crate::validation::Errors::Object(mut errors)) => {
+                errors.errors.push(error);
+                Err(crate::validation::Errors::Object(errors))
+            }
+            Err(crate::validation::Errors::NewType(mut errors)) => {
+                errors.push(error);
+                Err(crate::validation::Errors::NewType(errors))
+            }
+        }
+    }
+
+    /// Whether the validation is skipped, because the error budget is exhausted.
+    #[inline]
+    pub fn skip_validation(budget: &ErrorBudget) -> bool {
+        budget.skip()
+    }
+
+    /// Count the errors only with `max_errors`.
+    #[inline]
+    pub fn count_errors(budget: &ErrorBudget, errors: &impl CountErrors) -> usize {
+        if budget.has_max_errors() {
+            errors.count_errors()
+        } else {
+            0
+        }
+    }
+
+    /// Consume the error budget by the errors added since [`count_errors`].
+    #[inline]
+    pub fn consume_errors(budget: &ErrorBudget, count: usize, errors: &impl CountErrors) {
+        if budget.has_max_errors() {
+            budget.add_errors(errors.count_errors().saturating_sub(count));
+        }
+    }
+
+    /// The number of the errors, counting the nested errors one by one.
+    pub trait CountErrors {
+        fn count_errors(&self) -> usize;
+    }
+
+    impl CountErrors for crate::validation::Error {
+        fn count_errors(&self) -> usize {
+            match self {
+                crate::validation::Error::Items(errors) => {
+                    errors.errors.count_errors()
+                        + errors
+                            .items
+                            .values()
+                            .map(CountErrors::count_errors)
+                            .sum::<usize>()
+                }
+                crate::validation::Error::Properties(errors) => {
+                    errors.errors.count_errors()
+                        + errors
+                            .properties
+                            .values()
+                            .map(CountErrors::count_errors)
+                            .sum::<usize>()
+                }
+                _ => 1,
+            }
+        }
+    }
+
+    impl CountErrors for crate::validation::Errors {
+        fn count_errors(&self) -> usize {
+            match self {
+                crate::validation::Errors::Array(errors) => {
+                    errors.errors.count_errors()
+                        + errors
+                            .items
+                            .values()
+                            .map(CountErrors::count_errors)
+                            .sum::<usize>()
+                }
+                crate::validation::Errors::Object(errors) => {
+                    errors.errors.count_errors()
+                        + errors
+                            .properties
+                            .values()
+                            .map(CountErrors::count_errors)
+                            .sum::<usize>()
+                }
+                crate::validation::Errors::NewType(errors) => errors.count_errors(),
+            }
+        }
+    }
+
+    impl CountErrors for crate::validation::VecErrors {
+        fn count_errors(&self) -> usize {
+            self.iter().map(CountErrors::count_errors).sum()
+        }
+    }
+
+    impl<K> CountErrors for IndexMap<K, crate::validation::VecErrors> {
+        fn count_errors(&self) -> usize {
+            self.values().map(CountErrors::count_errors).sum()
+        }
+    }
 }
 
 #[cfg(test)]
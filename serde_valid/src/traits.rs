@@ -1,8 +1,10 @@
+mod is_format;
 mod is_match;
 mod is_unique;
 mod length;
 mod size;
 
+pub use is_format::IsFormat;
 pub use is_match::IsMatch;
 pub use is_unique::IsUnique;
 pub use length::Length;
@@ -0,0 +1,35 @@
+use crate::validation::StringFormat;
+
+pub trait IsFormat {
+    fn is_format(&self, format: StringFormat) -> bool;
+}
+
+macro_rules! impl_for_str {
+    ($ty:ty) => {
+        impl IsFormat for $ty {
+            fn is_format(&self, format: StringFormat) -> bool {
+                format.is_valid(self)
+            }
+        }
+    };
+}
+
+impl_for_str!(str);
+impl_for_str!(&str);
+impl_for_str!(String);
+impl_for_str!(std::borrow::Cow<'_, str>);
+
+macro_rules! impl_for_os_str {
+    ($ty:ty) => {
+        impl IsFormat for $ty {
+            fn is_format(&self, format: StringFormat) -> bool {
+                format.is_valid(&self.to_string_lossy())
+            }
+        }
+    };
+}
+
+impl_for_os_str!(std::ffi::OsStr);
+impl_for_os_str!(&std::ffi::OsStr);
+impl_for_os_str!(std::ffi::OsString);
+impl_for_os_str!(std::borrow::Cow<'_, std::ffi::OsStr>);
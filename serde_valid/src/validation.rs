@@ -1,32 +1,44 @@
 mod array;
 mod composited;
 pub mod error;
+mod error_budget;
 mod generic;
 mod numeric;
 mod object;
+mod options;
 mod string;
 
 use crate::{
-    EnumerateError, ExclusiveMaximumError, ExclusiveMinimumError, MaxLengthError,
-    MaxPropertiesError, MaximumError, MinLengthError, MinPropertiesError, MinimumError,
-    MultipleOfError, PatternError,
+    ConstError, EnumerateError, ExclusiveMaximumError, ExclusiveMinimumError, FormatError,
+    MaxLengthError, MaxPropertiesError, MaximumError, MinLengthError, MinPropertiesError,
+    MinimumError, MultipleOfError, PatternError,
 };
 pub use composited::Composited;
 
-pub use array::{ValidateMaxItems, ValidateMinItems, ValidateUniqueItems};
+pub use array::{ValidateContains, ValidateMaxItems, ValidateMinItems, ValidateUniqueItems};
 pub use error::{
-    ArrayErrors, Error, Errors, IntoError, ItemErrorsMap, ItemVecErrorsMap, ObjectErrors,
-    PropertyErrorsMap, PropertyVecErrorsMap, VecErrors,
+    ArrayErrors, DetailedError, Error, ErrorDetail, ErrorParams, Errors, IntoError, ItemErrorsMap,
+    ItemVecErrorsMap, JsonPointer, JsonPointerError, ObjectErrors, Output, OutputFormat,
+    OutputUnit, PathChunk, PropertyErrorsMap, PropertyVecErrorsMap, ToDetailed, ToOutput,
+    VecErrors, Visitor,
+};
+#[doc(hidden)]
+pub use error_budget::ErrorBudget;
+pub use generic::{
+    ComparisonOperator, EqualityOperator, OrderingOperator, ValidateConst, ValidateEnumerate,
+    ValidateFieldEquality, ValidateFieldOrdering, ValidateRequired,
 };
-pub use generic::ValidateEnumerate;
 use indexmap::IndexMap;
 pub use numeric::{
     ValidateExclusiveMaximum, ValidateExclusiveMinimum, ValidateMaximum, ValidateMinimum,
     ValidateMultipleOf,
 };
 pub use object::{ValidateMaxProperties, ValidateMinProperties};
+pub use options::ValidationOptions;
 pub use serde_valid_literal::{Literal, Number, Pattern};
-pub use string::{ValidateMaxLength, ValidateMinLength, ValidatePattern};
+pub use string::{
+    StringFormat, ValidateFormat, ValidateMaxLength, ValidateMinLength, ValidatePattern,
+};
 
 macro_rules! impl_composited_validation_1args {
     (
@@ -395,6 +407,19 @@ impl_composited_validation_1args!(
         V: ValidateCompositedPattern;
 );
 
+impl_composited_validation_1args!(
+    pub trait ValidateCompositedFormat {
+        fn validate_composited_format(
+            &self,
+            format: StringFormat,
+        ) -> Result<(), Composited<FormatError>>;
+    }
+
+    impl<K, V> ValidateCompositedFormat for std::collections::HashMap<K, V>
+    where
+        V: ValidateCompositedFormat;
+);
+
 // Object
 impl_composited_validation_1args!(
     pub trait ValidateCompositedMaxProperties {
@@ -423,3 +448,9 @@ impl_composited_validation_1args!(
         ) -> Result<(), Composited<EnumerateError>>;
     }
 );
+
+impl_composited_validation_1args!(
+    pub trait ValidateCompositedConst<T> {
+        fn validate_composited_const(&self, const_value: T) -> Result<(), Composited<ConstError>>;
+    }
+);
@@ -0,0 +1,173 @@
+use crate::ContainsError;
+
+/// Contains validation of the array items.
+///
+/// See <https://json-schema.org/understanding-json-schema/reference/array#contains>
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_valid::{Validate, ValidateContains};
+///
+/// struct MyType(Vec<i32>);
+///
+/// impl ValidateContains<i32> for MyType {
+///     fn validate_contains<F>(
+///         &self,
+///         predicate: F,
+///         min_contains: usize,
+///         max_contains: Option<usize>,
+///     ) -> Result<(), serde_valid::ContainsError>
+///     where
+///         F: Fn(&i32) -> bool,
+///     {
+///         self.0.validate_contains(predicate, min_contains, max_contains)
+///     }
+/// }
+///
+/// fn is_negative(value: &i32) -> bool {
+///     *value < 0
+/// }
+///
+/// #[derive(Validate)]
+/// struct TestStruct {
+///     #[validate(contains(custom(is_negative)))]
+///     val: MyType,
+/// }
+///
+/// let s = TestStruct {
+///     val: MyType(vec![1, 2, 3]),
+/// };
+///
+/// assert_eq!(
+///     s.validate().unwrap_err().to_string(),
+///     json!({
+///         "errors": [],
+///         "properties": {
+///             "val": {
+///                 "errors": ["The items must contain `>= 1` matching items, but `0` matched."]
+///             }
+///         }
+///     })
+///     .to_string()
+/// );
+/// ```
+pub trait ValidateContains<T> {
+    fn validate_contains<F>(
+        &self,
+        predicate: F,
+        min_contains: usize,
+        max_contains: Option<usize>,
+    ) -> Result<(), ContainsError>
+    where
+        F: Fn(&T) -> bool;
+}
+
+fn validate_contains_items<'a, T, F>(
+    items: impl Iterator<Item = &'a T>,
+    predicate: F,
+    min_contains: usize,
+    max_contains: Option<usize>,
+) -> Result<(), ContainsError>
+where
+    T: 'a,
+    F: Fn(&T) -> bool,
+{
+    let matched = items.filter(|item| predicate(item)).count();
+
+    if matched < min_contains || max_contains.is_some_and(|max_contains| matched > max_contains) {
+        Err(ContainsError::new(min_contains, max_contains, matched))
+    } else {
+        Ok(())
+    }
+}
+
+impl<T> ValidateContains<T> for Vec<T> {
+    fn validate_contains<F>(
+        &self,
+        predicate: F,
+        min_contains: usize,
+        max_contains: Option<usize>,
+    ) -> Result<(), ContainsError>
+    where
+        F: Fn(&T) -> bool,
+    {
+        validate_contains_items(self.iter(), predicate, min_contains, max_contains)
+    }
+}
+
+impl<T, const N: usize> ValidateContains<T> for [T; N] {
+    fn validate_contains<F>(
+        &self,
+        predicate: F,
+        min_contains: usize,
+        max_contains: Option<usize>,
+    ) -> Result<(), ContainsError>
+    where
+        F: Fn(&T) -> bool,
+    {
+        validate_contains_items(self.iter(), predicate, min_contains, max_contains)
+    }
+}
+
+impl<T, U> ValidateContains<T> for Option<U>
+where
+    U: ValidateContains<T>,
+{
+    fn validate_contains<F>(
+        &self,
+        predicate: F,
+        min_contains: usize,
+        max_contains: Option<usize>,
+    ) -> Result<(), ContainsError>
+    where
+        F: Fn(&T) -> bool,
+    {
+        match self {
+            Some(value) => value.validate_contains(predicate, min_contains, max_contains),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_array_contains_vec_type() {
+        assert!(ValidateContains::validate_contains(&vec![1, 2, 3], |x| *x == 2, 1, None).is_ok());
+    }
+
+    #[test]
+    fn test_validate_array_contains_array_type() {
+        assert!(ValidateContains::validate_contains(&[1, 2, 3], |x| *x == 2, 1, None).is_ok());
+    }
+
+    #[test]
+    fn test_validate_array_contains_is_false() {
+        let error =
+            ValidateContains::validate_contains(&vec![1, 2, 3], |x| *x > 3, 1, None).unwrap_err();
+        assert_eq!(error.matched, 0);
+    }
+
+    #[test]
+    fn test_validate_array_min_contains_is_false() {
+        let error =
+            ValidateContains::validate_contains(&vec![1, 2, 3], |x| *x > 1, 3, None).unwrap_err();
+        assert_eq!(error.matched, 2);
+    }
+
+    #[test]
+    fn test_validate_array_max_contains_is_false() {
+        let error = ValidateContains::validate_contains(&vec![1, 2, 3], |x| *x > 1, 0, Some(1))
+            .unwrap_err();
+        assert_eq!(error.matched, 2);
+    }
+
+    #[test]
+    fn test_validate_array_max_contains_is_true() {
+        assert!(
+            ValidateContains::validate_contains(&vec![1, 2, 3], |x| *x > 5, 0, Some(1)).is_ok()
+        );
+    }
+}
@@ -1,9 +1,9 @@
 use crate::validation::error::IntoError;
 
 use crate::error::{
-    EnumerateError, ExclusiveMaximumError, ExclusiveMinimumError, MaxItemsError, MaxLengthError,
-    MaxPropertiesError, MaximumError, MinItemsError, MinLengthError, MinPropertiesError,
-    MinimumError, MultipleOfError, PatternError, UniqueItemsError,
+    ConstError, EnumerateError, ExclusiveMaximumError, ExclusiveMinimumError, FormatError,
+    MaxItemsError, MaxLengthError, MaxPropertiesError, MaximumError, MinItemsError, MinLengthError,
+    MinPropertiesError, MinimumError, MultipleOfError, PatternError, UniqueItemsError,
 };
 use indexmap::IndexMap;
 
@@ -56,6 +56,7 @@ macro_rules! impl_into_error {
 
 // Global
 impl_into_error!(Enumerate);
+impl_into_error!(Const);
 
 // Numeric
 impl_into_error!(Maximum);
@@ -68,6 +69,7 @@ impl_into_error!(MultipleOf);
 impl_into_error!(MaxLength);
 impl_into_error!(MinLength);
 impl_into_error!(Pattern);
+impl_into_error!(Format);
 
 // Array
 impl_into_error!(MaxItems);
@@ -1,22 +1,34 @@
 mod array_erros;
+mod detailed;
 mod errors;
 mod format;
 mod into_error;
+mod json_pointer;
 mod message;
 mod object_errors;
+mod output;
+mod params;
+mod visitor;
 
 pub use crate::error::{
-    EnumerateError, ExclusiveMaximumError, ExclusiveMinimumError, MaxItemsError, MaxLengthError,
-    MaxPropertiesError, MaximumError, MinItemsError, MinLengthError, MinPropertiesError,
-    MinimumError, MultipleOfError, PatternError, UniqueItemsError,
+    AllOfError, AnyOfError, ConstError, ContainsError, CustomError, EnumerateError,
+    ExclusiveMaximumError, ExclusiveMinimumError, FieldComparisonError, FormatError,
+    InvalidValueError, MaxItemsError, MaxLengthError, MaxPropertiesError, MaximumError,
+    MinItemsError, MinLengthError, MinPropertiesError, MinimumError, MultipleOfError, NotError,
+    OneOfError, PatternError, RequiredError, TruncatedError, TypeError, UniqueItemsError,
 };
 pub use array_erros::ArrayErrors;
+pub use detailed::{DetailedError, ErrorDetail, ToDetailed};
 pub use errors::Errors;
 pub use format::{Format, FormatDefault};
 use indexmap::IndexMap;
 pub use into_error::IntoError;
+pub use json_pointer::{JsonPointer, JsonPointerError, PathChunk};
 pub use message::Message;
 pub use object_errors::ObjectErrors;
+pub use output::{Output, OutputFormat, OutputUnit, ToOutput};
+pub use params::ErrorParams;
+pub use visitor::Visitor;
 
 #[derive(Debug, Clone, serde::Serialize, thiserror::Error)]
 #[serde(untagged)]
@@ -53,6 +65,10 @@ pub enum Error {
     #[serde(serialize_with = "serialize_error_message")]
     Pattern(Message<PatternError>),
 
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    Format(Message<FormatError>),
+
     #[error("{0}")]
     #[serde(serialize_with = "serialize_error_message")]
     MinItems(Message<MinItemsError>),
@@ -65,6 +81,10 @@ pub enum Error {
     #[serde(serialize_with = "serialize_error_message")]
     UniqueItems(Message<UniqueItemsError>),
 
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    Contains(Message<ContainsError>),
+
     #[error("{0}")]
     #[serde(serialize_with = "serialize_error_message")]
     MinProperties(Message<MinPropertiesError>),
@@ -77,10 +97,54 @@ pub enum Error {
     #[serde(serialize_with = "serialize_error_message")]
     Enumerate(Message<EnumerateError>),
 
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    Const(Message<ConstError>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    Required(Message<RequiredError>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    FieldComparison(Box<Message<FieldComparisonError>>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    AnyOf(Box<Message<AnyOfError>>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    AllOf(Box<Message<AllOfError>>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    OneOf(Box<Message<OneOfError>>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    Not(Message<NotError>),
+
     #[error("{0}")]
     #[serde(serialize_with = "serialize_error_message")]
     Custom(String),
 
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    CustomError(Box<CustomError>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    Truncated(Message<TruncatedError>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    Type(Message<TypeError>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    InvalidValue(Message<InvalidValueError>),
+
     #[error(transparent)]
     Items(ArrayErrors),
 
@@ -93,6 +157,12 @@ pub enum Error {
     Fluent(crate::fluent::Message),
 }
 
+impl From<CustomError> for Error {
+    fn from(error: CustomError) -> Self {
+        Self::CustomError(Box::new(error))
+    }
+}
+
 fn serialize_error_message<T, S>(message: &T, serializer: S) -> Result<S::Ok, S::Error>
 where
     T: std::fmt::Display,
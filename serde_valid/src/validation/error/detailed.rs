@@ -0,0 +1,246 @@
+use serde_json::{Map, Value};
+
+use super::{
+    ArrayErrors, ErrorParams, Errors, FormatDefault, ItemErrorsMap, ItemVecErrorsMap, Message,
+    ObjectErrors, PropertyErrorsMap, VecErrors,
+};
+
+/// Machine-readable form of a validation error.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ErrorDetail {
+    pub code: String,
+    pub params: Map<String, Value>,
+    pub message: String,
+}
+
+impl ErrorDetail {
+    pub fn new<E>(error: &E) -> Self
+    where
+        E: ErrorParams + std::fmt::Display,
+    {
+        Self {
+            code: error.code().to_string(),
+            params: error.params(),
+            message: error.to_string(),
+        }
+    }
+}
+
+impl std::fmt::Display for ErrorDetail {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(untagged)]
+pub enum DetailedError {
+    Detail(ErrorDetail),
+    Items(ArrayErrors<DetailedError>),
+    Properties(ObjectErrors<DetailedError>),
+}
+
+impl std::fmt::Display for DetailedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DetailedError::Detail(detail) => write!(f, "{}", detail),
+            DetailedError::Items(items) => write!(f, "{}", items),
+            DetailedError::Properties(properties) => write!(f, "{}", properties),
+        }
+    }
+}
+
+/// Convert the validation errors into [`DetailedError`]s,
+/// which serialize as `{ "code": ..., "params": ..., "message": ... }`.
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_valid::Validate;
+/// use serde_valid::validation::ToDetailed;
+///
+/// #[derive(Validate)]
+/// struct TestStruct {
+///     #[validate(maximum = 100)]
+///     val: i32,
+/// }
+///
+/// let s = TestStruct { val: 101 };
+///
+/// assert_eq!(
+///     json!(s.validate().unwrap_err().to_detailed()),
+///     json!({
+///         "errors": [],
+///         "properties": {
+///             "val": {
+///                 "errors": [{
+///                     "code": "maximum",
+///                     "params": { "maximum": 100 },
+///                     "message": "The number must be `<= 100`."
+///                 }]
+///             }
+///         }
+///     })
+/// );
+/// ```
+pub trait ToDetailed {
+    type Target;
+
+    fn to_detailed(&self) -> Self::Target;
+}
+
+impl ToDetailed for Errors<crate::validation::Error> {
+    type Target = Errors<DetailedError>;
+
+    fn to_detailed(&self) -> Self::Target {
+        match self {
+            Errors::Array(array) => Errors::Array(array.to_detailed()),
+            Errors::Object(object) => Errors::Object(object.to_detailed()),
+            Errors::NewType(newtype) => Errors::NewType(newtype.to_detailed()),
+        }
+    }
+}
+
+impl ToDetailed for ArrayErrors<crate::validation::Error> {
+    type Target = ArrayErrors<DetailedError>;
+
+    fn to_detailed(&self) -> Self::Target {
+        ArrayErrors {
+            errors: self.errors.to_detailed(),
+            items: self.items.to_detailed(),
+        }
+    }
+}
+
+impl ToDetailed for ObjectErrors<crate::validation::Error> {
+    type Target = ObjectErrors<DetailedError>;
+
+    fn to_detailed(&self) -> Self::Target {
+        ObjectErrors {
+            errors: self.errors.to_detailed(),
+            properties: self.properties.to_detailed(),
+        }
+    }
+}
+
+impl ToDetailed for VecErrors<crate::validation::Error> {
+    type Target = VecErrors<DetailedError>;
+
+    fn to_detailed(&self) -> Self::Target {
+        self.iter().map(ToDetailed::to_detailed).collect()
+    }
+}
+
+impl ToDetailed for ItemErrorsMap<crate::validation::Error> {
+    type Target = ItemErrorsMap<DetailedError>;
+
+    fn to_detailed(&self) -> Self::Target {
+        self.iter()
+            .map(|(index, errors)| (*index, errors.to_detailed()))
+            .collect()
+    }
+}
+
+impl ToDetailed for ItemVecErrorsMap<crate::validation::Error> {
+    type Target = ItemVecErrorsMap<DetailedError>;
+
+    fn to_detailed(&self) -> Self::Target {
+        self.iter()
+            .map(|(index, errors)| (*index, errors.to_detailed()))
+            .collect()
+    }
+}
+
+impl ToDetailed for PropertyErrorsMap<crate::validation::Error> {
+    type Target = PropertyErrorsMap<DetailedError>;
+
+    fn to_detailed(&self) -> Self::Target {
+        self.iter()
+            .map(|(property, errors)| (property.to_string(), errors.to_detailed()))
+            .collect()
+    }
+}
+
+impl ToDetailed for crate::validation::Error {
+    type Target = DetailedError;
+
+    fn to_detailed(&self) -> Self::Target {
+        match self {
+            Self::Minimum(message) => message.to_detailed(),
+            Self::Maximum(message) => message.to_detailed(),
+            Self::ExclusiveMinimum(message) => message.to_detailed(),
+            Self::ExclusiveMaximum(message) => message.to_detailed(),
+            Self::MultipleOf(message) => message.to_detailed(),
+            Self::MinLength(message) => message.to_detailed(),
+            Self::MaxLength(message) => message.to_detailed(),
+            Self::Pattern(message) => message.to_detailed(),
+            Self::Format(message) => message.to_detailed(),
+            Self::MinItems(message) => message.to_detailed(),
+            Self::MaxItems(message) => message.to_detailed(),
+            Self::UniqueItems(message) => message.to_detailed(),
+            Self::Contains(message) => message.to_detailed(),
+            Self::MinProperties(message) => message.to_detailed(),
+            Self::MaxProperties(message) => message.to_detailed(),
+            Self::Enumerate(message) => message.to_detailed(),
+            Self::Const(message) => message.to_detailed(),
+            Self::Required(message) => message.to_detailed(),
+            Self::FieldComparison(message) => message.to_detailed(),
+            Self::AnyOf(message) => message.to_detailed(),
+            Self::AllOf(message) => message.to_detailed(),
+            Self::OneOf(message) => message.to_detailed(),
+            Self::Not(message) => message.to_detailed(),
+            Self::Truncated(message) => message.to_detailed(),
+            Self::Type(message) => message.to_detailed(),
+            Self::InvalidValue(message) => message.to_detailed(),
+            Self::Custom(message) => DetailedError::Detail(ErrorDetail {
+                code: "custom".to_string(),
+                params: Map::new(),
+                message: message.to_string(),
+            }),
+            Self::CustomError(error) => DetailedError::Detail(ErrorDetail::new(error.as_ref())),
+            Self::Items(items) => DetailedError::Items(items.to_detailed()),
+            Self::Properties(properties) => DetailedError::Properties(properties.to_detailed()),
+            #[cfg(feature = "fluent")]
+            Self::Fluent(message) => DetailedError::Detail(ErrorDetail {
+                code: message.id.to_string(),
+                params: fluent_params(message),
+                message: message.to_string(),
+            }),
+        }
+    }
+}
+
+impl<E> ToDetailed for Message<E>
+where
+    E: ErrorParams + FormatDefault,
+{
+    type Target = DetailedError;
+
+    fn to_detailed(&self) -> Self::Target {
+        DetailedError::Detail(ErrorDetail::new(self))
+    }
+}
+
+#[cfg(feature = "fluent")]
+fn fluent_params(message: &crate::features::fluent::Message) -> Map<String, Value> {
+    message
+        .args
+        .iter()
+        .map(|(key, value)| {
+            let value = match value {
+                fluent::FluentValue::String(string) => Value::String(string.to_string()),
+                fluent::FluentValue::Number(number) => serde_json::Number::from_f64(number.value)
+                    .map(Value::Number)
+                    .unwrap_or(Value::Null),
+                _ => Value::Null,
+            };
+            (key.to_string(), value)
+        })
+        .collect()
+}
+
+impl Errors<crate::validation::Error> {
+    /// Serialize the errors with the machine-readable code and params.
+    pub fn to_detailed_json(&self) -> Value {
+        serde_json::to_value(self.to_detailed()).unwrap_or(Value::Null)
+    }
+}
@@ -38,16 +38,8 @@ where
         match self {
             Errors::Array(a) => match other {
                 Errors::Array(b) => {
-                    a.errors.extend(b.errors);
-
-                    for (index, item) in b.items {
-                        match a.items.get_mut(&index) {
-                            Some(errors) => errors.merge(item),
-                            None => {
-                                a.items.insert(index, item);
-                            }
-                        };
-                    }
+                    let a = std::mem::replace(a, ArrayErrors::new(vec![], Default::default()));
+                    *self = Errors::Array(a.merge(b));
                 }
                 Errors::Object(_) => {
                     unreachable!("conflict Array and Object in serde_valid::validation::Errors")
@@ -61,16 +53,26 @@ where
                     a.extend(b.errors);
                     *self = Errors::Array(ArrayErrors::new(a.to_vec(), b.items));
                 }
-                Errors::Object(_) => {
-                    unreachable!("conflict Array and Object in serde_valid::validation::Errors")
+                Errors::Object(b) => {
+                    a.extend(b.errors);
+                    *self = Errors::Object(ObjectErrors::new(a.to_vec(), b.properties));
                 }
                 Errors::NewType(b) => {
                     a.extend(b);
                 }
             },
-            Errors::Object(_) => {
-                unimplemented!("Object does not support yet.")
-            }
+            Errors::Object(a) => match other {
+                Errors::Array(_) => {
+                    unreachable!("conflict Array and Object in serde_valid::validation::Errors")
+                }
+                Errors::Object(b) => {
+                    let a = std::mem::replace(a, ObjectErrors::new(vec![], Default::default()));
+                    *self = Errors::Object(a.merge(b));
+                }
+                Errors::NewType(errors) => {
+                    a.errors.extend(errors);
+                }
+            },
         }
     }
 }
@@ -94,3 +96,62 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validation::Error;
+    use indexmap::indexmap;
+    use serde_json::json;
+
+    #[test]
+    fn object_errors_merge() {
+        let mut errors = Errors::Object(ObjectErrors::new(
+            vec![Error::Custom("root_a".to_owned())],
+            indexmap! {
+                "a".to_owned() => Errors::NewType(vec![Error::Custom("a1".to_owned())]),
+            },
+        ));
+
+        errors.merge(Errors::Object(ObjectErrors::new(
+            vec![Error::Custom("root_b".to_owned())],
+            indexmap! {
+                "a".to_owned() => Errors::NewType(vec![Error::Custom("a2".to_owned())]),
+                "b".to_owned() => Errors::NewType(vec![Error::Custom("b1".to_owned())]),
+            },
+        )));
+
+        assert_eq!(
+            serde_json::to_value(&errors).unwrap(),
+            json!({
+                "errors": ["root_a", "root_b"],
+                "properties": {
+                    "a": { "errors": ["a1", "a2"] },
+                    "b": { "errors": ["b1"] }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn new_type_errors_merge_object() {
+        let mut errors = Errors::NewType(vec![Error::Custom("root_a".to_owned())]);
+
+        errors.merge(Errors::Object(ObjectErrors::new(
+            vec![],
+            indexmap! {
+                "a".to_owned() => Errors::NewType(vec![Error::Custom("a1".to_owned())]),
+            },
+        )));
+
+        assert_eq!(
+            serde_json::to_value(&errors).unwrap(),
+            json!({
+                "errors": ["root_a"],
+                "properties": {
+                    "a": { "errors": ["a1"] }
+                }
+            })
+        );
+    }
+}
@@ -0,0 +1,271 @@
+use std::borrow::Cow;
+
+/// The reference token of [`JsonPointer`].
+#[derive(Debug, Clone, Eq)]
+pub enum PathChunk {
+    Property(Box<str>),
+    Index(usize),
+}
+
+impl PathChunk {
+    /// The unescaped reference token.
+    pub fn token(&self) -> Cow<'_, str> {
+        match self {
+            Self::Property(property) => Cow::Borrowed(property),
+            Self::Index(index) => Cow::Owned(index.to_string()),
+        }
+    }
+}
+
+/// JSON Pointer does not distinguish the array index from the property name,
+/// so `Index(3)` equals to `Property("3")`.
+impl PartialEq for PathChunk {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Index(a), Self::Index(b)) => a == b,
+            _ => self.token() == other.token(),
+        }
+    }
+}
+
+/// Hashed by the token, consistently with [`PartialEq`].
+impl std::hash::Hash for PathChunk {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.token().hash(state);
+    }
+}
+
+impl From<usize> for PathChunk {
+    fn from(index: usize) -> Self {
+        Self::Index(index)
+    }
+}
+
+impl From<String> for PathChunk {
+    fn from(property: String) -> Self {
+        Self::Property(property.into_boxed_str())
+    }
+}
+
+impl From<&str> for PathChunk {
+    fn from(property: &str) -> Self {
+        Self::Property(property.into())
+    }
+}
+
+impl std::fmt::Display for PathChunk {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Property(property) => write!(f, "{}", escape_pointer_token(property)),
+            Self::Index(index) => write!(f, "{index}"),
+        }
+    }
+}
+
+/// [JSON Pointer](https://datatracker.ietf.org/doc/html/rfc6901) to the location of the errors.
+///
+/// ```rust
+/// use serde_valid::validation::{JsonPointer, PathChunk};
+///
+/// let pointer = JsonPointer::default().join("items").join(3).join("a/b");
+///
+/// assert_eq!(pointer.to_string(), "/items/3/a~1b");
+/// assert_eq!("/items/3/a~1b".parse::<JsonPointer>().unwrap(), pointer);
+/// assert_eq!(pointer.chunks()[1], PathChunk::Index(3));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct JsonPointer(Vec<PathChunk>);
+
+impl JsonPointer {
+    /// The pointer to the child location.
+    pub fn join(&self, chunk: impl Into<PathChunk>) -> Self {
+        let mut pointer = self.clone();
+        pointer.0.push(chunk.into());
+        pointer
+    }
+
+    pub fn chunks(&self) -> &[PathChunk] {
+        &self.0
+    }
+
+    /// Whether it points to the root.
+    pub fn is_root(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Whether it points to `other` or one of the children of `other`.
+    pub fn starts_with(&self, other: &JsonPointer) -> bool {
+        self.0.starts_with(&other.0)
+    }
+}
+
+impl From<&[PathChunk]> for JsonPointer {
+    fn from(chunks: &[PathChunk]) -> Self {
+        Self(chunks.to_vec())
+    }
+}
+
+impl From<Vec<PathChunk>> for JsonPointer {
+    fn from(chunks: Vec<PathChunk>) -> Self {
+        Self(chunks)
+    }
+}
+
+impl FromIterator<PathChunk> for JsonPointer {
+    fn from_iter<I: IntoIterator<Item = PathChunk>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl IntoIterator for JsonPointer {
+    type Item = PathChunk;
+    type IntoIter = <Vec<PathChunk> as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a JsonPointer {
+    type Item = &'a PathChunk;
+    type IntoIter = std::slice::Iter<'a, PathChunk>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl std::fmt::Display for JsonPointer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for chunk in &self.0 {
+            write!(f, "/{chunk}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for JsonPointer {
+    type Err = JsonPointerError;
+
+    fn from_str(pointer: &str) -> Result<Self, Self::Err> {
+        if pointer.is_empty() {
+            return Ok(Self::default());
+        }
+
+        let Some(tokens) = pointer.strip_prefix('/') else {
+            return Err(JsonPointerError::MissingLeadingSlash(pointer.to_owned()));
+        };
+
+        tokens
+            .split('/')
+            .map(|token| {
+                let token = unescape_pointer_token(token)
+                    .ok_or_else(|| JsonPointerError::InvalidEscape(pointer.to_owned()))?;
+                Ok(match token.parse::<usize>() {
+                    Ok(index) if index.to_string() == token => PathChunk::Index(index),
+                    _ => PathChunk::from(token),
+                })
+            })
+            .collect()
+    }
+}
+
+impl serde::Serialize for JsonPointer {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum JsonPointerError {
+    #[error("The JSON Pointer `{0}` must start with `/`.")]
+    MissingLeadingSlash(String),
+
+    #[error("The JSON Pointer `{0}` has the invalid escape, only `~0` and `~1` are allowed.")]
+    InvalidEscape(String),
+}
+
+pub(crate) fn escape_pointer_token(token: &str) -> Cow<'_, str> {
+    if token.contains(['~', '/']) {
+        Cow::Owned(token.replace('~', "~0").replace('/', "~1"))
+    } else {
+        Cow::Borrowed(token)
+    }
+}
+
+fn unescape_pointer_token(token: &str) -> Option<String> {
+    let mut unescaped = String::with_capacity(token.len());
+    let mut chars = token.chars();
+    while let Some(c) = chars.next() {
+        if c == '~' {
+            match chars.next() {
+                Some('0') => unescaped.push('~'),
+                Some('1') => unescaped.push('/'),
+                _ => return None,
+            }
+        } else {
+            unescaped.push(c);
+        }
+    }
+    Some(unescaped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_pointer_token() {
+        assert_eq!(escape_pointer_token("name"), "name");
+        assert_eq!(escape_pointer_token("a/b~c"), "a~1b~0c");
+    }
+
+    #[test]
+    fn test_json_pointer_display() {
+        assert_eq!(JsonPointer::default().to_string(), "");
+        assert_eq!(
+            JsonPointer::from([PathChunk::from(0), PathChunk::from("a~b")].as_ref()).to_string(),
+            "/0/a~0b"
+        );
+    }
+
+    #[test]
+    fn test_json_pointer_from_str() {
+        assert_eq!("".parse::<JsonPointer>(), Ok(JsonPointer::default()));
+        assert_eq!(
+            "/items/01/a~1b".parse::<JsonPointer>(),
+            Ok(JsonPointer::from(
+                [
+                    PathChunk::from("items"),
+                    PathChunk::from("01"),
+                    PathChunk::from("a/b")
+                ]
+                .as_ref()
+            ))
+        );
+        assert!(matches!(
+            "items".parse::<JsonPointer>(),
+            Err(JsonPointerError::MissingLeadingSlash(_))
+        ));
+        assert!(matches!(
+            "/a~2".parse::<JsonPointer>(),
+            Err(JsonPointerError::InvalidEscape(_))
+        ));
+    }
+
+    #[test]
+    fn test_path_chunk_index_equals_to_property() {
+        assert_eq!(PathChunk::Index(3), PathChunk::from("3"));
+        assert_ne!(PathChunk::Index(3), PathChunk::from("03"));
+    }
+
+    #[test]
+    fn test_path_chunk_index_hashes_as_property() {
+        let pointers = std::collections::HashSet::from([JsonPointer::default().join(3)]);
+
+        assert!(pointers.contains(&JsonPointer::default().join("3")));
+    }
+}
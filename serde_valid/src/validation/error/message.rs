@@ -11,6 +11,10 @@ impl<E> Message<E> {
         Self { error, format }
     }
 
+    pub fn error(&self) -> &E {
+        &self.error
+    }
+
     #[cfg(feature = "fluent")]
     pub fn fluent_message(&self) -> Option<&crate::features::fluent::Message> {
         match self.format {
@@ -0,0 +1,315 @@
+use std::borrow::Cow;
+
+use super::json_pointer::escape_pointer_token;
+use super::{ArrayErrors, ErrorParams, Errors, ItemVecErrorsMap, ObjectErrors, VecErrors};
+
+/// The output formats of [JSON Schema 2020-12](https://json-schema.org/draft/2020-12/json-schema-core#name-output-formats).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// `{ "valid": false }` only.
+    Flag,
+    /// The flat list of the error units.
+    Basic,
+    /// The hierarchical error units, with the single child units condensed.
+    Detailed,
+    /// The hierarchical error units as they are.
+    ///
+    /// Only the failed units are included, because the passed validations are not recorded.
+    Verbose,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(untagged)]
+pub enum Output {
+    Flag { valid: bool },
+    Unit(OutputUnit),
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutputUnit {
+    pub valid: bool,
+    pub keyword_location: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub absolute_keyword_location: Option<String>,
+    pub instance_location: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<OutputUnit>,
+}
+
+impl OutputUnit {
+    fn new(keyword_location: String, instance_location: String, base_uri: Option<&str>) -> Self {
+        Self {
+            valid: false,
+            absolute_keyword_location: base_uri
+                .map(|base_uri| format!("{base_uri}#{keyword_location}")),
+            keyword_location,
+            instance_location,
+            error: None,
+            errors: vec![],
+        }
+    }
+
+    fn leaves(mut self) -> Vec<OutputUnit> {
+        let children = std::mem::take(&mut self.errors);
+        let leaf = self.error.is_some().then_some(self);
+        leaf.into_iter()
+            .chain(children.into_iter().flat_map(OutputUnit::leaves))
+            .collect()
+    }
+
+    fn condense(mut self) -> Self {
+        self.errors = self.errors.into_iter().map(OutputUnit::condense).collect();
+        if self.error.is_none() && self.errors.len() == 1 {
+            self.errors.remove(0)
+        } else {
+            self
+        }
+    }
+}
+
+impl std::fmt::Display for Output {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match serde_json::to_string(&self) {
+            Ok(json_string) => write!(f, "{}", json_string),
+            Err(_) => Err(std::fmt::Error),
+        }
+    }
+}
+
+/// Convert the validation errors into the JSON Schema output formats.
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_valid::Validate;
+/// use serde_valid::validation::{OutputFormat, ToOutput};
+///
+/// #[derive(Validate)]
+/// struct TestStruct {
+///     #[validate(max_length = 3)]
+///     name: String,
+/// }
+///
+/// let s = TestStruct { name: "taro".to_string() };
+///
+/// assert_eq!(
+///     json!(s.validate().unwrap_err().to_output(OutputFormat::Basic)),
+///     json!({
+///         "valid": false,
+///         "keywordLocation": "",
+///         "instanceLocation": "",
+///         "errors": [{
+///             "valid": false,
+///             "keywordLocation": "/properties/name/maxLength",
+///             "instanceLocation": "/name",
+///             "error": "The length of the value must be `<= 3`."
+///         }]
+///     })
+/// );
+/// ```
+pub trait ToOutput {
+    fn to_output(&self, format: OutputFormat) -> Output {
+        self.to_output_with_base_uri(format, None)
+    }
+
+    /// `base_uri` is the `$id` of the schema, used as the prefix of `absoluteKeywordLocation`.
+    fn to_output_with_base_uri(&self, format: OutputFormat, base_uri: Option<&str>) -> Output;
+}
+
+impl ToOutput for Errors<crate::validation::Error> {
+    fn to_output_with_base_uri(&self, format: OutputFormat, base_uri: Option<&str>) -> Output {
+        let mut root = OutputUnit::new(String::new(), String::new(), base_uri);
+        errors_units(self, &mut root, base_uri);
+
+        match format {
+            OutputFormat::Flag => Output::Flag { valid: false },
+            OutputFormat::Basic => Output::Unit(OutputUnit {
+                errors: root
+                    .errors
+                    .into_iter()
+                    .flat_map(OutputUnit::leaves)
+                    .collect(),
+                ..root
+            }),
+            OutputFormat::Detailed => Output::Unit(OutputUnit {
+                errors: root.errors.into_iter().map(OutputUnit::condense).collect(),
+                ..root
+            }),
+            OutputFormat::Verbose => Output::Unit(root),
+        }
+    }
+}
+
+fn errors_units(
+    errors: &Errors<crate::validation::Error>,
+    parent: &mut OutputUnit,
+    base_uri: Option<&str>,
+) {
+    match errors {
+        Errors::Array(array) => array_units(array, parent, base_uri),
+        Errors::Object(object) => object_units(object, parent, base_uri),
+        Errors::NewType(newtype) => vec_units(newtype, parent, base_uri),
+    }
+}
+
+fn array_units(
+    array: &ArrayErrors<crate::validation::Error>,
+    parent: &mut OutputUnit,
+    base_uri: Option<&str>,
+) {
+    vec_units(&array.errors, parent, base_uri);
+    for (index, errors) in array.items.iter() {
+        let mut unit = OutputUnit::new(
+            format!("{}/items", parent.keyword_location),
+            format!("{}/{index}", parent.instance_location),
+            base_uri,
+        );
+        errors_units(errors, &mut unit, base_uri);
+        parent.errors.push(unit);
+    }
+}
+
+fn object_units(
+    object: &ObjectErrors<crate::validation::Error>,
+    parent: &mut OutputUnit,
+    base_uri: Option<&str>,
+) {
+    vec_units(&object.errors, parent, base_uri);
+    for (property, errors) in object.properties.iter() {
+        let property = escape_pointer_token(property);
+        let mut unit = OutputUnit::new(
+            format!("{}/properties/{property}", parent.keyword_location),
+            format!("{}/{property}", parent.instance_location),
+            base_uri,
+        );
+        errors_units(errors, &mut unit, base_uri);
+        parent.errors.push(unit);
+    }
+}
+
+fn vec_units(
+    errors: &VecErrors<crate::validation::Error>,
+    parent: &mut OutputUnit,
+    base_uri: Option<&str>,
+) {
+    for error in errors {
+        use crate::validation::Error;
+
+        match error {
+            Error::Items(array) => array_units(array, parent, base_uri),
+            Error::Properties(object) => object_units(object, parent, base_uri),
+            _ => {
+                let keyword = keyword(error);
+                let mut unit = OutputUnit::new(
+                    format!("{}/{keyword}", parent.keyword_location),
+                    parent.instance_location.to_string(),
+                    base_uri,
+                );
+                unit.error = Some(error.to_string());
+                match error {
+                    Error::AnyOf(message) => {
+                        branch_units(&message.error().errors, &mut unit, base_uri)
+                    }
+                    Error::AllOf(message) => {
+                        branch_units(&message.error().errors, &mut unit, base_uri)
+                    }
+                    Error::OneOf(message) => {
+                        branch_units(&message.error().errors, &mut unit, base_uri)
+                    }
+                    _ => {}
+                }
+                parent.errors.push(unit);
+            }
+        }
+    }
+}
+
+fn branch_units(
+    branches: &ItemVecErrorsMap<crate::validation::Error>,
+    parent: &mut OutputUnit,
+    base_uri: Option<&str>,
+) {
+    for (index, errors) in branches.iter() {
+        let mut unit = OutputUnit::new(
+            format!("{}/{index}", parent.keyword_location),
+            parent.instance_location.to_string(),
+            base_uri,
+        );
+        vec_units(errors, &mut unit, base_uri);
+        parent.errors.push(unit);
+    }
+}
+
+/// The JSON Schema keyword of the error, such as `maxLength`.
+fn keyword(error: &crate::validation::Error) -> Cow<'_, str> {
+    use crate::validation::Error;
+
+    match error {
+        Error::Minimum(message) => camel_case(message.code()),
+        Error::Maximum(message) => camel_case(message.code()),
+        Error::ExclusiveMinimum(message) => camel_case(message.code()),
+        Error::ExclusiveMaximum(message) => camel_case(message.code()),
+        Error::MultipleOf(message) => camel_case(message.code()),
+        Error::MinLength(message) => camel_case(message.code()),
+        Error::MaxLength(message) => camel_case(message.code()),
+        Error::Pattern(message) => camel_case(message.code()),
+        Error::Format(message) => camel_case(message.code()),
+        Error::MinItems(message) => camel_case(message.code()),
+        Error::MaxItems(message) => camel_case(message.code()),
+        Error::UniqueItems(message) => camel_case(message.code()),
+        Error::Contains(message) => camel_case(message.code()),
+        Error::MinProperties(message) => camel_case(message.code()),
+        Error::MaxProperties(message) => camel_case(message.code()),
+        Error::Enumerate(_) => Cow::Borrowed("enum"),
+        Error::Const(message) => camel_case(message.code()),
+        Error::Required(message) => camel_case(message.code()),
+        Error::FieldComparison(message) => camel_case(message.code()),
+        Error::AnyOf(message) => camel_case(message.code()),
+        Error::AllOf(message) => camel_case(message.code()),
+        Error::OneOf(message) => camel_case(message.code()),
+        Error::Not(message) => camel_case(message.code()),
+        Error::Truncated(message) => camel_case(message.code()),
+        Error::Type(message) => camel_case(message.code()),
+        Error::InvalidValue(message) => camel_case(message.code()),
+        Error::Custom(_) => Cow::Borrowed("custom"),
+        Error::CustomError(error) => Cow::Owned(escape_pointer_token(error.code()).into_owned()),
+        Error::Items(_) => Cow::Borrowed("items"),
+        Error::Properties(_) => Cow::Borrowed("properties"),
+        #[cfg(feature = "fluent")]
+        Error::Fluent(_) => Cow::Borrowed("custom"),
+    }
+}
+
+fn camel_case(code: &str) -> Cow<'_, str> {
+    if !code.contains('_') {
+        return Cow::Borrowed(code);
+    }
+
+    let mut keyword = String::with_capacity(code.len());
+    let mut upper = false;
+    for c in code.chars() {
+        if c == '_' {
+            upper = true;
+        } else if upper {
+            keyword.extend(c.to_uppercase());
+            upper = false;
+        } else {
+            keyword.push(c);
+        }
+    }
+    Cow::Owned(keyword)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_camel_case() {
+        assert_eq!(camel_case("maximum"), "maximum");
+        assert_eq!(camel_case("exclusive_maximum"), "exclusiveMaximum");
+        assert_eq!(camel_case("greater_than_or_equal"), "greaterThanOrEqual");
+    }
+}
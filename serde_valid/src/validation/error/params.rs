@@ -0,0 +1,270 @@
+use serde_json::{Map, Value};
+use serde_valid_literal::Literal;
+
+use crate::validation::{Number, StringFormat};
+use crate::{
+    AllOfError, AnyOfError, ConstError, ContainsError, CustomError, EnumerateError,
+    ExclusiveMaximumError, ExclusiveMinimumError, FieldComparisonError, FormatError,
+    InvalidValueError, MaxItemsError, MaxLengthError, MaxPropertiesError, MaximumError,
+    MinItemsError, MinLengthError, MinPropertiesError, MinimumError, MultipleOfError, NotError,
+    OneOfError, PatternError, RequiredError, TruncatedError, TypeError, UniqueItemsError,
+};
+
+use super::{Message, ToDetailed};
+
+/// Machine-readable code and params of the validation error.
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_valid::validation::ErrorParams;
+/// use serde_valid::MaximumError;
+///
+/// let error = MaximumError::new(100);
+///
+/// assert_eq!(error.code(), "maximum");
+/// assert_eq!(json!(error.params()), json!({ "maximum": 100 }));
+/// ```
+pub trait ErrorParams {
+    /// Stable keyword code, such as `"maximum"`.
+    fn code(&self) -> &str;
+
+    /// Typed params, such as `{ "maximum": 100 }`.
+    fn params(&self) -> Map<String, Value> {
+        Map::new()
+    }
+}
+
+impl<E> ErrorParams for Message<E>
+where
+    E: ErrorParams,
+{
+    fn code(&self) -> &str {
+        self.error().code()
+    }
+
+    fn params(&self) -> Map<String, Value> {
+        self.error().params()
+    }
+}
+
+trait ParamValue {
+    fn param_value(&self) -> Value;
+}
+
+impl ParamValue for Number {
+    fn param_value(&self) -> Value {
+        // The numbers are formatted as the Rust literals, which are also JSON numbers.
+        serde_json::from_str(&self.to_string()).unwrap_or_else(|_| Value::String(self.to_string()))
+    }
+}
+
+impl ParamValue for Literal {
+    fn param_value(&self) -> Value {
+        match self {
+            Literal::Bool(value) => Value::Bool(*value),
+            Literal::Number(value) => value.param_value(),
+            Literal::String(value) => Value::String(value.to_string()),
+            Literal::Char(value) => Value::String(value.to_string()),
+            Literal::Null => Value::Null,
+        }
+    }
+}
+
+impl ParamValue for Vec<Literal> {
+    fn param_value(&self) -> Value {
+        Value::Array(self.iter().map(ParamValue::param_value).collect())
+    }
+}
+
+impl ParamValue for StringFormat {
+    fn param_value(&self) -> Value {
+        Value::String(self.name().to_string())
+    }
+}
+
+impl ParamValue for usize {
+    fn param_value(&self) -> Value {
+        Value::from(*self)
+    }
+}
+
+impl ParamValue for Option<usize> {
+    fn param_value(&self) -> Value {
+        self.map(Value::from).unwrap_or(Value::Null)
+    }
+}
+
+impl ParamValue for String {
+    fn param_value(&self) -> Value {
+        Value::String(self.to_string())
+    }
+}
+
+macro_rules! impl_error_params {
+    ($Error:ty, $code:literal $(, $param:ident)*) => {
+        impl ErrorParams for $Error {
+            fn code(&self) -> &str {
+                $code
+            }
+
+            #[allow(unused_mut)]
+            fn params(&self) -> Map<String, Value> {
+                let mut params = Map::new();
+                $(
+                    params.insert(stringify!($param).to_string(), self.$param.param_value());
+                )*
+                params
+            }
+        }
+    };
+}
+
+impl_error_params!(MinimumError, "minimum", minimum);
+impl_error_params!(MaximumError, "maximum", maximum);
+impl_error_params!(
+    ExclusiveMinimumError,
+    "exclusive_minimum",
+    exclusive_minimum
+);
+impl_error_params!(
+    ExclusiveMaximumError,
+    "exclusive_maximum",
+    exclusive_maximum
+);
+impl_error_params!(MultipleOfError, "multiple_of", multiple_of);
+impl_error_params!(MinLengthError, "min_length", min_length);
+impl_error_params!(MaxLengthError, "max_length", max_length);
+impl_error_params!(PatternError, "pattern", pattern);
+impl_error_params!(FormatError, "format", format);
+impl_error_params!(MinItemsError, "min_items", min_items);
+impl_error_params!(MaxItemsError, "max_items", max_items);
+impl_error_params!(UniqueItemsError, "unique_items");
+impl_error_params!(
+    ContainsError,
+    "contains",
+    min_contains,
+    max_contains,
+    matched
+);
+impl_error_params!(MinPropertiesError, "min_properties", min_properties);
+impl_error_params!(MaxPropertiesError, "max_properties", max_properties);
+impl_error_params!(EnumerateError, "enumerate", enumerate);
+impl_error_params!(ConstError, "const", const_value);
+impl_error_params!(RequiredError, "required");
+impl_error_params!(NotError, "not");
+impl_error_params!(TruncatedError, "truncated", max_errors, max_error_items);
+impl_error_params!(TypeError, "type", expected, actual);
+impl_error_params!(InvalidValueError, "invalid_value");
+
+impl ErrorParams for FieldComparisonError {
+    fn code(&self) -> &str {
+        self.operator.code()
+    }
+
+    fn params(&self) -> Map<String, Value> {
+        let mut params = Map::new();
+        params.insert("other_field".to_string(), self.other_field.param_value());
+        if let Some(value) = &self.value {
+            params.insert("value".to_string(), value.clone());
+        }
+        if let Some(other_value) = &self.other_value {
+            params.insert("other_value".to_string(), other_value.clone());
+        }
+        params
+    }
+}
+
+impl ErrorParams for AnyOfError {
+    fn code(&self) -> &str {
+        "any_of"
+    }
+
+    fn params(&self) -> Map<String, Value> {
+        branch_errors_params(&self.errors)
+    }
+}
+
+impl ErrorParams for AllOfError {
+    fn code(&self) -> &str {
+        "all_of"
+    }
+
+    fn params(&self) -> Map<String, Value> {
+        branch_errors_params(&self.errors)
+    }
+}
+
+impl ErrorParams for OneOfError {
+    fn code(&self) -> &str {
+        "one_of"
+    }
+
+    fn params(&self) -> Map<String, Value> {
+        let mut params = branch_errors_params(&self.errors);
+        params.insert(
+            "valid_branches".to_string(),
+            Value::Array(
+                self.valid_branches
+                    .iter()
+                    .map(|v| v.param_value())
+                    .collect(),
+            ),
+        );
+        params
+    }
+}
+
+fn branch_errors_params(
+    errors: &super::ItemVecErrorsMap<crate::validation::Error>,
+) -> Map<String, Value> {
+    let mut params = Map::new();
+    params.insert(
+        "errors".to_string(),
+        serde_json::to_value(errors.to_detailed()).unwrap_or(Value::Null),
+    );
+    params
+}
+
+impl ErrorParams for CustomError {
+    fn code(&self) -> &str {
+        &self.code
+    }
+
+    fn params(&self) -> Map<String, Value> {
+        self.params.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_numeric_error_params() {
+        assert_eq!(
+            json!(MinimumError::new(-1.5).params()),
+            json!({ "minimum": -1.5 })
+        );
+        assert_eq!(
+            json!(MaxLengthError::new(5_usize).params()),
+            json!({ "max_length": 5 })
+        );
+    }
+
+    #[test]
+    fn test_enumerate_error_params() {
+        let error = EnumerateError::new(&[Literal::from("a"), Literal::from(1)]);
+
+        assert_eq!(error.code(), "enumerate");
+        assert_eq!(json!(error.params()), json!({ "enumerate": ["a", 1] }));
+    }
+
+    #[test]
+    fn test_format_error_params() {
+        let error = FormatError::new(StringFormat::DateTime);
+
+        assert_eq!(error.code(), "format");
+        assert_eq!(json!(error.params()), json!({ "format": "date-time" }));
+    }
+}
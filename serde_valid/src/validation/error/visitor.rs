@@ -0,0 +1,145 @@
+use super::{ArrayErrors, Error, Errors, JsonPointer, ObjectErrors, VecErrors};
+
+/// Visit the errors one by one with their instance locations.
+///
+/// The nested `Items` and `Properties` errors are visited at the locations of their children.
+///
+/// ```rust
+/// use serde_valid::validation::{Error, JsonPointer, Visitor};
+/// use serde_valid::Validate;
+///
+/// #[derive(Validate)]
+/// struct TestStruct {
+///     #[validate(max_length = 3)]
+///     name: String,
+///     #[validate]
+///     children: Vec<TestChild>,
+/// }
+///
+/// #[derive(Validate)]
+/// struct TestChild {
+///     #[validate(maximum = 10)]
+///     value: i32,
+/// }
+///
+/// #[derive(Default)]
+/// struct Locations(Vec<String>);
+///
+/// impl<'a> Visitor<'a> for Locations {
+///     fn visit_error(&mut self, instance_location: &JsonPointer, _error: &'a Error) {
+///         self.0.push(instance_location.to_string());
+///     }
+/// }
+///
+/// let s = TestStruct {
+///     name: "taro".to_string(),
+///     children: vec![TestChild { value: 1 }, TestChild { value: 100 }],
+/// };
+///
+/// let mut locations = Locations::default();
+/// s.validate().unwrap_err().visit(&mut locations);
+///
+/// assert_eq!(locations.0, vec!["/name", "/children/1/value"]);
+/// ```
+pub trait Visitor<'a> {
+    fn visit_error(&mut self, instance_location: &JsonPointer, error: &'a Error);
+}
+
+impl Errors<Error> {
+    /// Visit all the errors.
+    pub fn visit<'a>(&'a self, visitor: &mut impl Visitor<'a>) {
+        visit_errors(self, &JsonPointer::default(), visitor);
+    }
+
+    /// Iterate all the errors with their instance locations.
+    pub fn iter(&self) -> impl Iterator<Item = (JsonPointer, &Error)> {
+        struct Collector<'a>(Vec<(JsonPointer, &'a Error)>);
+
+        impl<'a> Visitor<'a> for Collector<'a> {
+            fn visit_error(&mut self, instance_location: &JsonPointer, error: &'a Error) {
+                self.0.push((instance_location.clone(), error));
+            }
+        }
+
+        let mut collector = Collector(vec![]);
+        self.visit(&mut collector);
+        collector.0.into_iter()
+    }
+
+    /// The errors at the instance location, such as `/items/3/name`.
+    ///
+    /// The errors of the children are not included.
+    pub fn get(&self, instance_location: &str) -> Vec<&Error> {
+        let Ok(instance_location) = instance_location.parse::<JsonPointer>() else {
+            return vec![];
+        };
+
+        self.iter()
+            .filter(|(location, _)| location == &instance_location)
+            .map(|(_, error)| error)
+            .collect()
+    }
+
+    /// The number of all the errors.
+    pub fn count(&self) -> usize {
+        struct Counter(usize);
+
+        impl Visitor<'_> for Counter {
+            fn visit_error(&mut self, _: &JsonPointer, _: &Error) {
+                self.0 += 1;
+            }
+        }
+
+        let mut counter = Counter(0);
+        self.visit(&mut counter);
+        counter.0
+    }
+}
+
+fn visit_errors<'a>(
+    errors: &'a Errors<Error>,
+    instance_location: &JsonPointer,
+    visitor: &mut impl Visitor<'a>,
+) {
+    match errors {
+        Errors::Array(array) => visit_array_errors(array, instance_location, visitor),
+        Errors::Object(object) => visit_object_errors(object, instance_location, visitor),
+        Errors::NewType(newtype) => visit_vec_errors(newtype, instance_location, visitor),
+    }
+}
+
+fn visit_array_errors<'a>(
+    array: &'a ArrayErrors<Error>,
+    instance_location: &JsonPointer,
+    visitor: &mut impl Visitor<'a>,
+) {
+    visit_vec_errors(&array.errors, instance_location, visitor);
+    for (index, errors) in array.items.iter() {
+        visit_errors(errors, &instance_location.join(*index), visitor);
+    }
+}
+
+fn visit_object_errors<'a>(
+    object: &'a ObjectErrors<Error>,
+    instance_location: &JsonPointer,
+    visitor: &mut impl Visitor<'a>,
+) {
+    visit_vec_errors(&object.errors, instance_location, visitor);
+    for (property, errors) in object.properties.iter() {
+        visit_errors(errors, &instance_location.join(property.as_str()), visitor);
+    }
+}
+
+fn visit_vec_errors<'a>(
+    errors: &'a VecErrors<Error>,
+    instance_location: &JsonPointer,
+    visitor: &mut impl Visitor<'a>,
+) {
+    for error in errors {
+        match error {
+            Error::Items(array) => visit_array_errors(array, instance_location, visitor),
+            Error::Properties(object) => visit_object_errors(object, instance_location, visitor),
+            _ => visitor.visit_error(instance_location, error),
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+use super::ValidationOptions;
+
+/// The errors found so far by one validation call, shared by its nested validations.
+///
+/// The outermost validation creates it, so a [`ValidationOptions`] can be reused across the calls and the threads.
+/// The counters are atomic only to be shared by the futures of [`AsyncValidate`](crate::AsyncValidate).
+#[doc(hidden)]
+#[derive(Debug, Default)]
+pub struct ErrorBudget {
+    max_errors: Option<usize>,
+    max_error_items: Option<usize>,
+    errors: AtomicUsize,
+    error_items: AtomicUsize,
+    truncated: AtomicBool,
+}
+
+impl ErrorBudget {
+    pub fn new(options: &ValidationOptions) -> Self {
+        Self {
+            max_errors: options.max_errors(),
+            max_error_items: options.max_error_items(),
+            ..Default::default()
+        }
+    }
+
+    pub fn max_errors(&self) -> Option<usize> {
+        self.max_errors
+    }
+
+    pub fn max_error_items(&self) -> Option<usize> {
+        self.max_error_items
+    }
+
+    /// Whether the errors reach `max_errors` or `max_error_items`.
+    pub fn is_exhausted(&self) -> bool {
+        self.max_errors
+            .is_some_and(|max_errors| self.errors.load(Ordering::Relaxed) >= max_errors)
+            || self.max_error_items.is_some_and(|max_error_items| {
+                self.error_items.load(Ordering::Relaxed) >= max_error_items
+            })
+    }
+
+    /// Whether any validation was skipped by `max_errors` or `max_error_items`.
+    pub fn is_truncated(&self) -> bool {
+        self.truncated.load(Ordering::Relaxed)
+    }
+
+    /// Whether the next validation should be skipped, recording the truncation.
+    pub fn skip(&self) -> bool {
+        let is_exhausted = self.is_exhausted();
+        if is_exhausted {
+            self.truncated.store(true, Ordering::Relaxed);
+        }
+        is_exhausted
+    }
+
+    pub fn has_max_errors(&self) -> bool {
+        self.max_errors.is_some()
+    }
+
+    pub fn add_errors(&self, errors: usize) {
+        self.errors.fetch_add(errors, Ordering::Relaxed);
+    }
+
+    pub fn add_error_item(&self) {
+        self.error_items.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_budget_with_max_errors_is_exhausted() {
+        let budget = ErrorBudget::new(&ValidationOptions::new().with_max_errors(2));
+
+        budget.add_errors(1);
+        assert!(!budget.is_exhausted());
+        budget.add_errors(1);
+        assert!(budget.is_exhausted());
+        assert!(!budget.is_truncated());
+        assert!(budget.skip());
+        assert!(budget.is_truncated());
+    }
+
+    #[test]
+    fn test_budget_with_max_error_items_is_exhausted() {
+        let budget = ErrorBudget::new(&ValidationOptions::new().with_max_error_items(1));
+
+        budget.add_errors(10);
+        assert!(!budget.is_exhausted());
+        budget.add_error_item();
+        assert!(budget.is_exhausted());
+    }
+
+    #[test]
+    fn test_budget_without_limits_is_never_exhausted() {
+        let budget = ErrorBudget::new(&ValidationOptions::new());
+
+        budget.add_errors(100);
+        budget.add_error_item();
+        assert!(!budget.skip());
+        assert!(!budget.is_truncated());
+    }
+}
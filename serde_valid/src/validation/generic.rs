@@ -1,2 +1,11 @@
+mod constant;
 mod enumerate;
+mod field_comparison;
+mod required;
+pub use constant::ValidateConst;
 pub use enumerate::ValidateEnumerate;
+pub use field_comparison::{
+    ComparisonOperator, EqualityOperator, OrderingOperator, ValidateFieldEquality,
+    ValidateFieldOrdering,
+};
+pub use required::ValidateRequired;
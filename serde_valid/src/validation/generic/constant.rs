@@ -0,0 +1,218 @@
+use crate::validation::ValidateCompositedConst;
+use crate::ConstError;
+
+/// Const validation.
+///
+/// See <https://json-schema.org/understanding-json-schema/reference/const>
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_valid::{Validate, ValidateConst};
+///
+/// struct MyType(String);
+///
+/// impl ValidateConst<&'static str> for MyType {
+///     fn validate_const(
+///         &self,
+///         const_value: &'static str,
+///     ) -> Result<(), serde_valid::ConstError> {
+///         self.0.validate_const(const_value)
+///     }
+/// }
+///
+/// #[derive(Validate)]
+/// struct TestStruct {
+///     #[validate(const = "v1")]
+///     val: MyType,
+/// }
+///
+/// let s = TestStruct {
+///     val: MyType("v2".to_string()),
+/// };
+///
+/// assert_eq!(
+///     s.validate().unwrap_err().to_string(),
+///     json!({
+///         "errors": [],
+///         "properties": {
+///             "val": {
+///                 "errors": ["The value must be `v1`."]
+///             }
+///         }
+///     })
+///     .to_string()
+/// );
+/// ```
+pub trait ValidateConst<T> {
+    fn validate_const(&self, const_value: T) -> Result<(), ConstError>;
+}
+
+macro_rules! impl_validate_generic_const_literal {
+    ($type:ty) => {
+        impl ValidateConst<$type> for $type {
+            fn validate_const(&self, const_value: $type) -> Result<(), ConstError> {
+                if *self == const_value {
+                    Ok(())
+                } else {
+                    Err(ConstError::new(const_value))
+                }
+            }
+        }
+
+        impl<T> ValidateCompositedConst<$type> for T
+        where
+            T: ValidateConst<$type>,
+        {
+            fn validate_composited_const(
+                &self,
+                limit: $type,
+            ) -> Result<(), crate::validation::Composited<ConstError>> {
+                self.validate_const(limit)
+                    .map_err(crate::validation::Composited::Single)
+            }
+        }
+    };
+}
+
+impl_validate_generic_const_literal!(i8);
+impl_validate_generic_const_literal!(i16);
+impl_validate_generic_const_literal!(i32);
+impl_validate_generic_const_literal!(i64);
+#[cfg(feature = "i128")]
+impl_validate_generic_const_literal!(i128);
+impl_validate_generic_const_literal!(isize);
+impl_validate_generic_const_literal!(u8);
+impl_validate_generic_const_literal!(u16);
+impl_validate_generic_const_literal!(u32);
+impl_validate_generic_const_literal!(u64);
+#[cfg(feature = "i128")]
+impl_validate_generic_const_literal!(u128);
+impl_validate_generic_const_literal!(usize);
+impl_validate_generic_const_literal!(std::num::NonZeroI8);
+impl_validate_generic_const_literal!(std::num::NonZeroI16);
+impl_validate_generic_const_literal!(std::num::NonZeroI32);
+impl_validate_generic_const_literal!(std::num::NonZeroI64);
+#[cfg(feature = "i128")]
+impl_validate_generic_const_literal!(std::num::NonZeroI128);
+impl_validate_generic_const_literal!(std::num::NonZeroIsize);
+impl_validate_generic_const_literal!(std::num::NonZeroU8);
+impl_validate_generic_const_literal!(std::num::NonZeroU16);
+impl_validate_generic_const_literal!(std::num::NonZeroU32);
+impl_validate_generic_const_literal!(std::num::NonZeroU64);
+#[cfg(feature = "i128")]
+impl_validate_generic_const_literal!(std::num::NonZeroU128);
+impl_validate_generic_const_literal!(std::num::NonZeroUsize);
+impl_validate_generic_const_literal!(f32);
+impl_validate_generic_const_literal!(f64);
+impl_validate_generic_const_literal!(bool);
+impl_validate_generic_const_literal!(char);
+
+macro_rules! impl_validate_generic_const_str {
+    ($type:ty) => {
+        impl ValidateConst<&'static str> for $type {
+            fn validate_const(&self, const_value: &'static str) -> Result<(), ConstError> {
+                if self == &const_value {
+                    Ok(())
+                } else {
+                    Err(ConstError::new(const_value))
+                }
+            }
+        }
+    };
+}
+
+impl_validate_generic_const_str!(&str);
+impl_validate_generic_const_str!(String);
+impl_validate_generic_const_str!(std::borrow::Cow<'_, str>);
+impl_validate_generic_const_str!(&std::ffi::OsStr);
+impl_validate_generic_const_str!(std::ffi::OsString);
+
+macro_rules! impl_validate_generic_const_path {
+    ($type:ty) => {
+        impl ValidateConst<&'static str> for $type {
+            fn validate_const(&self, const_value: &'static str) -> Result<(), ConstError> {
+                if &std::path::Path::new(const_value) == self {
+                    Ok(())
+                } else {
+                    Err(ConstError::new(const_value))
+                }
+            }
+        }
+    };
+}
+
+impl_validate_generic_const_path!(&std::path::Path);
+impl_validate_generic_const_path!(std::path::PathBuf);
+
+impl<T> ValidateCompositedConst<&'static str> for T
+where
+    T: ValidateConst<&'static str>,
+{
+    fn validate_composited_const(
+        &self,
+        limit: &'static str,
+    ) -> Result<(), crate::validation::Composited<ConstError>> {
+        self.validate_const(limit)
+            .map_err(crate::validation::Composited::Single)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_integer_type_is_true() {
+        assert!(ValidateConst::validate_const(&1, 1).is_ok());
+    }
+
+    #[test]
+    fn test_validate_integer_type_is_false() {
+        assert!(ValidateConst::validate_const(&1, 2).is_err());
+    }
+
+    #[test]
+    fn test_validate_float_type_is_true() {
+        assert!(ValidateConst::validate_const(&0.9, 0.9).is_ok());
+    }
+
+    #[test]
+    fn test_validate_bool_type_is_true() {
+        assert!(ValidateConst::validate_const(&true, true).is_ok());
+    }
+
+    #[test]
+    fn test_validate_char_type_is_false() {
+        assert!(ValidateConst::validate_const(&'a', 'b').is_err());
+    }
+
+    #[test]
+    fn test_validate_str_type() {
+        assert!(ValidateConst::validate_const(&"a", "a").is_ok());
+    }
+
+    #[test]
+    fn test_validate_string_type() {
+        assert!(ValidateConst::validate_const(&String::from("a"), "a").is_ok());
+    }
+
+    #[test]
+    fn test_validate_os_str_type() {
+        assert!(ValidateConst::validate_const(&std::ffi::OsStr::new("a"), "a").is_ok());
+    }
+
+    #[test]
+    fn test_validate_os_string_type() {
+        assert!(ValidateConst::validate_const(&std::ffi::OsString::from("a"), "a").is_ok());
+    }
+
+    #[test]
+    fn test_validate_path_type() {
+        assert!(ValidateConst::validate_const(&std::path::Path::new("a"), "a").is_ok());
+    }
+
+    #[test]
+    fn test_validate_path_buf_type() {
+        assert!(ValidateConst::validate_const(&std::path::PathBuf::from("a"), "a").is_ok());
+    }
+}
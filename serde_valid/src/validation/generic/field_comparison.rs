@@ -0,0 +1,294 @@
+use crate::FieldComparisonError;
+
+/// Comparison operator between two fields.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComparisonOperator {
+    Equals,
+    NotEquals,
+    LessThan,
+    LessThanOrEqual,
+    GreaterThan,
+    GreaterThanOrEqual,
+}
+
+impl ComparisonOperator {
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Self::Equals => "==",
+            Self::NotEquals => "!=",
+            Self::LessThan => "<",
+            Self::LessThanOrEqual => "<=",
+            Self::GreaterThan => ">",
+            Self::GreaterThanOrEqual => ">=",
+        }
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Equals => "equals",
+            Self::NotEquals => "not_equals",
+            Self::LessThan => "less_than",
+            Self::LessThanOrEqual => "less_than_or_equal",
+            Self::GreaterThan => "greater_than",
+            Self::GreaterThanOrEqual => "greater_than_or_equal",
+        }
+    }
+}
+
+impl std::fmt::Display for ComparisonOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.symbol())
+    }
+}
+
+/// Equality operator of [`ValidateFieldEquality`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EqualityOperator {
+    Equals,
+    NotEquals,
+}
+
+impl From<EqualityOperator> for ComparisonOperator {
+    fn from(operator: EqualityOperator) -> Self {
+        match operator {
+            EqualityOperator::Equals => Self::Equals,
+            EqualityOperator::NotEquals => Self::NotEquals,
+        }
+    }
+}
+
+/// Ordering operator of [`ValidateFieldOrdering`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrderingOperator {
+    LessThan,
+    LessThanOrEqual,
+    GreaterThan,
+    GreaterThanOrEqual,
+}
+
+impl From<OrderingOperator> for ComparisonOperator {
+    fn from(operator: OrderingOperator) -> Self {
+        match operator {
+            OrderingOperator::LessThan => Self::LessThan,
+            OrderingOperator::LessThanOrEqual => Self::LessThanOrEqual,
+            OrderingOperator::GreaterThan => Self::GreaterThan,
+            OrderingOperator::GreaterThanOrEqual => Self::GreaterThanOrEqual,
+        }
+    }
+}
+
+/// Equality validation against another field.
+///
+/// Used by `#[validate(equals = field(...))]` and `#[validate(not_equals = field(...))]`.
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_valid::Validate;
+///
+/// #[derive(Validate)]
+/// struct TestStruct {
+///     password: String,
+///     #[validate(equals = field(password))]
+///     password_confirmation: String,
+/// }
+///
+/// let s = TestStruct {
+///     password: "secret".to_string(),
+///     password_confirmation: "secret!".to_string(),
+/// };
+///
+/// assert_eq!(
+///     s.validate().unwrap_err().to_string(),
+///     json!({
+///         "errors": [],
+///         "properties": {
+///             "password_confirmation": {
+///                 "errors": ["The value must be `== password`."]
+///             }
+///         }
+///     })
+///     .to_string()
+/// );
+/// ```
+pub trait ValidateFieldEquality<Rhs: ?Sized = Self> {
+    fn validate_field_equality(
+        &self,
+        operator: EqualityOperator,
+        other_field: String,
+        other: &Rhs,
+    ) -> Result<(), FieldComparisonError>;
+}
+
+impl<T, Rhs> ValidateFieldEquality<Rhs> for T
+where
+    T: PartialEq<Rhs> + ?Sized,
+    Rhs: ?Sized,
+{
+    fn validate_field_equality(
+        &self,
+        operator: EqualityOperator,
+        other_field: String,
+        other: &Rhs,
+    ) -> Result<(), FieldComparisonError> {
+        let is_valid = match operator {
+            EqualityOperator::Equals => self == other,
+            EqualityOperator::NotEquals => self != other,
+        };
+
+        if is_valid {
+            Ok(())
+        } else {
+            Err(FieldComparisonError::new(operator, other_field))
+        }
+    }
+}
+
+/// Ordering validation against another field.
+///
+/// Used by `#[validate(less_than = field(...))]`, `#[validate(less_than_or_equal = field(...))]`,
+/// `#[validate(greater_than = field(...))]` and `#[validate(greater_than_or_equal = field(...))]`.
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_valid::Validate;
+///
+/// #[derive(Validate)]
+/// struct TestStruct {
+///     #[validate(less_than_or_equal = field(end_date))]
+///     start_date: String,
+///     end_date: String,
+/// }
+///
+/// let s = TestStruct {
+///     start_date: "2024-02-01".to_string(),
+///     end_date: "2024-01-01".to_string(),
+/// };
+///
+/// assert_eq!(
+///     s.validate().unwrap_err().to_string(),
+///     json!({
+///         "errors": [],
+///         "properties": {
+///             "start_date": {
+///                 "errors": ["The value must be `<= end_date`."]
+///             }
+///         }
+///     })
+///     .to_string()
+/// );
+/// ```
+pub trait ValidateFieldOrdering<Rhs: ?Sized = Self> {
+    fn validate_field_ordering(
+        &self,
+        operator: OrderingOperator,
+        other_field: String,
+        other: &Rhs,
+    ) -> Result<(), FieldComparisonError>;
+}
+
+impl<T, Rhs> ValidateFieldOrdering<Rhs> for T
+where
+    T: PartialOrd<Rhs> + ?Sized,
+    Rhs: ?Sized,
+{
+    fn validate_field_ordering(
+        &self,
+        operator: OrderingOperator,
+        other_field: String,
+        other: &Rhs,
+    ) -> Result<(), FieldComparisonError> {
+        let is_valid = match operator {
+            OrderingOperator::LessThan => self < other,
+            OrderingOperator::LessThanOrEqual => self <= other,
+            OrderingOperator::GreaterThan => self > other,
+            OrderingOperator::GreaterThanOrEqual => self >= other,
+        };
+
+        if is_valid {
+            Ok(())
+        } else {
+            Err(FieldComparisonError::new(operator, other_field))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_field_equality_is_true() {
+        assert!(ValidateFieldEquality::validate_field_equality(
+            "a",
+            EqualityOperator::Equals,
+            "other".to_string(),
+            "a"
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_validate_field_equality_is_false() {
+        let error = ValidateFieldEquality::validate_field_equality(
+            &1,
+            EqualityOperator::Equals,
+            "other".to_string(),
+            &2,
+        )
+        .unwrap_err();
+
+        assert_eq!(error.operator, ComparisonOperator::Equals);
+        assert_eq!(error.other_field, "other");
+    }
+
+    #[test]
+    fn test_validate_field_not_equals() {
+        assert!(ValidateFieldEquality::validate_field_equality(
+            &1,
+            EqualityOperator::NotEquals,
+            "other".to_string(),
+            &1
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_validate_field_ordering_is_true() {
+        assert!(ValidateFieldOrdering::validate_field_ordering(
+            &1,
+            OrderingOperator::LessThan,
+            "other".to_string(),
+            &2
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_validate_field_ordering_is_false() {
+        assert!(ValidateFieldOrdering::validate_field_ordering(
+            &2.5,
+            OrderingOperator::LessThanOrEqual,
+            "other".to_string(),
+            &2.0
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_validate_field_ordering_boundary() {
+        assert!(ValidateFieldOrdering::validate_field_ordering(
+            &2,
+            OrderingOperator::GreaterThanOrEqual,
+            "other".to_string(),
+            &2
+        )
+        .is_ok());
+        assert!(ValidateFieldOrdering::validate_field_ordering(
+            &2,
+            OrderingOperator::GreaterThan,
+            "other".to_string(),
+            &2
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,68 @@
+use crate::RequiredError;
+
+/// Required validation.
+///
+/// The value must be present. Usually combined with the `when` conditional validation.
+///
+/// See <https://json-schema.org/understanding-json-schema/reference/object#required>
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_valid::{Validate, ValidateRequired};
+///
+/// struct MyType(Option<i32>);
+///
+/// impl ValidateRequired for MyType {
+///     fn validate_required(&self) -> Result<(), serde_valid::RequiredError> {
+///         self.0.validate_required()
+///     }
+/// }
+///
+/// #[derive(Validate)]
+/// struct TestStruct {
+///     #[validate(required)]
+///     val: MyType,
+/// }
+///
+/// let s = TestStruct { val: MyType(None) };
+///
+/// assert_eq!(
+///     s.validate().unwrap_err().to_string(),
+///     json!({
+///         "errors": [],
+///         "properties": {
+///             "val": {
+///                 "errors": ["The value is required."]
+///             }
+///         }
+///     })
+///     .to_string()
+/// );
+/// ```
+pub trait ValidateRequired {
+    fn validate_required(&self) -> Result<(), RequiredError>;
+}
+
+impl<T> ValidateRequired for Option<T> {
+    fn validate_required(&self) -> Result<(), RequiredError> {
+        match self {
+            Some(_) => Ok(()),
+            None => Err(RequiredError {}),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_required_is_true() {
+        assert!(ValidateRequired::validate_required(&Some(1)).is_ok());
+    }
+
+    #[test]
+    fn test_validate_required_is_false() {
+        assert!(ValidateRequired::validate_required(&None::<i32>).is_err());
+    }
+}
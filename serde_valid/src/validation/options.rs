@@ -0,0 +1,111 @@
+/// Options to select which validations run.
+///
+/// Validations without `groups(...)` always run.
+/// Validations with `groups(...)` run only when one of their groups is selected.
+///
+/// `max_errors` and `max_error_items` stop the validation early,
+/// and the skipped validations are reported by the `truncated` error.
+///
+/// ```rust
+/// use serde_valid::{Validate, ValidationOptions};
+///
+/// #[derive(Validate)]
+/// struct User {
+///     #[validate(max_length = 0, groups(create))]
+///     id: String,
+///     #[validate(max_length = 8)]
+///     name: String,
+/// }
+///
+/// let s = User {
+///     id: "123".to_string(),
+///     name: "alice".to_string(),
+/// };
+///
+/// assert!(s.validate().is_ok());
+/// assert!(s.validate_with(&ValidationOptions::new().with_group("update")).is_ok());
+/// assert!(s.validate_with(&ValidationOptions::new().with_group("create")).is_err());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValidationOptions {
+    groups: Vec<String>,
+    max_errors: Option<usize>,
+    max_error_items: Option<usize>,
+}
+
+impl ValidationOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Select the validation group.
+    pub fn with_group(mut self, group: impl Into<String>) -> Self {
+        self.groups.push(group.into());
+        self
+    }
+
+    /// Stop the validation at the first error.
+    pub fn with_fail_fast(self) -> Self {
+        self.with_max_errors(1)
+    }
+
+    /// Stop the validation after `max_errors` errors.
+    pub fn with_max_errors(mut self, max_errors: usize) -> Self {
+        self.max_errors = Some(max_errors);
+        self
+    }
+
+    /// Stop the validation after `max_error_items` items of `Vec`, `HashMap` and `IndexMap` with errors.
+    pub fn with_max_error_items(mut self, max_error_items: usize) -> Self {
+        self.max_error_items = Some(max_error_items);
+        self
+    }
+
+    /// Selected validation groups.
+    pub fn groups(&self) -> &[String] {
+        &self.groups
+    }
+
+    pub fn max_errors(&self) -> Option<usize> {
+        self.max_errors
+    }
+
+    pub fn max_error_items(&self) -> Option<usize> {
+        self.max_error_items
+    }
+
+    /// Whether any of the groups is selected.
+    pub fn is_any_group_selected(&self, groups: &[&str]) -> bool {
+        self.groups
+            .iter()
+            .any(|selected| groups.contains(&selected.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_options_select_no_group() {
+        assert!(!ValidationOptions::default().is_any_group_selected(&["create"]));
+    }
+
+    #[test]
+    fn test_options_with_group_is_selected() {
+        let options = ValidationOptions::new().with_group("create");
+
+        assert!(options.is_any_group_selected(&["create", "update"]));
+        assert!(!options.is_any_group_selected(&["update"]));
+    }
+
+    #[test]
+    fn test_options_with_multiple_groups() {
+        let options = ValidationOptions::new()
+            .with_group("create")
+            .with_group("admin");
+
+        assert_eq!(options.groups(), ["create", "admin"]);
+        assert!(options.is_any_group_selected(&["admin"]));
+    }
+}
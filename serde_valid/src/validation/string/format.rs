@@ -0,0 +1,397 @@
+use crate::{traits::IsFormat, FormatError};
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+/// Semantic string formats defined by JSON Schema.
+///
+/// See <https://json-schema.org/understanding-json-schema/reference/string#built-in-formats>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StringFormat {
+    Email,
+    Uri,
+    Uuid,
+    Ipv4,
+    Ipv6,
+    Hostname,
+    Date,
+    Time,
+    DateTime,
+    Duration,
+}
+
+static EMAIL_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"^[a-zA-Z0-9.!#$%&'*+/=?^_`{|}~-]+@[a-zA-Z0-9](?:[a-zA-Z0-9-]{0,61}[a-zA-Z0-9])?(?:\.[a-zA-Z0-9](?:[a-zA-Z0-9-]{0,61}[a-zA-Z0-9])?)*$",
+    )
+    .unwrap()
+});
+
+static URI_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"^[A-Za-z][A-Za-z0-9+.\-]*:[^\s<>"{}|\\^`]*$"#).unwrap());
+
+static UUID_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}$")
+        .unwrap()
+});
+
+static DURATION_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^P(?:(\d+W)|(?:\d+Y)?(?:\d+M)?(?:\d+D)?(?:T(?:\d+H)?(?:\d+M)?(?:\d+S)?)?)$")
+        .unwrap()
+});
+
+impl StringFormat {
+    /// The format name used by the `format` keyword of JSON Schema.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Email => "email",
+            Self::Uri => "uri",
+            Self::Uuid => "uuid",
+            Self::Ipv4 => "ipv4",
+            Self::Ipv6 => "ipv6",
+            Self::Hostname => "hostname",
+            Self::Date => "date",
+            Self::Time => "time",
+            Self::DateTime => "date-time",
+            Self::Duration => "duration",
+        }
+    }
+
+    pub fn is_valid(&self, value: &str) -> bool {
+        match self {
+            Self::Email => EMAIL_REGEX.is_match(value),
+            Self::Uri => URI_REGEX.is_match(value),
+            Self::Uuid => UUID_REGEX.is_match(value),
+            Self::Ipv4 => value.parse::<std::net::Ipv4Addr>().is_ok(),
+            Self::Ipv6 => value.parse::<std::net::Ipv6Addr>().is_ok(),
+            Self::Hostname => is_hostname(value),
+            Self::Date => is_date(value),
+            Self::Time => is_time(value),
+            Self::DateTime => is_date_time(value),
+            Self::Duration => is_duration(value),
+        }
+    }
+}
+
+impl std::fmt::Display for StringFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl std::str::FromStr for StringFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "email" => Ok(Self::Email),
+            "uri" => Ok(Self::Uri),
+            "uuid" => Ok(Self::Uuid),
+            "ipv4" => Ok(Self::Ipv4),
+            "ipv6" => Ok(Self::Ipv6),
+            "hostname" => Ok(Self::Hostname),
+            "date" => Ok(Self::Date),
+            "time" => Ok(Self::Time),
+            "date-time" => Ok(Self::DateTime),
+            "duration" => Ok(Self::Duration),
+            _ => Err(format!("unknown format \"{s}\"")),
+        }
+    }
+}
+
+fn is_hostname(value: &str) -> bool {
+    let value = value.strip_suffix('.').unwrap_or(value);
+    !value.is_empty()
+        && value.len() <= 253
+        && value.split('.').all(|label| {
+            !label.is_empty()
+                && label.len() <= 63
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label
+                    .bytes()
+                    .all(|c| c.is_ascii_alphanumeric() || c == b'-')
+        })
+}
+
+fn parse_digits(value: &str, len: usize) -> Option<u32> {
+    if value.len() == len && value.bytes().all(|c| c.is_ascii_digit()) {
+        value.parse().ok()
+    } else {
+        None
+    }
+}
+
+fn is_date(value: &str) -> bool {
+    let mut parts = value.split('-');
+    let (Some(year), Some(month), Some(day), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return false;
+    };
+    let (Some(year), Some(month), Some(day)) = (
+        parse_digits(year, 4),
+        parse_digits(month, 2),
+        parse_digits(day, 2),
+    ) else {
+        return false;
+    };
+
+    let is_leap_year = (year % 4 == 0 && year % 100 != 0) || year % 400 == 0;
+    let days_in_month = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year => 29,
+        2 => 28,
+        _ => return false,
+    };
+
+    (1..=days_in_month).contains(&day)
+}
+
+fn is_time(value: &str) -> bool {
+    let (time, offset) = if let Some(time) = value.strip_suffix(['Z', 'z']) {
+        (time, None)
+    } else {
+        match value.rfind(['+', '-']) {
+            Some(index) => (&value[..index], Some(&value[index + 1..])),
+            None => return false,
+        }
+    };
+
+    if let Some(offset) = offset {
+        let Some((hour, minute)) = offset.split_once(':') else {
+            return false;
+        };
+        match (parse_digits(hour, 2), parse_digits(minute, 2)) {
+            (Some(hour), Some(minute)) if hour <= 23 && minute <= 59 => {}
+            _ => return false,
+        }
+    }
+
+    let (time, fraction) = match time.split_once('.') {
+        Some((time, fraction)) => (time, Some(fraction)),
+        None => (time, None),
+    };
+    if let Some(fraction) = fraction {
+        if fraction.is_empty() || !fraction.bytes().all(|c| c.is_ascii_digit()) {
+            return false;
+        }
+    }
+
+    let mut parts = time.split(':');
+    let (Some(hour), Some(minute), Some(second), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return false;
+    };
+    matches!(
+        (
+            parse_digits(hour, 2),
+            parse_digits(minute, 2),
+            parse_digits(second, 2),
+        ),
+        (Some(hour), Some(minute), Some(second)) if hour <= 23 && minute <= 59 && second <= 60
+    )
+}
+
+fn is_date_time(value: &str) -> bool {
+    match value.split_once(['T', 't']) {
+        Some((date, time)) => is_date(date) && is_time(time),
+        None => false,
+    }
+}
+
+fn is_duration(value: &str) -> bool {
+    DURATION_REGEX.is_match(value) && value != "P" && !value.ends_with('T')
+}
+
+/// Format validation of the string.
+///
+/// See <https://json-schema.org/understanding-json-schema/reference/string#format>
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_valid::{Validate, ValidateFormat};
+/// use serde_valid::validation::StringFormat;
+///
+/// struct MyType(String);
+///
+/// impl ValidateFormat for MyType {
+///     fn validate_format(
+///         &self,
+///         format: StringFormat,
+///     ) -> Result<(), serde_valid::FormatError> {
+///         self.0.validate_format(format)
+///     }
+/// }
+///
+/// #[derive(Validate)]
+/// struct TestStruct {
+///     #[validate(format = "email")]
+///     val: MyType,
+/// }
+///
+/// let s = TestStruct {
+///     val: MyType(String::from("not-an-email")),
+/// };
+///
+/// assert_eq!(
+///     s.validate().unwrap_err().to_string(),
+///     json!({
+///         "errors": [],
+///         "properties": {
+///             "val": {
+///                 "errors": [r#"The value must match the format of "email"."#]
+///             }
+///         }
+///     })
+///     .to_string()
+/// );
+/// ```
+pub trait ValidateFormat {
+    fn validate_format(&self, format: StringFormat) -> Result<(), FormatError>;
+}
+
+impl<T> ValidateFormat for T
+where
+    T: IsFormat + ?Sized,
+{
+    fn validate_format(&self, format: StringFormat) -> Result<(), FormatError> {
+        if self.is_format(format) {
+            Ok(())
+        } else {
+            Err(FormatError::new(format))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::borrow::Cow;
+    use std::ffi::{OsStr, OsString};
+
+    #[test]
+    fn test_validate_string_format_str_type() {
+        assert!(ValidateFormat::validate_format("user@example.com", StringFormat::Email).is_ok());
+    }
+
+    #[test]
+    fn test_validate_string_format_string_type() {
+        assert!(ValidateFormat::validate_format(
+            &String::from("user@example.com"),
+            StringFormat::Email
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_validate_string_format_cow_str_type() {
+        assert!(
+            ValidateFormat::validate_format(&Cow::from("127.0.0.1"), StringFormat::Ipv4).is_ok()
+        );
+    }
+
+    #[test]
+    fn test_validate_string_format_os_str_type() {
+        assert!(ValidateFormat::validate_format(OsStr::new("::1"), StringFormat::Ipv6).is_ok());
+    }
+
+    #[test]
+    fn test_validate_string_format_os_string_type() {
+        assert!(ValidateFormat::validate_format(
+            &OsString::from("example.com"),
+            StringFormat::Hostname
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_validate_string_format_is_false() {
+        assert!(ValidateFormat::validate_format("not-an-email", StringFormat::Email).is_err());
+    }
+
+    #[test]
+    fn test_string_format_email() {
+        assert!(StringFormat::Email.is_valid("first.last+tag@mail.example.co.jp"));
+        assert!(!StringFormat::Email.is_valid("user@"));
+        assert!(!StringFormat::Email.is_valid("user@-example.com"));
+        assert!(!StringFormat::Email.is_valid("user example@example.com"));
+    }
+
+    #[test]
+    fn test_string_format_uri() {
+        assert!(StringFormat::Uri.is_valid("https://example.com/path?query=1#fragment"));
+        assert!(StringFormat::Uri.is_valid("urn:isbn:0451450523"));
+        assert!(!StringFormat::Uri.is_valid("/relative/path"));
+        assert!(!StringFormat::Uri.is_valid("https://example.com/with space"));
+    }
+
+    #[test]
+    fn test_string_format_uuid() {
+        assert!(StringFormat::Uuid.is_valid("67e55044-10b1-426f-9247-bb680e5fe0c8"));
+        assert!(!StringFormat::Uuid.is_valid("67e5504410b1426f9247bb680e5fe0c8"));
+        assert!(!StringFormat::Uuid.is_valid("67e55044-10b1-426f-9247-bb680e5fe0cg"));
+    }
+
+    #[test]
+    fn test_string_format_ip() {
+        assert!(StringFormat::Ipv4.is_valid("192.168.0.1"));
+        assert!(!StringFormat::Ipv4.is_valid("256.0.0.1"));
+        assert!(StringFormat::Ipv6.is_valid("2001:db8::8a2e:370:7334"));
+        assert!(!StringFormat::Ipv6.is_valid("192.168.0.1"));
+    }
+
+    #[test]
+    fn test_string_format_hostname() {
+        assert!(StringFormat::Hostname.is_valid("www.example.com"));
+        assert!(StringFormat::Hostname.is_valid("localhost"));
+        assert!(!StringFormat::Hostname.is_valid("-example.com"));
+        assert!(!StringFormat::Hostname.is_valid("example..com"));
+        assert!(!StringFormat::Hostname.is_valid(&"a".repeat(64)));
+    }
+
+    #[test]
+    fn test_string_format_date() {
+        assert!(StringFormat::Date.is_valid("2020-02-29"));
+        assert!(!StringFormat::Date.is_valid("2021-02-29"));
+        assert!(!StringFormat::Date.is_valid("2020-13-01"));
+        assert!(!StringFormat::Date.is_valid("2020/09/10"));
+    }
+
+    #[test]
+    fn test_string_format_time() {
+        assert!(StringFormat::Time.is_valid("12:34:56Z"));
+        assert!(StringFormat::Time.is_valid("23:59:60.123+09:00"));
+        assert!(!StringFormat::Time.is_valid("12:34:56"));
+        assert!(!StringFormat::Time.is_valid("24:00:00Z"));
+    }
+
+    #[test]
+    fn test_string_format_date_time() {
+        assert!(StringFormat::DateTime.is_valid("2020-09-10T12:34:56Z"));
+        assert!(StringFormat::DateTime.is_valid("2020-09-10t12:34:56.789-05:00"));
+        assert!(!StringFormat::DateTime.is_valid("2020-09-10 12:34:56Z"));
+        assert!(!StringFormat::DateTime.is_valid("2020-09-10"));
+    }
+
+    #[test]
+    fn test_string_format_duration() {
+        assert!(StringFormat::Duration.is_valid("P3Y6M4DT12H30M5S"));
+        assert!(StringFormat::Duration.is_valid("PT20M"));
+        assert!(StringFormat::Duration.is_valid("P2W"));
+        assert!(!StringFormat::Duration.is_valid("P"));
+        assert!(!StringFormat::Duration.is_valid("P1DT"));
+        assert!(!StringFormat::Duration.is_valid("1D"));
+    }
+
+    #[test]
+    fn test_string_format_name() {
+        assert_eq!(StringFormat::DateTime.to_string(), "date-time");
+        assert_eq!(
+            "date-time".parse::<StringFormat>(),
+            Ok(StringFormat::DateTime)
+        );
+        assert!("datetime".parse::<StringFormat>().is_err());
+    }
+}
@@ -0,0 +1,363 @@
+use std::collections::HashSet;
+use std::sync::Mutex;
+
+use serde_json::json;
+use serde_valid::{AsyncValidate, Validate, ValidationOptions};
+
+/// In-memory stand-in for the database.
+static USER_NAMES: Mutex<Option<HashSet<String>>> = Mutex::new(None);
+
+async fn exists_user_name(name: &str) -> bool {
+    USER_NAMES
+        .lock()
+        .unwrap()
+        .get_or_insert_with(|| HashSet::from(["alice".to_string(), "bob".to_string()]))
+        .contains(name)
+}
+
+async fn unique_name(name: &String) -> Result<(), serde_valid::validation::Error> {
+    if exists_user_name(name).await {
+        Err(serde_valid::validation::Error::Custom(format!(
+            "The name `{name}` is already taken."
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(Validate)]
+struct User {
+    #[validate(max_length = 8)]
+    #[validate(custom_async(unique_name))]
+    name: String,
+}
+
+#[tokio::test]
+async fn custom_async_is_ok() {
+    let s = User {
+        name: "carol".to_string(),
+    };
+
+    assert!(s.validate_async().await.is_ok());
+}
+
+#[tokio::test]
+async fn custom_async_is_err() {
+    let s = User {
+        name: "alice".to_string(),
+    };
+
+    assert!(s.validate().is_ok());
+    assert_eq!(
+        s.validate_async().await.unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "name": {
+                    "errors": ["The name `alice` is already taken."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[tokio::test]
+async fn custom_async_merges_sync_errors() {
+    let s = User {
+        name: "alice".repeat(2),
+    };
+
+    assert_eq!(
+        s.validate_async().await.unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "name": {
+                    "errors": ["The length of the value must be `<= 8`."]
+                }
+            }
+        })
+        .to_string()
+    );
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(max_length = 2)]
+        #[validate(custom_async(unique_name))]
+        name: String,
+    }
+
+    let s = TestStruct {
+        name: "bob".to_string(),
+    };
+
+    assert_eq!(
+        s.validate_async().await.unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "name": {
+                    "errors": [
+                        "The length of the value must be `<= 2`.",
+                        "The name `bob` is already taken."
+                    ]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[tokio::test]
+async fn custom_async_closure_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(custom_async(|name| async move {
+            if exists_user_name(name).await {
+                Err(serde_valid::validation::Error::Custom("taken.".to_string()))
+            } else {
+                Ok(())
+            }
+        }))]
+        name: String,
+    }
+
+    assert!(TestStruct {
+        name: "carol".to_string()
+    }
+    .validate_async()
+    .await
+    .is_ok());
+    assert!(TestStruct {
+        name: "bob".to_string()
+    }
+    .validate_async()
+    .await
+    .is_err());
+}
+
+#[tokio::test]
+async fn custom_async_nested_is_err() {
+    #[derive(Validate)]
+    struct Team {
+        #[validate(nested_async)]
+        leader: User,
+        #[validate(nested_async)]
+        members: Vec<User>,
+        #[validate(nested_async)]
+        sub_leader: Option<User>,
+    }
+
+    let s = Team {
+        leader: User {
+            name: "carol".to_string(),
+        },
+        members: vec![
+            User {
+                name: "dave".to_string(),
+            },
+            User {
+                name: "bob".to_string(),
+            },
+        ],
+        sub_leader: Some(User {
+            name: "alice".to_string(),
+        }),
+    };
+
+    assert!(s.validate().is_ok());
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(
+            &s.validate_async().await.unwrap_err().to_string()
+        )
+        .unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "members": {
+                    "errors": [],
+                    "items": {
+                        "1": {
+                            "errors": [],
+                            "properties": {
+                                "name": {
+                                    "errors": ["The name `bob` is already taken."]
+                                }
+                            }
+                        }
+                    }
+                },
+                "sub_leader": {
+                    "errors": [],
+                    "properties": {
+                        "name": {
+                            "errors": ["The name `alice` is already taken."]
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[tokio::test]
+async fn custom_async_nested_without_nested_async_is_not_awaited() {
+    #[derive(Validate)]
+    struct Team {
+        #[validate(custom_async(unique_name))]
+        name: String,
+        #[validate]
+        leader: User,
+    }
+
+    let s = Team {
+        name: "team".to_string(),
+        leader: User {
+            name: "alice".to_string(),
+        },
+    };
+
+    assert!(s.validate_async().await.is_ok());
+}
+
+#[tokio::test]
+async fn custom_async_with_groups_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(custom_async(unique_name), groups(create))]
+        name: String,
+    }
+
+    let s = TestStruct {
+        name: "alice".to_string(),
+    };
+
+    assert!(s.validate_async().await.is_ok());
+    assert!(s
+        .validate_async_with(&ValidationOptions::new().with_group("create"))
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn custom_async_enum_is_err() {
+    #[derive(Validate)]
+    enum TestEnum {
+        Named {
+            #[validate(custom_async(unique_name))]
+            name: String,
+        },
+        Unnamed(#[validate(custom_async(unique_name))] String),
+    }
+
+    assert!(TestEnum::Named {
+        name: "carol".to_string()
+    }
+    .validate_async()
+    .await
+    .is_ok());
+    assert!(TestEnum::Named {
+        name: "alice".to_string()
+    }
+    .validate_async()
+    .await
+    .is_err());
+    assert!(TestEnum::Unnamed("bob".to_string())
+        .validate_async()
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn custom_async_generic_nested_is_err() {
+    #[derive(Validate)]
+    struct Page<T: Validate> {
+        #[validate(nested_async)]
+        items: Vec<T>,
+    }
+
+    let s = Page {
+        items: vec![User {
+            name: "alice".to_string(),
+        }],
+    };
+
+    assert!(s.validate().is_ok());
+    assert!(s.validate_async().await.is_err());
+}
+
+#[tokio::test]
+async fn manual_validate_impl_is_async_validate() {
+    struct Name(String);
+
+    impl Validate for Name {
+        fn validate(&self) -> Result<(), serde_valid::validation::Errors> {
+            if self.0.is_empty() {
+                Err(serde_valid::validation::Errors::NewType(vec![
+                    serde_valid::validation::Error::Custom("empty.".to_string()),
+                ]))
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    impl AsyncValidate for Name {}
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(nested_async)]
+        name: Name,
+    }
+
+    assert!(TestStruct {
+        name: Name("alice".to_string())
+    }
+    .validate_async()
+    .await
+    .is_ok());
+    assert!(TestStruct {
+        name: Name("".to_string())
+    }
+    .validate_async()
+    .await
+    .is_err());
+}
+
+#[test]
+fn non_sync_type_without_async_validations_is_validate() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(maximum = 10)]
+        val: i32,
+        _cache: std::cell::Cell<Option<i32>>,
+    }
+
+    let s = TestStruct {
+        val: 20,
+        _cache: std::cell::Cell::new(None),
+    };
+
+    assert!(s.validate().is_err());
+}
+
+#[tokio::test]
+async fn type_without_async_validations_is_async_validate() {
+    #[derive(Validate)]
+    struct TestStruct<T> {
+        #[validate(maximum = 10)]
+        val: i32,
+        _other: T,
+    }
+
+    let s = TestStruct {
+        val: 20,
+        _other: "other",
+    };
+
+    assert_eq!(
+        s.validate_async().await.unwrap_err().to_string(),
+        s.validate().unwrap_err().to_string()
+    );
+}
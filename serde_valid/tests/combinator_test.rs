@@ -0,0 +1,323 @@
+use serde_json::json;
+use serde_valid::Validate;
+
+#[test]
+fn any_of_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(any_of(pattern = r"^\d+$", enumerate("auto", "none")))]
+        val: String,
+    }
+
+    assert!(TestStruct {
+        val: "123".to_string()
+    }
+    .validate()
+    .is_ok());
+    assert!(TestStruct {
+        val: "auto".to_string()
+    }
+    .validate()
+    .is_ok());
+}
+
+#[test]
+fn any_of_err_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(any_of(pattern = r"^\d+$", enumerate("auto", "none")))]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: "manual".to_string(),
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [
+                        "The value must satisfy any of the validations. [0] The value must match the pattern of \"^\\d+$\". [1] The value must be in [auto, none]."
+                    ]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn all_of_err_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(all_of(min_length = 2, max_length = 4, pattern = r"^[a-z]+$"))]
+        val: String,
+    }
+
+    assert!(TestStruct {
+        val: "abc".to_string()
+    }
+    .validate()
+    .is_ok());
+
+    let s = TestStruct {
+        val: "ABCDE".to_string(),
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [
+                        "The value must satisfy all of the validations. [1] The length of the value must be `<= 4`. [2] The value must match the pattern of \"^[a-z]+$\"."
+                    ]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn one_of_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(one_of(multiple_of = 3, multiple_of = 5))]
+        val: i32,
+    }
+
+    assert!(TestStruct { val: 9 }.validate().is_ok());
+    assert!(TestStruct { val: 10 }.validate().is_ok());
+}
+
+#[test]
+fn one_of_err_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(one_of(multiple_of = 3, multiple_of = 5))]
+        val: i32,
+    }
+
+    assert_eq!(
+        TestStruct { val: 15 }.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [
+                        "The value must satisfy exactly one of the validations, but [0, 1] are satisfied."
+                    ]
+                }
+            }
+        })
+        .to_string()
+    );
+
+    assert_eq!(
+        TestStruct { val: 7 }.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [
+                        "The value must satisfy exactly one of the validations. [0] The value must be multiple of `3`. [1] The value must be multiple of `5`."
+                    ]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn not_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(not(enumerate("root")))]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: "user".to_string(),
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn not_err_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(not(enumerate("root")))]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: "root".to_string(),
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [
+                        "The value must not satisfy the validation."
+                    ]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn nested_combinator_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(any_of(not(minimum = 0), all_of(multiple_of = 2, maximum = 10)))]
+        val: i32,
+    }
+
+    assert!(TestStruct { val: -1 }.validate().is_ok());
+    assert!(TestStruct { val: 4 }.validate().is_ok());
+    assert!(TestStruct { val: 5 }.validate().is_err());
+    assert!(TestStruct { val: 12 }.validate().is_err());
+}
+
+#[test]
+fn combinator_with_const_and_custom() {
+    fn is_even(val: &i32) -> Result<(), serde_valid::validation::Error> {
+        if val % 2 == 0 {
+            Ok(())
+        } else {
+            Err(serde_valid::validation::Error::Custom(
+                "The value must be even.".to_string(),
+            ))
+        }
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(any_of(const = 1, custom(is_even)))]
+        val: i32,
+    }
+
+    assert!(TestStruct { val: 1 }.validate().is_ok());
+    assert!(TestStruct { val: 2 }.validate().is_ok());
+    assert_eq!(
+        TestStruct { val: 3 }.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [
+                        "The value must satisfy any of the validations. [0] The value must be `1`. [1] The value must be even."
+                    ]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn combinator_option_type() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(any_of(format = "ipv4", format = "ipv6"))]
+        val: Option<String>,
+    }
+
+    assert!(TestStruct { val: None }.validate().is_ok());
+    assert!(TestStruct {
+        val: Some("::1".to_string())
+    }
+    .validate()
+    .is_ok());
+    assert!(TestStruct {
+        val: Some("localhost".to_string())
+    }
+    .validate()
+    .is_err());
+}
+
+#[test]
+fn combinator_unnamed_struct() {
+    #[derive(Validate)]
+    struct TestStruct(#[validate(not(maximum = 10))] i32);
+
+    assert!(TestStruct(11).validate().is_ok());
+    assert!(TestStruct(10).validate().is_err());
+}
+
+#[test]
+fn combinator_custom_err_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(
+            any_of(pattern = r"^\d+$", enumerate("auto", "none")),
+            message = "this is custom message."
+        )]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: "manual".to_string(),
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [
+                        "this is custom message."
+                    ]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn combinator_custom_err_message_fn() {
+    fn error_message(params: &serde_valid::AnyOfError) -> String {
+        format!("{} validations failed.", params.errors.len())
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(
+            any_of(pattern = r"^\d+$", enumerate("auto", "none")),
+            message_fn(error_message)
+        )]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: "manual".to_string(),
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [
+                        "2 validations failed."
+                    ]
+                }
+            }
+        })
+        .to_string()
+    );
+}
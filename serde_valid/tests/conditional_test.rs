@@ -0,0 +1,303 @@
+use serde_json::json;
+use serde_valid::Validate;
+
+#[test]
+fn required_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(required)]
+        val: Option<i32>,
+    }
+
+    assert!(TestStruct { val: Some(1) }.validate().is_ok());
+}
+
+#[test]
+fn required_err_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(required)]
+        val: Option<i32>,
+    }
+
+    assert_eq!(
+        TestStruct { val: None }.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["The value is required."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn required_if_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        kind: String,
+        #[validate(required_if(field = "kind", equals = "card"))]
+        card_number: Option<String>,
+    }
+
+    assert!(TestStruct {
+        kind: "card".to_string(),
+        card_number: Some("4242424242424242".to_string()),
+    }
+    .validate()
+    .is_ok());
+    assert!(TestStruct {
+        kind: "cash".to_string(),
+        card_number: None,
+    }
+    .validate()
+    .is_ok());
+}
+
+#[test]
+fn required_if_err_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        kind: String,
+        #[validate(required_if(field = kind, equals = "card"))]
+        card_number: Option<String>,
+    }
+
+    let s = TestStruct {
+        kind: "card".to_string(),
+        card_number: None,
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "card_number": {
+                    "errors": ["The value is required."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn required_if_custom_err_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        kind: String,
+        #[validate(
+            required_if(field = kind, equals = "card"),
+            message = "card number is required for card payments."
+        )]
+        card_number: Option<String>,
+    }
+
+    let s = TestStruct {
+        kind: "card".to_string(),
+        card_number: None,
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "card_number": {
+                    "errors": ["card number is required for card payments."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn when_then_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(when(field = "kind", equals = "card", then(required, pattern = r"^\d{16}$")))]
+        card_number: Option<String>,
+        kind: String,
+    }
+
+    assert!(TestStruct {
+        card_number: Some("4242424242424242".to_string()),
+        kind: "card".to_string(),
+    }
+    .validate()
+    .is_ok());
+    assert!(TestStruct {
+        card_number: Some("not a card".to_string()),
+        kind: "cash".to_string(),
+    }
+    .validate()
+    .is_ok());
+}
+
+#[test]
+fn when_then_err_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(when(field = "kind", equals = "card", then(required, pattern = r"^\d{16}$")))]
+        card_number: Option<String>,
+        #[validate(enumerate("card", "cash"))]
+        kind: String,
+    }
+
+    let s = TestStruct {
+        card_number: Some("4242".to_string()),
+        kind: "card".to_string(),
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "card_number": {
+                    "errors": ["The value must match the pattern of \"^\\d{16}$\"."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn when_else_err_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        is_business: bool,
+        #[validate(when(
+            field = is_business,
+            equals = true,
+            then(min_length = 8),
+            else(max_length = 0)
+        ))]
+        tax_id: String,
+    }
+
+    assert!(TestStruct {
+        is_business: false,
+        tax_id: "".to_string(),
+    }
+    .validate()
+    .is_ok());
+
+    let s = TestStruct {
+        is_business: false,
+        tax_id: "12345678".to_string(),
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "tax_id": {
+                    "errors": ["The length of the value must be `<= 0`."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn when_enum_condition_err_message() {
+    #[derive(PartialEq)]
+    enum Kind {
+        Card,
+        #[allow(dead_code)]
+        Cash,
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        kind: Kind,
+        #[validate(when(field = kind, equals = Kind::Card, then(required)))]
+        card_number: Option<String>,
+    }
+
+    let s = TestStruct {
+        kind: Kind::Card,
+        card_number: None,
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "card_number": {
+                    "errors": ["The value is required."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn when_enum_variant_err_message() {
+    #[derive(Validate)]
+    enum TestEnum {
+        Payment {
+            kind: String,
+            #[validate(when(field = kind, equals = "card", then(required)))]
+            card_number: Option<String>,
+        },
+    }
+
+    let s = TestEnum::Payment {
+        kind: "card".to_string(),
+        card_number: None,
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "card_number": {
+                    "errors": ["The value is required."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn when_with_rename_err_message() {
+    #[derive(Validate, serde::Deserialize)]
+    struct TestStruct {
+        kind: String,
+        #[serde(rename = "cardNumber")]
+        #[validate(required_if(field = kind, equals = "card"))]
+        card_number: Option<String>,
+    }
+
+    let s = TestStruct {
+        kind: "card".to_string(),
+        card_number: None,
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "cardNumber": {
+                    "errors": ["The value is required."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
@@ -0,0 +1,238 @@
+use serde_json::json;
+use serde_valid::{Validate, ValidateConst};
+
+#[test]
+fn const_integer_type() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(const = 1)]
+        val: i32,
+    }
+
+    let s = TestStruct { val: 1 };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn const_float_type() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(const = 0.3)]
+        val: f32,
+    }
+
+    let s = TestStruct { val: 0.3 };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn const_bool_type() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(const = true)]
+        val: bool,
+    }
+
+    let s = TestStruct { val: true };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn const_str_type() {
+    #[derive(Validate)]
+    struct TestStruct<'a> {
+        #[validate(const = "v1")]
+        val: &'a str,
+    }
+
+    let s = TestStruct { val: "v1" };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn const_string_type() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(const = "v1")]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: "v1".to_string(),
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn const_vec_type() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(const = 3)]
+        val: Vec<u8>,
+    }
+
+    let s = TestStruct { val: vec![3, 3] };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn const_option_type() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(const = "v1")]
+        val: Option<String>,
+    }
+
+    let s = TestStruct { val: None };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn const_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(const = 1)]
+        val: i32,
+    }
+
+    let s = TestStruct { val: 2 };
+    assert!(s.validate().is_err());
+}
+
+#[test]
+fn const_err_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(const = "v1")]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: "v2".to_string(),
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [
+                        "The value must be `v1`."
+                    ]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn const_vec_type_err_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(const = 3)]
+        val: Vec<u8>,
+    }
+
+    let s = TestStruct { val: vec![3, 4] };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [],
+                    "items": {
+                        "1": {
+                            "errors": ["The value must be `3`."]
+                        }
+                    }
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn const_custom_err_message_fn() {
+    fn error_message(params: &serde_valid::ConstError) -> String {
+        format!("this is not {}.", params.const_value)
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(const = "v1", message_fn(error_message))]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: "v2".to_string(),
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [
+                        "this is not v1."
+                    ]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn const_custom_err_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(const = 1, message = "this is custom message.")]
+        val: i32,
+    }
+
+    let s = TestStruct { val: 2 };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [
+                        "this is custom message."
+                    ]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn const_trait() {
+    struct MyType(String);
+
+    impl ValidateConst<&'static str> for MyType {
+        fn validate_const(&self, const_value: &'static str) -> Result<(), serde_valid::ConstError> {
+            self.0.validate_const(const_value)
+        }
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(const = "v1")]
+        val: MyType,
+    }
+
+    let s = TestStruct {
+        val: MyType("v1".to_string()),
+    };
+
+    assert!(s.validate().is_ok());
+}
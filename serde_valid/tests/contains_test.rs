@@ -0,0 +1,257 @@
+use serde_json::json;
+use serde_valid::{Validate, ValidateContains};
+
+#[derive(Debug, PartialEq)]
+enum Role {
+    Admin,
+    User,
+}
+
+fn is_admin(role: &Role) -> bool {
+    *role == Role::Admin
+}
+
+#[test]
+fn contains_vec_type() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(contains(custom(is_admin)))]
+        val: Vec<Role>,
+    }
+
+    let s = TestStruct {
+        val: vec![Role::User, Role::Admin],
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn contains_array_type() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(contains(custom(is_admin)))]
+        val: [Role; 2],
+    }
+
+    let s = TestStruct {
+        val: [Role::User, Role::Admin],
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn contains_closure() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(contains(custom(|x| *x > 10)))]
+        val: Vec<i32>,
+    }
+
+    let s = TestStruct {
+        val: vec![1, 2, 11],
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn contains_option_type() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(contains(custom(is_admin)))]
+        val: Option<Vec<Role>>,
+    }
+
+    let s = TestStruct { val: None };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn contains_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(contains(custom(is_admin)))]
+        val: Vec<Role>,
+    }
+
+    let s = TestStruct {
+        val: vec![Role::User, Role::User],
+    };
+    assert!(s.validate().is_err());
+}
+
+#[test]
+fn min_contains_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(contains(custom(is_admin), min_contains = 2))]
+        val: Vec<Role>,
+    }
+
+    let s = TestStruct {
+        val: vec![Role::Admin, Role::User, Role::Admin],
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn max_contains_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(contains(custom(is_admin), min_contains = 0, max_contains = 1))]
+        val: Vec<Role>,
+    }
+
+    let s = TestStruct {
+        val: vec![Role::User, Role::User],
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn contains_err_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(contains(custom(is_admin)))]
+        val: Vec<Role>,
+    }
+
+    let s = TestStruct {
+        val: vec![Role::User],
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [
+                        "The items must contain `>= 1` matching items, but `0` matched."
+                    ]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn max_contains_err_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(contains(custom(|x: &i32| *x < 0), max_contains = 2))]
+        val: Vec<i32>,
+    }
+
+    let s = TestStruct {
+        val: vec![-1, -2, -3, 4],
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [
+                        "The items must contain `<= 2` matching items, but `3` matched."
+                    ]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn contains_custom_err_message_fn() {
+    fn error_message(params: &serde_valid::ContainsError) -> String {
+        format!("{} admin(s) found.", params.matched)
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(
+            contains(custom(is_admin), min_contains = 2),
+            message_fn(error_message)
+        )]
+        val: Vec<Role>,
+    }
+
+    let s = TestStruct {
+        val: vec![Role::Admin, Role::User],
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [
+                        "1 admin(s) found."
+                    ]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn contains_custom_err_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(contains(custom(is_admin)), message = "this is custom message.")]
+        val: Vec<Role>,
+    }
+
+    let s = TestStruct { val: vec![] };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [
+                        "this is custom message."
+                    ]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn contains_trait() {
+    struct MyType(Vec<Role>);
+
+    impl ValidateContains<Role> for MyType {
+        fn validate_contains<F>(
+            &self,
+            predicate: F,
+            min_contains: usize,
+            max_contains: Option<usize>,
+        ) -> Result<(), serde_valid::ContainsError>
+        where
+            F: Fn(&Role) -> bool,
+        {
+            self.0
+                .validate_contains(predicate, min_contains, max_contains)
+        }
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(contains(custom(is_admin)))]
+        val: MyType,
+    }
+
+    let s = TestStruct {
+        val: MyType(vec![Role::Admin]),
+    };
+
+    assert!(s.validate().is_ok());
+}
@@ -0,0 +1,368 @@
+use serde::Deserialize;
+use serde_json::json;
+use serde_valid::json::FromJsonValueWithContext;
+use serde_valid::{Validate, ValidateWithContext, ValidationOptions};
+
+struct Tenant {
+    currencies: Vec<String>,
+    max_items: usize,
+}
+
+fn tenant() -> Tenant {
+    Tenant {
+        currencies: vec!["JPY".to_string(), "USD".to_string()],
+        max_items: 2,
+    }
+}
+
+fn enabled_currency(
+    currency: &String,
+    tenant: &Tenant,
+) -> Result<(), serde_valid::validation::Error> {
+    if tenant.currencies.contains(currency) {
+        Ok(())
+    } else {
+        Err(serde_valid::validation::Error::Custom(format!(
+            "The currency `{currency}` is not enabled."
+        )))
+    }
+}
+
+#[test]
+fn context_custom_is_ok() {
+    #[derive(Validate)]
+    #[validate(context = Tenant)]
+    struct Payment {
+        #[validate(custom(enabled_currency))]
+        currency: String,
+    }
+
+    let s = Payment {
+        currency: "JPY".to_string(),
+    };
+
+    assert!(s.validate_with_context(&tenant()).is_ok());
+}
+
+#[test]
+fn context_custom_err_message() {
+    #[derive(Validate)]
+    #[validate(context = Tenant)]
+    struct Payment {
+        #[validate(custom(enabled_currency))]
+        currency: String,
+    }
+
+    let s = Payment {
+        currency: "EUR".to_string(),
+    };
+
+    assert_eq!(
+        s.validate_with_context(&tenant()).unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "currency": {
+                    "errors": ["The currency `EUR` is not enabled."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn context_custom_closure_is_err() {
+    #[derive(Validate)]
+    #[validate(context = Tenant)]
+    struct Order {
+        #[validate(custom(|items, tenant| {
+            if items.len() <= tenant.max_items {
+                Ok(())
+            } else {
+                Err(serde_valid::validation::Error::Custom(
+                    "Too many items for the plan.".to_string(),
+                ))
+            }
+        }))]
+        items: Vec<i32>,
+    }
+
+    assert!(Order { items: vec![1, 2] }
+        .validate_with_context(&tenant())
+        .is_ok());
+    assert_eq!(
+        Order {
+            items: vec![1, 2, 3]
+        }
+        .validate_with_context(&tenant())
+        .unwrap_err()
+        .to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "items": {
+                    "errors": ["Too many items for the plan."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn context_struct_custom_is_err() {
+    fn total_limit(order: &Order, tenant: &Tenant) -> Result<(), serde_valid::validation::Error> {
+        if order.lines.len() <= tenant.max_items {
+            Ok(())
+        } else {
+            Err(serde_valid::validation::Error::Custom(
+                "Too many lines for the plan.".to_string(),
+            ))
+        }
+    }
+
+    #[derive(Validate)]
+    #[validate(context = Tenant)]
+    #[validate(custom(total_limit))]
+    #[validate(custom(|order, tenant| enabled_currency(&order.currency, tenant)))]
+    struct Order {
+        currency: String,
+        lines: Vec<i32>,
+    }
+
+    let s = Order {
+        currency: "EUR".to_string(),
+        lines: vec![1, 2, 3],
+    };
+
+    assert_eq!(
+        s.validate_with_context(&tenant()).unwrap_err().to_string(),
+        json!({
+            "errors": [
+                "Too many lines for the plan.",
+                "The currency `EUR` is not enabled."
+            ],
+            "properties": {}
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn context_nested_is_err() {
+    #[derive(Validate)]
+    #[validate(context = Tenant)]
+    struct Line {
+        #[validate(custom(enabled_currency))]
+        currency: String,
+    }
+
+    #[derive(Validate)]
+    struct Note {
+        #[validate(max_length = 4)]
+        text: String,
+    }
+
+    #[derive(Validate)]
+    #[validate(context = Tenant)]
+    struct Order {
+        #[validate]
+        lines: Vec<Line>,
+        #[validate]
+        note: Option<Note>,
+    }
+
+    let s = Order {
+        lines: vec![
+            Line {
+                currency: "JPY".to_string(),
+            },
+            Line {
+                currency: "EUR".to_string(),
+            },
+        ],
+        note: Some(Note {
+            text: "too long".to_string(),
+        }),
+    };
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(
+            &s.validate_with_context(&tenant()).unwrap_err().to_string()
+        )
+        .unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "lines": {
+                    "errors": [],
+                    "items": {
+                        "1": {
+                            "errors": [],
+                            "properties": {
+                                "currency": {
+                                    "errors": ["The currency `EUR` is not enabled."]
+                                }
+                            }
+                        }
+                    }
+                },
+                "note": {
+                    "errors": [],
+                    "properties": {
+                        "text": {
+                            "errors": ["The length of the value must be `<= 4`."]
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn context_nested_without_context_is_err() {
+    // `Validate` is implemented manually, so it is not `ValidateWithContext`.
+    struct Code(String);
+
+    impl Validate for Code {
+        fn validate(&self) -> Result<(), serde_valid::validation::Errors> {
+            if self.0.len() <= 4 {
+                Ok(())
+            } else {
+                Err(serde_valid::validation::Errors::NewType(vec![
+                    serde_valid::validation::Error::Custom("The code is too long.".to_string()),
+                ]))
+            }
+        }
+    }
+
+    #[derive(Validate)]
+    #[validate(context = Tenant)]
+    struct Order {
+        #[validate(custom(enabled_currency))]
+        currency: String,
+        #[validate(nested_without_context)]
+        code: Code,
+        #[validate(nested_without_context)]
+        codes: Vec<Code>,
+    }
+
+    let s = Order {
+        currency: "JPY".to_string(),
+        code: Code("too long".to_string()),
+        codes: vec![Code("ok".to_string())],
+    };
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(
+            &s.validate_with_context(&tenant()).unwrap_err().to_string()
+        )
+        .unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "code": {
+                    "errors": ["The code is too long."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn context_with_groups_is_err() {
+    #[derive(Validate)]
+    #[validate(context = Tenant)]
+    struct Payment {
+        #[validate(custom(enabled_currency), groups(create))]
+        currency: String,
+    }
+
+    let s = Payment {
+        currency: "EUR".to_string(),
+    };
+
+    assert!(s.validate_with_context(&tenant()).is_ok());
+    assert!(s
+        .validate_with_context_and_options(
+            &tenant(),
+            &ValidationOptions::new().with_group("create")
+        )
+        .is_err());
+}
+
+#[test]
+fn context_enum_is_err() {
+    #[derive(Validate)]
+    #[validate(context = Tenant)]
+    enum Payment {
+        Card {
+            #[validate(custom(enabled_currency))]
+            currency: String,
+        },
+        Cash(#[validate(custom(enabled_currency))] String),
+    }
+
+    assert!(Payment::Card {
+        currency: "USD".to_string()
+    }
+    .validate_with_context(&tenant())
+    .is_ok());
+    assert!(Payment::Card {
+        currency: "EUR".to_string()
+    }
+    .validate_with_context(&tenant())
+    .is_err());
+    assert!(Payment::Cash("EUR".to_string())
+        .validate_with_context(&tenant())
+        .is_err());
+}
+
+#[test]
+fn context_unsized_type_is_ok() {
+    fn contains(val: &i32, allowed: &[i32]) -> Result<(), serde_valid::validation::Error> {
+        if allowed.contains(val) {
+            Ok(())
+        } else {
+            Err(serde_valid::validation::Error::Custom(
+                "not allowed.".to_string(),
+            ))
+        }
+    }
+
+    #[derive(Validate)]
+    #[validate(context = "[i32]")]
+    struct TestStruct(#[validate(custom(contains))] i32);
+
+    assert!(TestStruct(1).validate_with_context(&[1, 2][..]).is_ok());
+    assert!(TestStruct(3).validate_with_context(&[1, 2][..]).is_err());
+}
+
+#[test]
+fn from_json_value_with_context_is_err() {
+    #[derive(Debug, Validate, Deserialize)]
+    #[validate(context = Tenant)]
+    struct Payment {
+        #[validate(custom(enabled_currency))]
+        currency: String,
+    }
+
+    assert!(Payment::from_json_value_with(json!({ "currency": "JPY" }), &tenant()).is_ok());
+
+    let err = Payment::from_json_value_with(json!({ "currency": "EUR" }), &tenant()).unwrap_err();
+
+    assert_eq!(
+        err.as_validation_errors().unwrap().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "currency": {
+                    "errors": ["The currency `EUR` is not enabled."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
@@ -0,0 +1,140 @@
+#[cfg(feature = "csv")]
+#[test]
+fn csv_rows_are_ok() {
+    use serde::Deserialize;
+    use serde_valid::csv::{FromCsvReader, Reader};
+    use serde_valid::Validate;
+
+    #[derive(Debug, Validate, Deserialize)]
+    struct TestStruct {
+        #[validate(maximum = 100)]
+        age: u8,
+        #[validate(min_length = 1)]
+        name: String,
+    }
+
+    let mut reader = Reader::from_reader("name,age\nalice,20\nbob,30\n".as_bytes());
+    let rows = TestStruct::from_csv_reader(&mut reader)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    assert_eq!(
+        rows.iter().map(|row| row.name.as_str()).collect::<Vec<_>>(),
+        vec!["alice", "bob"]
+    );
+}
+
+#[cfg(feature = "csv")]
+#[test]
+fn csv_validation_errors_by_row_and_column() {
+    use serde::Deserialize;
+    use serde_valid::csv::{FromCsvReader, Reader};
+    use serde_valid::Validate;
+
+    #[allow(dead_code)]
+    #[derive(Debug, Validate, Deserialize)]
+    struct TestStruct {
+        #[serde(rename = "e-mail")]
+        #[validate(pattern = r"@")]
+        email: String,
+        #[validate(maximum = 100)]
+        age: u8,
+    }
+
+    let input = "e-mail,age\nalice@example.com,20\nbob,200\n\"carol\nexample.com\",30\n";
+    let mut reader = Reader::from_reader(input.as_bytes());
+    let mut rows = TestStruct::from_csv_reader(&mut reader);
+
+    assert!(rows.next().unwrap().is_ok());
+
+    let err = rows.next().unwrap().unwrap_err();
+    assert_eq!(err.row(), 3);
+    assert_eq!(
+        err.columns()
+            .iter()
+            .map(|column| column.column())
+            .collect::<Vec<_>>(),
+        vec![Some("e-mail"), Some("age")]
+    );
+    assert_eq!(
+        err.to_string(),
+        "row 3, column `e-mail`: The value must match the pattern of \"@\".\n\
+         row 3, column `age`: The number must be `<= 100`."
+    );
+    assert!(err.as_error().is_validation_errors());
+
+    let err = rows.next().unwrap().unwrap_err();
+    assert_eq!(err.row(), 4);
+    assert_eq!(err.columns()[0].column(), Some("e-mail"));
+
+    assert!(rows.next().is_none());
+}
+
+#[cfg(feature = "csv")]
+#[test]
+fn csv_deserialize_errors_by_row_and_column() {
+    use serde::Deserialize;
+    use serde_valid::csv::{FromCsvReader, Reader};
+    use serde_valid::Validate;
+
+    #[allow(dead_code)]
+    #[derive(Debug, Validate, Deserialize)]
+    struct TestStruct {
+        name: String,
+        age: u8,
+    }
+
+    let input = "name,age\nalice,abc\nbob\ncarol,30\n";
+    let mut reader = Reader::from_reader(input.as_bytes());
+    let mut rows = TestStruct::from_csv_reader(&mut reader);
+
+    let err = rows.next().unwrap().unwrap_err();
+    assert_eq!(err.row(), 2);
+    assert_eq!(err.columns()[0].column(), Some("age"));
+    assert_eq!(
+        err.to_string(),
+        "row 2, column `age`: invalid digit found in string"
+    );
+    assert_eq!(
+        err.as_error().as_serde_error_path().unwrap().to_string(),
+        "/age"
+    );
+
+    let err = rows.next().unwrap().unwrap_err();
+    assert_eq!(err.row(), 3);
+    assert_eq!(err.columns()[0].column(), None);
+
+    assert_eq!(rows.next().unwrap().unwrap().name, "carol");
+    assert!(rows.next().is_none());
+}
+
+#[cfg(feature = "csv")]
+#[test]
+fn csv_without_headers() {
+    use serde::Deserialize;
+    use serde_valid::csv::{FromCsvReader, ReaderBuilder};
+    use serde_valid::Validate;
+
+    #[allow(dead_code)]
+    #[derive(Debug, Validate, Deserialize)]
+    struct TestStruct {
+        name: String,
+        #[validate(maximum = 100)]
+        age: u8,
+    }
+
+    let mut reader = ReaderBuilder::new()
+        .has_headers(false)
+        .from_reader("alice,200\nbob,x\n".as_bytes());
+    let mut rows = TestStruct::from_csv_reader(&mut reader);
+
+    let err = rows.next().unwrap().unwrap_err();
+    assert_eq!(err.row(), 1);
+    assert_eq!(err.columns()[0].column(), Some("age"));
+
+    let err = rows.next().unwrap().unwrap_err();
+    assert_eq!(err.row(), 2);
+    assert_eq!(err.columns()[0].column(), Some("1"));
+
+    assert!(rows.next().is_none());
+}
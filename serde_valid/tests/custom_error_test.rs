@@ -0,0 +1,186 @@
+use serde_json::json;
+use serde_valid::{CustomError, Validate};
+
+fn unique_name(name: &str) -> Result<(), CustomError> {
+    if name == "alice" {
+        Err(CustomError::new("unique_name", "The name is already taken.").with_param("name", name))
+    } else {
+        Ok(())
+    }
+}
+
+#[test]
+fn custom_error_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(custom(unique_name))]
+        name: String,
+    }
+
+    assert!(TestStruct {
+        name: "bob".to_string()
+    }
+    .validate()
+    .is_ok());
+}
+
+#[test]
+fn custom_error_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(custom(unique_name))]
+        name: String,
+    }
+
+    let errors = TestStruct {
+        name: "alice".to_string(),
+    }
+    .validate()
+    .unwrap_err();
+
+    assert_eq!(
+        errors.to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "name": {
+                    "errors": ["The name is already taken."]
+                }
+            }
+        })
+        .to_string()
+    );
+    assert_eq!(
+        errors.to_detailed_json(),
+        json!({
+            "errors": [],
+            "properties": {
+                "name": {
+                    "errors": [{
+                        "code": "unique_name",
+                        "params": { "name": "alice" },
+                        "message": "The name is already taken."
+                    }]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn custom_error_struct_custom_is_err() {
+    fn sample_rule(data: &TestStruct) -> Result<(), CustomError> {
+        if data.min > data.max {
+            Err(CustomError::new("min_max", "min must be <= max.")
+                .with_param("min", data.min)
+                .with_param("max", data.max))
+        } else {
+            Ok(())
+        }
+    }
+
+    #[derive(Validate)]
+    #[validate(custom(sample_rule))]
+    struct TestStruct {
+        min: i32,
+        max: i32,
+    }
+
+    assert!(TestStruct { min: 1, max: 2 }.validate().is_ok());
+    assert_eq!(
+        TestStruct { min: 2, max: 1 }
+            .validate()
+            .unwrap_err()
+            .to_detailed_json(),
+        json!({
+            "errors": [{
+                "code": "min_max",
+                "params": { "min": 2, "max": 1 },
+                "message": "min must be <= max."
+            }],
+            "properties": {}
+        })
+    );
+}
+
+#[test]
+fn custom_error_rule_is_err() {
+    fn sample_rule(min: &i32, max: &i32) -> Result<(), CustomError> {
+        if min > max {
+            Err(CustomError::new("min_max", "min must be <= max."))
+        } else {
+            Ok(())
+        }
+    }
+
+    #[derive(Validate)]
+    #[rule(sample_rule(min, max))]
+    struct TestStruct {
+        min: i32,
+        max: i32,
+    }
+
+    assert!(TestStruct { min: 2, max: 1 }.validate().is_err());
+}
+
+#[test]
+fn custom_error_into_error_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(custom(|name: &String| if name.is_empty() {
+            Err(CustomError::new("not_empty", "The name is empty.").into())
+        } else {
+            Ok(())
+        }))]
+        name: String,
+    }
+
+    assert_eq!(
+        TestStruct {
+            name: "".to_string()
+        }
+        .validate()
+        .unwrap_err()
+        .to_detailed_json(),
+        json!({
+            "errors": [],
+            "properties": {
+                "name": {
+                    "errors": [{
+                        "code": "not_empty",
+                        "params": {},
+                        "message": "The name is empty."
+                    }]
+                }
+            }
+        })
+    );
+}
+
+#[cfg(feature = "flatten")]
+#[test]
+fn custom_error_flatten_is_err() {
+    use serde_valid::flatten::IntoFlat;
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(custom(unique_name))]
+        name: String,
+    }
+
+    let errors = TestStruct {
+        name: "alice".to_string(),
+    }
+    .validate()
+    .unwrap_err();
+
+    assert_eq!(
+        serde_json::to_value(errors.into_flat()).unwrap(),
+        json!({
+            "errors": [{
+                "error": "The name is already taken.",
+                "instance_location": "/name"
+            }]
+        })
+    );
+}
@@ -106,3 +106,340 @@ fn toml_error_as_validation_errors() {
         })
     );
 }
+
+#[test]
+fn json_error_as_serde_error_path() {
+    use serde_valid::json::FromJsonStr;
+
+    #[allow(dead_code)]
+    #[derive(Debug, Validate, Deserialize)]
+    struct TestStruct {
+        items: Vec<TestItem>,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Debug, Validate, Deserialize)]
+    struct TestItem {
+        val: i32,
+    }
+
+    let err =
+        TestStruct::from_json_str(r#"{ "items": [{ "val": 1 }, { "val": "2" }] }"#).unwrap_err();
+
+    assert!(err.is_serde_error());
+    assert_eq!(
+        err.as_serde_error_path().unwrap().to_string(),
+        "/items/1/val"
+    );
+}
+
+#[test]
+fn json_error_trailing_characters_at_root() {
+    use serde_valid::json::FromJsonStr;
+
+    #[allow(dead_code)]
+    #[derive(Debug, Validate, Deserialize)]
+    struct TestStruct {
+        val: i32,
+    }
+
+    let err = TestStruct::from_json_str(r#"{ "val": 1 } 2"#).unwrap_err();
+
+    assert!(err.is_serde_error());
+    assert!(err.as_serde_error_path().unwrap().is_root());
+}
+
+#[cfg(feature = "yaml")]
+#[test]
+fn yaml_error_as_serde_error_path() {
+    use serde_valid::yaml::FromYamlStr;
+
+    #[allow(dead_code)]
+    #[derive(Debug, Validate, Deserialize)]
+    struct TestStruct {
+        items: Vec<i32>,
+    }
+
+    let err = TestStruct::from_yaml_str("items: [1, a]").unwrap_err();
+
+    assert_eq!(err.as_serde_error_path().unwrap().to_string(), "/items/1");
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn toml_error_as_serde_error_path() {
+    use serde_valid::toml::FromTomlStr;
+
+    #[allow(dead_code)]
+    #[derive(Debug, Validate, Deserialize)]
+    struct TestStruct {
+        inner: TestInner,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Debug, Validate, Deserialize)]
+    struct TestInner {
+        val: i32,
+    }
+
+    let err = TestStruct::from_toml_str("[inner]\nval = \"1\"").unwrap_err();
+
+    assert_eq!(err.as_serde_error_path().unwrap().to_string(), "/inner/val");
+}
+
+#[cfg(feature = "msgpack")]
+#[test]
+fn msgpack_error_as_validation_errors() {
+    use serde::Serialize;
+    use serde_valid::msgpack::{FromMsgpackSlice, ToMsgpackVec};
+
+    #[derive(Debug, Validate, Serialize, Deserialize)]
+    struct TestStruct {
+        #[validate(maximum = 10)]
+        val: i32,
+    }
+
+    let slice = TestStruct { val: 15 }.to_msgpack_vec().unwrap();
+    let err = TestStruct::from_msgpack_slice(&slice).unwrap_err();
+
+    assert_eq!(
+        serde_json::to_value(err.as_validation_errors().unwrap()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["The number must be `<= 10`."]
+                }
+            }
+        })
+    );
+}
+
+#[cfg(feature = "msgpack")]
+#[test]
+fn msgpack_error_as_serde_error_path() {
+    use serde::Serialize;
+    use serde_valid::msgpack::{FromMsgpackReader, ToMsgpackVec};
+
+    #[derive(Debug, Validate, Serialize)]
+    struct TestInput {
+        items: Vec<serde_json::Value>,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Debug, Validate, Deserialize)]
+    struct TestStruct {
+        items: Vec<i32>,
+    }
+
+    let bytes = TestInput {
+        items: vec![json!(1), json!("2")],
+    }
+    .to_msgpack_vec()
+    .unwrap();
+    let err = TestStruct::from_msgpack_reader(bytes.as_slice()).unwrap_err();
+
+    assert!(err.is_serde_error());
+    assert_eq!(err.as_serde_error_path().unwrap().to_string(), "/items/1");
+}
+
+#[cfg(feature = "cbor")]
+#[test]
+fn cbor_error_as_validation_errors() {
+    use serde::Serialize;
+    use serde_valid::cbor::{FromCborSlice, ToCborVec};
+
+    #[derive(Debug, Validate, Serialize, Deserialize)]
+    struct TestStruct {
+        #[validate(maximum = 10)]
+        val: i32,
+    }
+
+    let slice = TestStruct { val: 15 }.to_cbor_vec().unwrap();
+    let err = TestStruct::from_cbor_slice(&slice).unwrap_err();
+
+    assert_eq!(
+        serde_json::to_value(err.as_validation_errors().unwrap()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["The number must be `<= 10`."]
+                }
+            }
+        })
+    );
+}
+
+#[cfg(feature = "cbor")]
+#[test]
+fn cbor_error_as_serde_error_path() {
+    use serde::Serialize;
+    use serde_valid::cbor::{FromCborReader, ToCborVec};
+
+    #[derive(Debug, Validate, Serialize)]
+    struct TestInput {
+        items: Vec<serde_json::Value>,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Debug, Validate, Deserialize)]
+    struct TestStruct {
+        items: Vec<i32>,
+    }
+
+    let bytes = TestInput {
+        items: vec![json!(1), json!("2")],
+    }
+    .to_cbor_vec()
+    .unwrap();
+    let err = TestStruct::from_cbor_reader(bytes.as_slice()).unwrap_err();
+
+    assert!(err.is_serde_error());
+    assert_eq!(err.as_serde_error_path().unwrap().to_string(), "/items/1");
+
+    let err = TestStruct::from_cbor_reader(&bytes[..bytes.len() - 1]).unwrap_err();
+
+    assert!(err.is_serde_error());
+}
+
+#[cfg(feature = "json5")]
+#[test]
+fn json5_error_as_validation_errors() {
+    use serde_valid::json5::FromJson5Str;
+
+    #[derive(Debug, Validate, Deserialize)]
+    struct TestStruct {
+        #[validate(maximum = 10)]
+        val: i32,
+    }
+
+    let err = TestStruct::from_json5_str("{ val: 15, }").unwrap_err();
+
+    assert_eq!(
+        serde_json::to_value(err.as_validation_errors().unwrap()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["The number must be `<= 10`."]
+                }
+            }
+        })
+    );
+}
+
+#[cfg(feature = "json5")]
+#[test]
+fn json5_error_as_serde_error_location() {
+    use serde_valid::json5::{Error, FromJson5Str, Location};
+
+    #[allow(dead_code)]
+    #[derive(Debug, Validate, Deserialize)]
+    struct TestStruct {
+        items: Vec<i32>,
+    }
+
+    let err = TestStruct::from_json5_str("{\n  items: [1, 'a'],\n}").unwrap_err();
+
+    assert_eq!(err.as_serde_error_path().unwrap().to_string(), "/items/1");
+    assert!(matches!(
+        err.as_serde_error(),
+        Some(Error::Message {
+            location: Some(Location {
+                line: 2,
+                column: 14
+            }),
+            ..
+        })
+    ));
+}
+
+#[cfg(feature = "ron")]
+#[test]
+fn ron_error_as_validation_errors() {
+    use serde_valid::ron::FromRonStr;
+
+    #[derive(Debug, Validate, Deserialize)]
+    struct TestStruct {
+        #[validate(maximum = 10)]
+        val: i32,
+    }
+
+    let err = TestStruct::from_ron_str("(val: 15)").unwrap_err();
+
+    assert_eq!(
+        serde_json::to_value(err.as_validation_errors().unwrap()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["The number must be `<= 10`."]
+                }
+            }
+        })
+    );
+}
+
+#[cfg(feature = "ron")]
+#[test]
+fn ron_error_as_serde_error_location() {
+    use serde_valid::ron::{FromRonReader, FromRonValue, Position};
+
+    #[allow(dead_code)]
+    #[derive(Debug, Validate, Deserialize)]
+    struct TestStruct {
+        items: Vec<i32>,
+    }
+
+    let err = TestStruct::from_ron_reader("(\n  items: [1, \"a\"],\n)".as_bytes()).unwrap_err();
+
+    assert_eq!(err.as_serde_error_path().unwrap().to_string(), "/items/1");
+    assert_eq!(
+        err.as_serde_error().unwrap().position,
+        Position { line: 2, col: 14 }
+    );
+
+    let err =
+        TestStruct::from_ron_value(ron::from_str("(items: [1, \"a\"])").unwrap()).unwrap_err();
+
+    assert_eq!(err.as_serde_error_path().unwrap().to_string(), "/items/1");
+}
+
+#[cfg(feature = "flatten")]
+#[test]
+fn json_error_into_flat() {
+    use serde_valid::flatten::IntoFlat;
+
+    #[allow(dead_code)]
+    #[derive(Debug, Validate, Deserialize)]
+    struct TestStruct {
+        #[validate(maximum = 10)]
+        val: i32,
+        items: Vec<i32>,
+    }
+
+    let err = TestStruct::from_json_value(json!({ "val": 1, "items": [1, "2"] })).unwrap_err();
+
+    assert_eq!(
+        serde_json::to_value(err.into_flat()).unwrap(),
+        json!({
+            "errors": [{
+                "error": "invalid type: string \"2\", expected i32",
+                "instance_location": "/items/1"
+            }]
+        })
+    );
+
+    let err = TestStruct::from_json_value(json!({ "val": 15, "items": [] })).unwrap_err();
+
+    assert_eq!(
+        serde_json::to_value(err.into_flat()).unwrap(),
+        json!({
+            "errors": [{
+                "error": "The number must be `<= 10`.",
+                "instance_location": "/val"
+            }]
+        })
+    );
+}
@@ -0,0 +1,274 @@
+use serde_json::json;
+use serde_valid::validation::{ErrorParams, ToDetailed};
+use serde_valid::{CustomError, Validate};
+
+#[test]
+fn detailed_error_numeric_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(maximum = 100)]
+        #[validate(multiple_of = 7)]
+        val: i32,
+    }
+
+    let s = TestStruct { val: 101 };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_detailed_json(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [
+                        {
+                            "code": "maximum",
+                            "params": { "maximum": 100 },
+                            "message": "The number must be `<= 100`."
+                        },
+                        {
+                            "code": "multiple_of",
+                            "params": { "multiple_of": 7 },
+                            "message": "The value must be multiple of `7`."
+                        }
+                    ]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn detailed_error_string_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(pattern = r"^\d+$")]
+        #[validate(enumerate("1", "2"))]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: "a".to_string(),
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_detailed_json(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [
+                        {
+                            "code": "pattern",
+                            "params": { "pattern": r"^\d+$" },
+                            "message": r#"The value must match the pattern of "^\d+$"."#
+                        },
+                        {
+                            "code": "enumerate",
+                            "params": { "enumerate": ["1", "2"] },
+                            "message": r#"The value must be in [1, 2]."#
+                        }
+                    ]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn detailed_error_custom_message_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(min_length = 4, message = "too short.")]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: "a".to_string(),
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_detailed_json(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [{
+                        "code": "min_length",
+                        "params": { "min_length": 4 },
+                        "message": "too short."
+                    }]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn detailed_error_items_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(max_items = 2)]
+        #[validate(maximum = 10)]
+        val: Vec<i32>,
+    }
+
+    let s = TestStruct {
+        val: vec![1, 20, 3],
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_detailed_json(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [{
+                        "code": "max_items",
+                        "params": { "max_items": 2 },
+                        "message": "The length of the items must be `<= 2`."
+                    }],
+                    "items": {
+                        "1": {
+                            "errors": [{
+                                "code": "maximum",
+                                "params": { "maximum": 10 },
+                                "message": "The number must be `<= 10`."
+                            }]
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn detailed_error_custom_code_is_err() {
+    fn unique_name(name: &str) -> Result<(), serde_valid::validation::Error> {
+        if name == "alice" {
+            Err(serde_valid::validation::Error::CustomError(Box::new(
+                CustomError::new("unique_name", "The name is already taken.")
+                    .with_param("name", name),
+            )))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn not_empty(name: &str) -> Result<(), serde_valid::validation::Error> {
+        if name.is_empty() {
+            Err(serde_valid::validation::Error::Custom(
+                "The name is empty.".to_string(),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(custom(unique_name))]
+        #[validate(custom(not_empty))]
+        name: String,
+    }
+
+    let errors = TestStruct {
+        name: "alice".to_string(),
+    }
+    .validate()
+    .unwrap_err();
+
+    assert_eq!(
+        errors.to_detailed_json(),
+        json!({
+            "errors": [],
+            "properties": {
+                "name": {
+                    "errors": [{
+                        "code": "unique_name",
+                        "params": { "name": "alice" },
+                        "message": "The name is already taken."
+                    }]
+                }
+            }
+        })
+    );
+    assert_eq!(
+        errors.to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "name": {
+                    "errors": ["The name is already taken."]
+                }
+            }
+        })
+        .to_string()
+    );
+
+    assert_eq!(
+        TestStruct {
+            name: "".to_string(),
+        }
+        .validate()
+        .unwrap_err()
+        .to_detailed_json(),
+        json!({
+            "errors": [],
+            "properties": {
+                "name": {
+                    "errors": [{
+                        "code": "custom",
+                        "params": {},
+                        "message": "The name is empty."
+                    }]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn detailed_error_field_comparison_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        password: String,
+        #[validate(equals = field(password))]
+        password_confirmation: String,
+    }
+
+    let s = TestStruct {
+        password: "a".to_string(),
+        password_confirmation: "b".to_string(),
+    };
+
+    let errors = s.validate().unwrap_err().to_detailed();
+    let serde_valid::validation::Errors::Object(object) = errors else {
+        panic!("expected object errors");
+    };
+    let serde_valid::validation::Errors::NewType(errors) =
+        &object.properties["password_confirmation"]
+    else {
+        panic!("expected newtype errors");
+    };
+    let serde_valid::validation::DetailedError::Detail(detail) = &errors[0] else {
+        panic!("expected error detail");
+    };
+
+    assert_eq!(detail.code, "equals");
+    assert_eq!(
+        serde_json::Value::Object(detail.params.clone()),
+        json!({"other_field": "password", "value": "b", "other_value": "a"})
+    );
+}
+
+#[test]
+fn error_params_contains_is_ok() {
+    let error = serde_valid::ContainsError::new(1, Some(2), 3);
+
+    assert_eq!(error.code(), "contains");
+    assert_eq!(
+        json!(error.params()),
+        json!({ "min_contains": 1, "max_contains": 2, "matched": 3 })
+    );
+}
@@ -0,0 +1,284 @@
+use serde::Deserialize;
+use serde_json::json;
+use serde_valid::json::FromJsonValue;
+use serde_valid::Validate;
+
+#[test]
+fn externally_tagged_enum_is_err() {
+    #[derive(Debug, Validate, Deserialize)]
+    #[validate(externally_tagged)]
+    enum TestEnum {
+        Named {
+            #[validate(maximum = 5)]
+            val: i32,
+        },
+    }
+
+    let err = TestEnum::from_json_value(json!({ "Named": { "val": 6 } })).unwrap_err();
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&err.to_string()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "Named": {
+                    "errors": [],
+                    "properties": {
+                        "val": {
+                            "errors": ["The number must be `<= 5`."]
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn externally_tagged_enum_without_opt_in_is_err() {
+    #[derive(Debug, Validate, Deserialize)]
+    enum TestEnum {
+        Named {
+            #[validate(maximum = 5)]
+            val: i32,
+        },
+    }
+
+    let err = TestEnum::from_json_value(json!({ "Named": { "val": 6 } })).unwrap_err();
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&err.to_string()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["The number must be `<= 5`."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn externally_tagged_enum_variant_rename_is_err() {
+    #[derive(Debug, Validate, Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    #[validate(externally_tagged)]
+    enum TestEnum {
+        NewType(#[validate(maximum = 5)] i32),
+        #[serde(rename = "pair")]
+        Unnamed(#[validate(maximum = 5)] i32, #[validate(minimum = 0)] i32),
+    }
+
+    let err = TestEnum::from_json_value(json!({ "new_type": 6 })).unwrap_err();
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&err.to_string()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "new_type": {
+                    "errors": ["The number must be `<= 5`."]
+                }
+            }
+        })
+    );
+
+    let err = TestEnum::from_json_value(json!({ "pair": [6, 0] })).unwrap_err();
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&err.to_string()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "pair": {
+                    "errors": [],
+                    "items": {
+                        "0": {
+                            "errors": ["The number must be `<= 5`."]
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn internally_tagged_enum_is_err() {
+    #[derive(Debug, Validate, Deserialize)]
+    #[serde(tag = "type")]
+    enum TestEnum {
+        Named {
+            #[validate(maximum = 5)]
+            val: i32,
+        },
+    }
+
+    let err = TestEnum::from_json_value(json!({ "type": "Named", "val": 6 })).unwrap_err();
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&err.to_string()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["The number must be `<= 5`."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn adjacently_tagged_enum_is_err() {
+    #[derive(Debug, Validate, Deserialize)]
+    #[serde(tag = "type", content = "data")]
+    enum TestEnum {
+        Named {
+            #[validate(maximum = 5)]
+            val: i32,
+        },
+        NewType(#[validate(maximum = 5)] i32),
+    }
+
+    let err =
+        TestEnum::from_json_value(json!({ "type": "Named", "data": { "val": 6 } })).unwrap_err();
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&err.to_string()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "data": {
+                    "errors": [],
+                    "properties": {
+                        "val": {
+                            "errors": ["The number must be `<= 5`."]
+                        }
+                    }
+                }
+            }
+        })
+    );
+
+    let err = TestEnum::from_json_value(json!({ "type": "NewType", "data": 6 })).unwrap_err();
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&err.to_string()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "data": {
+                    "errors": ["The number must be `<= 5`."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn adjacently_tagged_enum_unnamed_variant_is_err() {
+    #[derive(Debug, Validate, Deserialize)]
+    #[serde(tag = "t", content = "c", rename_all = "snake_case")]
+    enum TestEnum {
+        #[serde(rename = "pair")]
+        Unnamed(#[validate(maximum = 5)] i32, #[validate(minimum = 0)] i32),
+    }
+
+    let err = TestEnum::from_json_value(json!({ "t": "pair", "c": [6, -1] })).unwrap_err();
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&err.to_string()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "c": {
+                    "errors": [],
+                    "items": {
+                        "0": {
+                            "errors": ["The number must be `<= 5`."]
+                        },
+                        "1": {
+                            "errors": ["The number must be `>= 0`."]
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn untagged_enum_is_err() {
+    #[derive(Debug, Validate, Deserialize)]
+    #[serde(untagged)]
+    enum TestEnum {
+        Named {
+            #[validate(maximum = 5)]
+            val: i32,
+        },
+    }
+
+    let err = TestEnum::from_json_value(json!({ "val": 6 })).unwrap_err();
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&err.to_string()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["The number must be `<= 5`."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn untagged_variant_is_err() {
+    #[derive(Debug, Validate, Deserialize)]
+    #[validate(externally_tagged)]
+    enum TestEnum {
+        #[allow(dead_code)]
+        Tagged(i32),
+        #[serde(untagged)]
+        Other(#[validate(maximum = 5)] i32),
+    }
+
+    let err = TestEnum::from_json_value(json!(6)).unwrap_err();
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&err.to_string()).unwrap(),
+        json!({
+            "errors": ["The number must be `<= 5`."]
+        })
+    );
+}
+
+#[cfg(feature = "flatten")]
+#[test]
+fn adjacently_tagged_enum_flatten_pointer() {
+    use serde_valid::flatten::IntoFlat;
+
+    #[derive(Debug, Validate, Deserialize)]
+    #[serde(tag = "type", content = "data")]
+    enum TestEnum {
+        Named {
+            #[validate(maximum = 5)]
+            val: i32,
+        },
+    }
+
+    let err = TestEnum::Named { val: 6 }.validate().unwrap_err();
+
+    assert_eq!(
+        serde_json::to_value(err.into_flat()).unwrap(),
+        json!({
+            "errors": [{
+                "error": "The number must be `<= 5`.",
+                "instance_location": "/data/val"
+            }]
+        })
+    );
+}
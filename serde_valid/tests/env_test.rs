@@ -0,0 +1,226 @@
+#[cfg(feature = "env")]
+#[test]
+fn env_nested_keys_with_prefix() {
+    use serde::Deserialize;
+    use serde_valid::env::{EnvOptions, FromEnv};
+    use serde_valid::Validate;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    #[serde(rename_all = "lowercase")]
+    enum Mode {
+        Debug,
+        Release,
+    }
+
+    #[derive(Debug, Validate, Deserialize)]
+    struct Settings {
+        mode: Mode,
+        #[validate]
+        db: Database,
+        hosts: Vec<String>,
+        ports: Vec<u16>,
+        timeout: Option<u64>,
+    }
+
+    #[derive(Debug, Validate, Deserialize)]
+    struct Database {
+        host: String,
+        port: u16,
+        pool_size: usize,
+    }
+
+    let settings = Settings::from_env_vars(
+        [
+            ("APP_MODE", "release"),
+            ("APP_DB__HOST", "localhost"),
+            ("APP_DB__PORT", "5432"),
+            ("APP_DB__POOL_SIZE", "4"),
+            ("APP_HOSTS", "a.example.com, b.example.com"),
+            ("APP_PORTS__1", "8081"),
+            ("APP_PORTS__0", "8080"),
+            ("OTHER_DB__PORT", "x"),
+        ],
+        &EnvOptions::new().with_prefix("APP_"),
+    )
+    .unwrap();
+
+    assert_eq!(settings.mode, Mode::Release);
+    assert_eq!(settings.db.host, "localhost");
+    assert_eq!(settings.db.port, 5432);
+    assert_eq!(settings.db.pool_size, 4);
+    assert_eq!(settings.hosts, vec!["a.example.com", "b.example.com"]);
+    assert_eq!(settings.ports, vec![8080, 8081]);
+    assert_eq!(settings.timeout, None);
+}
+
+#[cfg(feature = "env")]
+#[test]
+fn env_validation_errors_as_variable_names() {
+    use serde::Deserialize;
+    use serde_valid::env::{EnvOptions, FromEnv};
+    use serde_valid::Validate;
+
+    #[allow(dead_code)]
+    #[derive(Debug, Validate, Deserialize)]
+    #[validate(custom(|_| Err(serde_valid::validation::Error::Custom("invalid settings.".to_string()))))]
+    struct Settings {
+        #[validate]
+        db: Database,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Debug, Validate, Deserialize)]
+    struct Database {
+        #[validate(min_length = 1)]
+        host: String,
+        #[validate(maximum = 1024)]
+        port: u32,
+    }
+
+    let err = Settings::from_env_vars(
+        [("APP.DB.HOST", ""), ("APP.DB.PORT", "65535")],
+        &EnvOptions::new().with_prefix("APP.").with_separator("."),
+    )
+    .unwrap_err();
+
+    assert_eq!(
+        err.variables()
+            .iter()
+            .map(|variable| variable.variable())
+            .collect::<Vec<_>>(),
+        vec![None, Some("APP.DB.HOST"), Some("APP.DB.PORT")]
+    );
+    assert_eq!(
+        err.to_string(),
+        "invalid settings.\n\
+         APP.DB.HOST: The length of the value must be `>= 1`.\n\
+         APP.DB.PORT: The number must be `<= 1024`."
+    );
+    assert!(err.as_error().is_validation_errors());
+}
+
+#[cfg(feature = "env")]
+#[test]
+fn env_deserialize_error_as_variable_name() {
+    use serde::Deserialize;
+    use serde_valid::env::{EnvOptions, FromEnv};
+    use serde_valid::Validate;
+
+    #[allow(dead_code)]
+    #[derive(Debug, Validate, Deserialize)]
+    struct Settings {
+        db: Database,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Debug, Validate, Deserialize)]
+    struct Database {
+        port: u16,
+    }
+
+    let options = EnvOptions::new().with_prefix("APP_");
+
+    let err = Settings::from_env_vars([("APP_DB__PORT", "http")], &options).unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "APP_DB__PORT: invalid value: string \"http\", expected u16"
+    );
+    assert_eq!(
+        err.as_error().as_serde_error_path().unwrap().to_string(),
+        "/db/port"
+    );
+
+    let err = Settings::from_env_vars([("APP_DB", "localhost")], &options).unwrap_err();
+
+    assert_eq!(err.variables()[0].variable(), Some("APP_DB"));
+}
+
+#[cfg(feature = "env")]
+#[test]
+fn env_serde_rename_all_is_matched_case_insensitively() {
+    use serde::Deserialize;
+    use serde_valid::env::{EnvOptions, FromEnv};
+    use serde_valid::Validate;
+
+    #[derive(Debug, Validate, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Settings {
+        #[validate(maximum = 100)]
+        max_connections: u32,
+        #[validate]
+        read_replica: Database,
+    }
+
+    #[derive(Debug, Validate, Deserialize)]
+    struct Database {
+        #[serde(rename = "HostName")]
+        host_name: String,
+        #[validate(minimum = 1024)]
+        port: u16,
+    }
+
+    let options = EnvOptions::new().with_prefix("APP_");
+
+    let settings = Settings::from_env_vars(
+        [
+            ("APP_MAXCONNECTIONS", "10"),
+            ("APP_readReplica__HostName", "localhost"),
+            ("APP_READREPLICA__PORT", "5432"),
+        ],
+        &options,
+    )
+    .unwrap();
+
+    assert_eq!(settings.max_connections, 10);
+    assert_eq!(settings.read_replica.host_name, "localhost");
+    assert_eq!(settings.read_replica.port, 5432);
+
+    let err = Settings::from_env_vars(
+        [
+            ("APP_maxConnections", "1000"),
+            ("APP_readReplica__HOSTNAME", "localhost"),
+            ("APP_readReplica__port", "80"),
+        ],
+        &options,
+    )
+    .unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "APP_maxConnections: The number must be `<= 100`.\n\
+         APP_readReplica__port: The number must be `>= 1024`."
+    );
+
+    let err = Settings::from_env_vars(
+        [
+            ("APP_MAXCONNECTIONS", "10"),
+            ("APP_READREPLICA__PORT", "5432"),
+        ],
+        &options,
+    )
+    .unwrap_err();
+
+    assert_eq!(err.variables()[0].variable(), Some("APP_READREPLICA"));
+}
+
+#[cfg(feature = "env")]
+#[test]
+fn env_from_process_environment() {
+    use serde::Deserialize;
+    use serde_valid::env::{EnvOptions, FromEnv};
+    use serde_valid::Validate;
+
+    #[derive(Debug, Validate, Deserialize)]
+    struct Settings {
+        #[validate(maximum = 10)]
+        workers: u8,
+    }
+
+    std::env::set_var("SERDE_VALID_ENV_TEST_WORKERS", "4");
+
+    let settings =
+        Settings::from_env(&EnvOptions::new().with_prefix("SERDE_VALID_ENV_TEST_")).unwrap();
+
+    assert_eq!(settings.workers, 4);
+}
@@ -0,0 +1,248 @@
+use serde_json::json;
+use serde_valid::{AsyncValidate, Validate, ValidationOptions};
+
+#[derive(Validate)]
+struct Record {
+    #[validate(max_length = 3)]
+    name: String,
+    #[validate(maximum = 10)]
+    value: i32,
+}
+
+#[derive(Validate)]
+struct Import {
+    #[validate(min_items = 1)]
+    #[validate]
+    records: Vec<Record>,
+}
+
+fn invalid_record() -> Record {
+    Record {
+        name: "taro".to_string(),
+        value: 100,
+    }
+}
+
+#[test]
+fn error_budget_is_ok() {
+    let s = Record {
+        name: "abc".to_string(),
+        value: 1,
+    };
+
+    assert!(s
+        .validate_with(&ValidationOptions::new().with_fail_fast())
+        .is_ok());
+}
+
+#[test]
+fn error_budget_without_limit_collects_all_errors() {
+    let s = Import {
+        records: vec![invalid_record(), invalid_record()],
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "records": {
+                    "errors": [],
+                    "items": {
+                        "0": {
+                            "errors": [],
+                            "properties": {
+                                "name": {"errors": ["The length of the value must be `<= 3`."]},
+                                "value": {"errors": ["The number must be `<= 10`."]}
+                            }
+                        },
+                        "1": {
+                            "errors": [],
+                            "properties": {
+                                "name": {"errors": ["The length of the value must be `<= 3`."]},
+                                "value": {"errors": ["The number must be `<= 10`."]}
+                            }
+                        }
+                    }
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn error_budget_fail_fast_err_message() {
+    let s = invalid_record();
+
+    assert_eq!(
+        s.validate_with(&ValidationOptions::new().with_fail_fast())
+            .unwrap_err()
+            .to_string(),
+        json!({
+            "errors": ["The validation was stopped after `1` errors."],
+            "properties": {
+                "name": {"errors": ["The length of the value must be `<= 3`."]}
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn error_budget_max_errors_in_nested_vec() {
+    let s = Import {
+        records: vec![invalid_record(), invalid_record(), invalid_record()],
+    };
+
+    assert_eq!(
+        s.validate_with(&ValidationOptions::new().with_max_errors(3))
+            .unwrap_err()
+            .to_string(),
+        json!({
+            "errors": ["The validation was stopped after `3` errors."],
+            "properties": {
+                "records": {
+                    "errors": [],
+                    "items": {
+                        "0": {
+                            "errors": [],
+                            "properties": {
+                                "name": {"errors": ["The length of the value must be `<= 3`."]},
+                                "value": {"errors": ["The number must be `<= 10`."]}
+                            }
+                        },
+                        "1": {
+                            "errors": [],
+                            "properties": {
+                                "name": {"errors": ["The length of the value must be `<= 3`."]}
+                            }
+                        }
+                    }
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn error_budget_max_error_items_in_vec() {
+    let s = vec![invalid_record(), invalid_record(), invalid_record()];
+
+    assert_eq!(
+        s.validate_with(&ValidationOptions::new().with_max_error_items(1))
+            .unwrap_err()
+            .to_string(),
+        json!({
+            "errors": ["The validation was stopped after `1` items with errors."],
+            "items": {
+                "0": {
+                    "errors": [],
+                    "properties": {
+                        "name": {"errors": ["The length of the value must be `<= 3`."]},
+                        "value": {"errors": ["The number must be `<= 10`."]}
+                    }
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn error_budget_is_not_truncated_on_last_error() {
+    let s = Record {
+        name: "abc".to_string(),
+        value: 100,
+    };
+
+    assert_eq!(
+        s.validate_with(&ValidationOptions::new().with_fail_fast())
+            .unwrap_err()
+            .to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "value": {"errors": ["The number must be `<= 10`."]}
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn error_budget_is_reset_for_each_validation() {
+    let options = ValidationOptions::new().with_fail_fast();
+    let s = invalid_record();
+
+    let err = s.validate_with(&options).unwrap_err().to_string();
+
+    let valid = Record {
+        name: "abc".to_string(),
+        value: 1,
+    };
+    assert!(valid.validate_with(&options).is_ok());
+
+    assert_eq!(s.validate_with(&options).unwrap_err().to_string(), err);
+}
+
+#[test]
+fn error_budget_options_are_shared_across_threads() {
+    let options = ValidationOptions::new().with_max_errors(1000);
+    let imports = (0..8)
+        .map(|_| Import {
+            records: (0..10).map(|_| invalid_record()).collect(),
+        })
+        .collect::<Vec<_>>();
+    let expected = imports.validate_with(&options).unwrap_err().to_string();
+
+    std::thread::scope(|scope| {
+        let handles = (0..8)
+            .map(|_| {
+                scope.spawn(|| {
+                    (0..100)
+                        .map(|_| imports.validate_with(&options))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for handle in handles {
+            for result in handle.join().unwrap() {
+                assert_eq!(result.unwrap_err().to_string(), expected);
+            }
+        }
+    });
+}
+
+#[tokio::test]
+async fn error_budget_async_fail_fast_err_message() {
+    #[derive(Validate)]
+    struct AsyncRecord {
+        #[validate(max_length = 3)]
+        name: String,
+        #[validate(maximum = 10)]
+        #[validate(custom_async(|_| async { Ok(()) }))]
+        value: i32,
+    }
+
+    let s = AsyncRecord {
+        name: "taro".to_string(),
+        value: 100,
+    };
+
+    assert_eq!(
+        s.validate_async_with(&ValidationOptions::new().with_fail_fast())
+            .await
+            .unwrap_err()
+            .to_string(),
+        json!({
+            "errors": ["The validation was stopped after `1` errors."],
+            "properties": {
+                "name": {"errors": ["The length of the value must be `<= 3`."]}
+            }
+        })
+        .to_string()
+    );
+}
@@ -0,0 +1,335 @@
+use serde_json::json;
+use serde_valid::Validate;
+
+#[test]
+fn equals_field_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        password: String,
+        #[validate(equals = field(password))]
+        password_confirmation: String,
+    }
+
+    assert!(TestStruct {
+        password: "secret".to_string(),
+        password_confirmation: "secret".to_string(),
+    }
+    .validate()
+    .is_ok());
+}
+
+#[test]
+fn equals_field_err_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        password: String,
+        #[validate(equals = field(password))]
+        password_confirmation: String,
+    }
+
+    let s = TestStruct {
+        password: "secret".to_string(),
+        password_confirmation: "Secret".to_string(),
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "password_confirmation": {
+                    "errors": ["The value must be `== password`."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn not_equals_field_err_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(not_equals = field(new_password))]
+        old_password: String,
+        new_password: String,
+    }
+
+    let s = TestStruct {
+        old_password: "secret".to_string(),
+        new_password: "secret".to_string(),
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "old_password": {
+                    "errors": ["The value must be `!= new_password`."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn less_than_or_equal_field_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(less_than_or_equal = field(end_date))]
+        start_date: String,
+        end_date: String,
+    }
+
+    assert!(TestStruct {
+        start_date: "2024-01-01".to_string(),
+        end_date: "2024-01-01".to_string(),
+    }
+    .validate()
+    .is_ok());
+}
+
+#[test]
+fn less_than_field_err_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(less_than = field(max_price))]
+        min_price: f64,
+        #[validate(minimum = 0.0)]
+        max_price: f64,
+    }
+
+    let s = TestStruct {
+        min_price: 10.0,
+        max_price: 10.0,
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "min_price": {
+                    "errors": ["The value must be `< max_price`."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn equals_field_with_mixed_types_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(equals = field(name))]
+        greeting: String,
+        name: &'static str,
+    }
+
+    assert!(TestStruct {
+        greeting: "alice".to_string(),
+        name: "alice",
+    }
+    .validate()
+    .is_ok());
+}
+
+#[test]
+fn greater_than_or_equal_field_err_params() {
+    #[derive(Validate)]
+    struct TestStruct {
+        min: i32,
+        #[validate(greater_than_or_equal = field(min))]
+        max: i32,
+    }
+
+    let s = TestStruct { min: 5, max: 3 };
+
+    let errors = s.validate().unwrap_err();
+    let serde_valid::validation::Errors::Object(object) = errors else {
+        panic!("expected object errors");
+    };
+    let serde_valid::validation::Errors::NewType(max_errors) = &object.properties["max"] else {
+        panic!("expected new type errors");
+    };
+    let serde_valid::validation::Error::FieldComparison(message) = &max_errors[0] else {
+        panic!("expected field comparison error");
+    };
+
+    assert_eq!(
+        message.error().operator,
+        serde_valid::validation::ComparisonOperator::GreaterThanOrEqual
+    );
+    assert_eq!(message.error().other_field, "min");
+    assert_eq!(message.error().value, Some(json!(3)));
+    assert_eq!(message.error().other_value, Some(json!(5)));
+}
+
+#[test]
+fn field_comparison_custom_err_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(
+            less_than_or_equal = field(end_date),
+            message = "start_date must not be after end_date."
+        )]
+        start_date: u32,
+        end_date: u32,
+    }
+
+    let s = TestStruct {
+        start_date: 20240102,
+        end_date: 20240101,
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "start_date": {
+                    "errors": ["start_date must not be after end_date."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn field_comparison_with_rename_err_message() {
+    #[derive(Validate, serde::Deserialize)]
+    struct TestStruct {
+        #[serde(rename = "startDate")]
+        #[validate(less_than_or_equal = field(end_date))]
+        start_date: u32,
+        #[serde(rename = "endDate")]
+        end_date: u32,
+    }
+
+    let s = TestStruct {
+        start_date: 2,
+        end_date: 1,
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "startDate": {
+                    "errors": ["The value must be `<= endDate`."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn field_comparison_enum_variant_err_message() {
+    #[derive(Validate)]
+    enum TestEnum {
+        Range {
+            #[validate(less_than = field(end))]
+            start: i32,
+            end: i32,
+        },
+    }
+
+    let s = TestEnum::Range { start: 2, end: 1 };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "start": {
+                    "errors": ["The value must be `< end`."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn field_comparison_redact_without_serialize_is_err() {
+    #[derive(PartialEq, PartialOrd)]
+    struct Secret(String);
+
+    #[derive(Validate)]
+    struct TestStruct {
+        secret: Secret,
+        #[validate(equals = field(secret), redact)]
+        secret_confirmation: Secret,
+        #[validate(less_than = field(secret), redact)]
+        previous_secret: Secret,
+    }
+
+    let s = TestStruct {
+        secret: Secret("b".to_string()),
+        secret_confirmation: Secret("a".to_string()),
+        previous_secret: Secret("a".to_string()),
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "secret_confirmation": {
+                    "errors": ["The value must be `== secret`."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn field_comparison_redact_err_params() {
+    #[derive(Validate)]
+    struct TestStruct {
+        password: String,
+        #[validate(equals = field(password), redact, message = "The passwords do not match.")]
+        password_confirmation: String,
+    }
+
+    let s = TestStruct {
+        password: "secret".to_string(),
+        password_confirmation: "Secret".to_string(),
+    };
+
+    let errors = s.validate().unwrap_err();
+    assert_eq!(
+        errors.to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "password_confirmation": {
+                    "errors": ["The passwords do not match."]
+                }
+            }
+        })
+        .to_string()
+    );
+
+    let serde_valid::validation::Errors::Object(object) = errors else {
+        panic!("expected object errors");
+    };
+    let serde_valid::validation::Errors::NewType(errors) =
+        &object.properties["password_confirmation"]
+    else {
+        panic!("expected new type errors");
+    };
+    let serde_valid::validation::Error::FieldComparison(message) = &errors[0] else {
+        panic!("expected field comparison error");
+    };
+
+    assert_eq!(message.error().value, None);
+    assert_eq!(message.error().other_value, None);
+}
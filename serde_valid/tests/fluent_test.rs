@@ -51,4 +51,98 @@ mod tests {
             .to_string()
         );
     }
+
+    #[test]
+    fn fluent_const_error() {
+        #[derive(Debug, Deserialize, Validate)]
+        struct Test {
+            #[validate(const = "v1", fluent("const-error", expected = "v1"))]
+            a: String,
+            #[validate(const = "v1")]
+            b: String,
+        }
+
+        let test = Test {
+            a: "v2".to_string(),
+            b: "v2".to_string(),
+        };
+        let a = test.validate().unwrap_err().localize(&get_bundle(
+            "const-error = The value must be { $expected }.",
+        ));
+
+        assert_eq!(
+            a.to_string(),
+            json!({
+                "errors": [],
+                "properties": {
+                    "a": {
+                        "errors": [
+                            "The value must be \u{2068}v1\u{2069}."
+                        ]
+                    },
+                    "b": {
+                        "errors": [
+                            "The value must be `v1`."
+                        ]
+                    }
+                }
+            })
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn fluent_custom_error() {
+        fn unique_name(name: &str) -> Result<(), serde_valid::CustomError> {
+            Err(
+                serde_valid::CustomError::new("unique-name", "The name is already taken.")
+                    .with_param("name", name),
+            )
+        }
+
+        #[derive(Debug, Deserialize, Validate)]
+        struct Test {
+            #[validate(custom(unique_name))]
+            a: String,
+        }
+
+        let test = Test {
+            a: "taro".to_string(),
+        };
+
+        assert_eq!(
+            test.validate()
+                .unwrap_err()
+                .localize(&get_bundle("unique-name = { $name } is already taken."))
+                .to_string(),
+            json!({
+                "errors": [],
+                "properties": {
+                    "a": {
+                        "errors": [
+                            "\u{2068}taro\u{2069} is already taken."
+                        ]
+                    }
+                }
+            })
+            .to_string()
+        );
+        assert_eq!(
+            test.validate()
+                .unwrap_err()
+                .localize(&get_bundle("hello-world = Hello, world!"))
+                .to_string(),
+            json!({
+                "errors": [],
+                "properties": {
+                    "a": {
+                        "errors": [
+                            "The name is already taken."
+                        ]
+                    }
+                }
+            })
+            .to_string()
+        );
+    }
 }
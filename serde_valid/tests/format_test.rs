@@ -0,0 +1,361 @@
+use serde_json::json;
+use serde_valid::validation::StringFormat;
+use serde_valid::{Validate, ValidateFormat};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::ffi::{OsStr, OsString};
+
+#[test]
+fn format_string_type() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(format = "email")]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: String::from("user@example.com"),
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn format_str_type() {
+    #[derive(Validate)]
+    struct TestStruct<'a> {
+        #[validate(format = "uuid")]
+        val: &'a str,
+    }
+
+    let s = TestStruct {
+        val: "67e55044-10b1-426f-9247-bb680e5fe0c8",
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn format_cow_str_type() {
+    #[derive(Validate)]
+    struct TestStruct<'a> {
+        #[validate(format = "date")]
+        val: Cow<'a, str>,
+    }
+
+    let s = TestStruct {
+        val: Cow::from("2020-09-10"),
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn format_os_str_type() {
+    #[derive(Validate)]
+    struct TestStruct<'a> {
+        #[validate(format = "ipv4")]
+        val: &'a OsStr,
+    }
+
+    let s = TestStruct {
+        val: OsStr::new("192.168.0.1"),
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn format_os_string_type() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(format = "ipv6")]
+        val: OsString,
+    }
+
+    let s = TestStruct {
+        val: OsString::from("::1"),
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn format_all_formats_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(format = "email")]
+        email: String,
+        #[validate(format = "uri")]
+        uri: String,
+        #[validate(format = "uuid")]
+        uuid: String,
+        #[validate(format = "ipv4")]
+        ipv4: String,
+        #[validate(format = "ipv6")]
+        ipv6: String,
+        #[validate(format = "hostname")]
+        hostname: String,
+        #[validate(format = "date")]
+        date: String,
+        #[validate(format = "time")]
+        time: String,
+        #[validate(format = "date-time")]
+        date_time: String,
+        #[validate(format = "duration")]
+        duration: String,
+    }
+
+    let s = TestStruct {
+        email: String::from("user@example.com"),
+        uri: String::from("https://example.com/path"),
+        uuid: String::from("67e55044-10b1-426f-9247-bb680e5fe0c8"),
+        ipv4: String::from("127.0.0.1"),
+        ipv6: String::from("2001:db8::1"),
+        hostname: String::from("www.example.com"),
+        date: String::from("2020-09-10"),
+        time: String::from("12:34:56+09:00"),
+        date_time: String::from("2020-09-10T12:34:56Z"),
+        duration: String::from("P1DT12H"),
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn format_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(format = "date-time")]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: String::from("2020-09-10 12:34:56"),
+    };
+    assert!(s.validate().is_err());
+}
+
+#[test]
+fn format_vec_type_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(format = "hostname")]
+        val: Vec<String>,
+    }
+
+    let s = TestStruct {
+        val: vec![String::from("example.com"), String::from("localhost")],
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn format_nested_vec_type_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(format = "date")]
+        val: Vec<Vec<String>>,
+    }
+
+    let s = TestStruct {
+        val: vec![
+            vec![String::from("2020-09-10"), String::from("2020-10-10")],
+            vec![String::from("2020-11-10"), String::from("2020-12-10")],
+        ],
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn format_option_type_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(format = "email")]
+        val: Option<String>,
+    }
+
+    let s = TestStruct { val: None };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn format_vec_optional_type_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(format = "uri")]
+        val: Vec<Option<String>>,
+    }
+
+    let s = TestStruct {
+        val: vec![Some(String::from("mailto:user@example.com")), None],
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn format_hash_map_type_err_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(format = "email")]
+        val: HashMap<String, String>,
+    }
+
+    let s = TestStruct {
+        val: HashMap::from([(String::from("key"), String::from("not-an-email"))]),
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [],
+                    "items": {
+                        "0": {
+                            "errors": ["The value must match the format of \"email\"."]
+                        }
+                    }
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn format_vec_type_err_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(format = "ipv4")]
+        val: Vec<String>,
+    }
+
+    let s = TestStruct {
+        val: vec![String::from("127.0.0.1"), String::from("256.0.0.1")],
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [],
+                    "items": {
+                        "1": {
+                            "errors": ["The value must match the format of \"ipv4\"."]
+                        }
+                    }
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn format_err_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(format = "date-time")]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: String::from("2020/09/10"),
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [
+                        "The value must match the format of \"date-time\"."
+                    ]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn format_custom_err_message_fn() {
+    fn error_message(params: &serde_valid::FormatError) -> String {
+        format!("this is not {}.", params.format)
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(format = "uuid", message_fn(error_message))]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: String::from("not-a-uuid"),
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [
+                        "this is not uuid."
+                    ]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn format_custom_err_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(format = "email", message = "this is custom message.")]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: String::from("not-an-email"),
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [
+                        "this is custom message."
+                    ]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn format_trait() {
+    struct MyType(String);
+
+    impl ValidateFormat for MyType {
+        fn validate_format(&self, format: StringFormat) -> Result<(), serde_valid::FormatError> {
+            self.0.validate_format(format)
+        }
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(format = "email")]
+        val: MyType,
+    }
+
+    let s = TestStruct {
+        val: MyType(String::from("user@example.com")),
+    };
+
+    assert!(s.validate().is_ok());
+}
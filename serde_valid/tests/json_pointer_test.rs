@@ -0,0 +1,145 @@
+use std::collections::HashMap;
+
+use serde_valid::validation::{Error, JsonPointer, Visitor};
+use serde_valid::Validate;
+
+#[derive(Validate)]
+struct TestStruct {
+    #[validate(max_length = 3)]
+    name: String,
+    #[validate(min_items = 3)]
+    #[validate]
+    items: Vec<TestItem>,
+    #[validate]
+    tags: HashMap<String, TestItem>,
+}
+
+#[derive(Validate)]
+struct TestItem {
+    #[validate(max_length = 3)]
+    #[validate(pattern = r"^[a-z]+$")]
+    name: String,
+}
+
+fn invalid_struct() -> TestStruct {
+    TestStruct {
+        name: "taro".to_string(),
+        items: vec![
+            TestItem {
+                name: "abc".to_string(),
+            },
+            TestItem {
+                name: "ABCD".to_string(),
+            },
+        ],
+        tags: HashMap::from([(
+            "a/b".to_string(),
+            TestItem {
+                name: "abcd".to_string(),
+            },
+        )]),
+    }
+}
+
+#[test]
+fn json_pointer_errors_iter() {
+    let errors = invalid_struct().validate().unwrap_err();
+
+    assert_eq!(
+        errors
+            .iter()
+            .map(|(pointer, error)| (pointer.to_string(), error.to_string()))
+            .collect::<Vec<_>>(),
+        vec![
+            (
+                "/name".to_string(),
+                "The length of the value must be `<= 3`.".to_string()
+            ),
+            (
+                "/items".to_string(),
+                "The length of the items must be `>= 3`.".to_string()
+            ),
+            (
+                "/items/1/name".to_string(),
+                "The length of the value must be `<= 3`.".to_string()
+            ),
+            (
+                "/items/1/name".to_string(),
+                "The value must match the pattern of \"^[a-z]+$\".".to_string()
+            ),
+            (
+                "/tags/a~1b/name".to_string(),
+                "The length of the value must be `<= 3`.".to_string()
+            ),
+        ]
+    );
+}
+
+#[test]
+fn json_pointer_errors_get() {
+    let errors = invalid_struct().validate().unwrap_err();
+
+    assert_eq!(
+        errors
+            .get("/items/1/name")
+            .into_iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>(),
+        vec![
+            "The length of the value must be `<= 3`.",
+            "The value must match the pattern of \"^[a-z]+$\".",
+        ]
+    );
+    assert_eq!(errors.get("/tags/a~1b/name").len(), 1);
+    assert!(errors.get("/items/0/name").is_empty());
+    assert!(errors.get("").is_empty());
+    assert!(errors.get("items").is_empty());
+}
+
+#[test]
+fn json_pointer_errors_count() {
+    let errors = invalid_struct().validate().unwrap_err();
+
+    assert_eq!(errors.count(), 5);
+}
+
+#[test]
+fn json_pointer_errors_visit() {
+    struct MaxLengthLocations(Vec<JsonPointer>);
+
+    impl<'a> Visitor<'a> for MaxLengthLocations {
+        fn visit_error(&mut self, instance_location: &JsonPointer, error: &'a Error) {
+            if let Error::MaxLength(_) = error {
+                self.0.push(instance_location.clone());
+            }
+        }
+    }
+
+    let errors = invalid_struct().validate().unwrap_err();
+    let mut locations = MaxLengthLocations(vec![]);
+    errors.visit(&mut locations);
+
+    assert_eq!(
+        locations.0,
+        vec![
+            "/name".parse::<JsonPointer>().unwrap(),
+            "/items/1/name".parse::<JsonPointer>().unwrap(),
+            "/tags/a~1b/name".parse::<JsonPointer>().unwrap(),
+        ]
+    );
+}
+
+#[test]
+fn json_pointer_new_type_errors() {
+    let s = vec![TestItem {
+        name: "ABCD".to_string(),
+    }];
+    let errors = s.validate().unwrap_err();
+
+    assert_eq!(errors.count(), 2);
+    assert_eq!(errors.get("/0/name").len(), 2);
+    assert_eq!(
+        serde_json::to_value(errors.iter().next().unwrap().0).unwrap(),
+        serde_json::json!("/0/name")
+    );
+}
@@ -0,0 +1,320 @@
+use serde::Deserialize;
+use serde_json::json;
+use serde_valid::json::FromJsonValue;
+use serde_valid::validation::ToDetailed;
+use serde_valid::validation::ValidationOptions;
+use serde_valid::Validate;
+
+#[test]
+fn lenient_is_ok() {
+    #[derive(Debug, Validate, Deserialize)]
+    struct TestStruct {
+        #[validate(maximum = 10)]
+        val: i32,
+        name: Option<String>,
+    }
+
+    let s = TestStruct::from_json_value_lenient(json!({ "val": 5 })).unwrap();
+
+    assert_eq!(s.val, 5);
+    assert_eq!(s.name, None);
+}
+
+#[test]
+fn lenient_reports_type_errors_with_validation_errors() {
+    #[allow(dead_code)]
+    #[derive(Debug, Validate, Deserialize)]
+    struct TestStruct {
+        #[validate(maximum = 10)]
+        val: i32,
+        flag: bool,
+        #[validate(max_length = 4)]
+        name: String,
+    }
+
+    let err = TestStruct::from_json_value_lenient(json!({
+        "val": 1.5,
+        "flag": "true",
+        "name": "alice"
+    }))
+    .unwrap_err();
+
+    assert_eq!(
+        serde_json::to_value(err.as_validation_errors().unwrap()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "name": {
+                    "errors": ["The length of the value must be `<= 4`."]
+                },
+                "val": {
+                    "errors": ["The value must be of type `integer`, but `number` was given."]
+                },
+                "flag": {
+                    "errors": ["The value must be of type `boolean`, but `string` was given."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn lenient_skips_validations_of_invalid_values() {
+    #[derive(Debug, Validate, Deserialize)]
+    struct TestStruct {
+        #[validate(minimum = 1)]
+        val: i32,
+        #[validate(min_length = 1)]
+        name: String,
+    }
+
+    let err = TestStruct::from_json_value_lenient(json!({ "val": "x" })).unwrap_err();
+
+    assert_eq!(
+        serde_json::to_value(err.as_validation_errors().unwrap()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "name": {
+                    "errors": ["The value is required."]
+                },
+                "val": {
+                    "errors": ["The value must be of type `integer`, but `string` was given."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn lenient_reports_nested_errors() {
+    #[derive(Debug, Validate, Deserialize)]
+    struct TestStruct {
+        #[validate]
+        #[validate(max_items = 2)]
+        children: Vec<TestChild>,
+    }
+
+    #[derive(Debug, Validate, Deserialize)]
+    struct TestChild {
+        #[validate(maximum = 10)]
+        val: u8,
+    }
+
+    let err = TestStruct::from_json_value_lenient(json!({
+        "children": [{ "val": 300 }, { "val": 20 }, {}]
+    }))
+    .unwrap_err();
+
+    assert_eq!(
+        serde_json::to_value(err.as_validation_errors().unwrap()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "children": {
+                    "errors": ["The length of the items must be `<= 2`."],
+                    "items": {
+                        "1": {
+                            "errors": [],
+                            "properties": {
+                                "val": { "errors": ["The number must be `<= 10`."] }
+                            }
+                        },
+                        "2": {
+                            "errors": [],
+                            "properties": {
+                                "val": { "errors": ["The value is required."] }
+                            }
+                        },
+                        "0": {
+                            "errors": [],
+                            "properties": {
+                                "val": {
+                                    "errors": ["invalid value: integer `300`, expected u8"]
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn lenient_reports_rejected_values() {
+    #[derive(Debug, Deserialize)]
+    enum Status {
+        Active,
+        Inactive,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Debug, Validate, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct TestStruct {
+        status: Status,
+        #[validate(maximum = 10)]
+        val: i32,
+    }
+
+    let err = TestStruct::from_json_value_lenient(json!({
+        "status": "Deleted",
+        "val": 20,
+        "extra": 1
+    }))
+    .unwrap_err();
+    let errors = err.as_validation_errors().unwrap();
+
+    assert_eq!(errors.count(), 3);
+    assert_eq!(
+        json!(errors.to_detailed()),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [{
+                        "code": "maximum",
+                        "params": { "maximum": 10 },
+                        "message": "The number must be `<= 10`."
+                    }]
+                },
+                "status": {
+                    "errors": [{
+                        "code": "invalid_value",
+                        "params": {},
+                        "message": "unknown variant `Deleted`, expected `Active` or `Inactive`"
+                    }]
+                },
+                "extra": {
+                    "errors": [{
+                        "code": "invalid_value",
+                        "params": {},
+                        "message": "unknown field `extra`, expected `status` or `val`"
+                    }]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn lenient_type_error_params() {
+    #[allow(dead_code)]
+    #[derive(Debug, Validate, Deserialize)]
+    struct TestStruct {
+        val: Vec<i32>,
+    }
+
+    let err = TestStruct::from_json_value_lenient(json!({ "val": [1, "2"] })).unwrap_err();
+
+    assert_eq!(
+        json!(err.as_validation_errors().unwrap().to_detailed()),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [],
+                    "items": {
+                        "1": {
+                            "errors": [{
+                                "code": "type",
+                                "params": { "expected": "integer", "actual": "string" },
+                                "message": "The value must be of type `integer`, but `string` was given."
+                            }]
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn lenient_reports_many_missing_fields() {
+    #[allow(dead_code)]
+    #[derive(Debug, Validate, Deserialize)]
+    struct Item {
+        name: String,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Debug, Validate, Deserialize)]
+    struct TestStruct {
+        #[validate]
+        items: Vec<Item>,
+    }
+
+    let items = vec![json!({}); 200];
+
+    let err = TestStruct::from_json_value_lenient(json!({ "items": items })).unwrap_err();
+
+    let errors = serde_json::to_value(err.as_validation_errors().unwrap()).unwrap();
+
+    assert_eq!(
+        errors["properties"]["items"]["items"]
+            .as_object()
+            .unwrap()
+            .len(),
+        200
+    );
+    assert_eq!(
+        errors["properties"]["items"]["items"]["199"],
+        json!({
+            "errors": [],
+            "properties": {
+                "name": { "errors": ["The value is required."] }
+            }
+        })
+    );
+}
+
+#[test]
+fn lenient_max_errors_stops_replacing_missing_fields() {
+    #[allow(dead_code)]
+    #[derive(Debug, Validate, Deserialize)]
+    struct Item {
+        name: String,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Debug, Validate, Deserialize)]
+    struct TestStruct {
+        #[validate]
+        items: Vec<Item>,
+    }
+
+    let items = vec![json!({}); 200];
+
+    let err = TestStruct::from_json_value_lenient_with(
+        json!({ "items": items }),
+        &ValidationOptions::new().with_max_errors(2),
+    )
+    .unwrap_err();
+
+    assert_eq!(
+        serde_json::to_value(err.as_validation_errors().unwrap()).unwrap(),
+        json!({
+            "errors": ["The validation was stopped after `2` errors."],
+            "properties": {
+                "items": {
+                    "errors": [],
+                    "items": {
+                        "0": {
+                            "errors": [],
+                            "properties": {
+                                "name": { "errors": ["The value is required."] }
+                            }
+                        },
+                        "1": {
+                            "errors": [],
+                            "properties": {
+                                "name": { "errors": ["The value is required."] }
+                            }
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
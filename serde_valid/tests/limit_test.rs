@@ -0,0 +1,146 @@
+use serde_json::json;
+use serde_valid::Validate;
+use std::sync::OnceLock;
+
+const MAX_NAME_LENGTH: usize = 4;
+static MIN_AGE: u8 = 18;
+
+mod limits {
+    pub const MAX_TAGS: usize = 2;
+    pub const STEP: f32 = 0.5;
+}
+
+static CONFIG: OnceLock<usize> = OnceLock::new();
+
+fn max_properties() -> usize {
+    *CONFIG.get_or_init(|| 1)
+}
+
+#[test]
+fn limit_const_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(max_length = MAX_NAME_LENGTH)]
+        name: String,
+        #[validate(minimum = MIN_AGE)]
+        age: u8,
+        #[validate(max_items = limits::MAX_TAGS)]
+        tags: Vec<String>,
+        #[validate(multiple_of = limits::STEP)]
+        rate: f32,
+    }
+
+    let s = TestStruct {
+        name: "taro".to_string(),
+        age: 18,
+        tags: vec!["a".to_string(), "b".to_string()],
+        rate: 1.5,
+    };
+
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn limit_const_err_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(max_length = MAX_NAME_LENGTH)]
+        name: String,
+        #[validate(minimum = MIN_AGE)]
+        age: u8,
+    }
+
+    let s = TestStruct {
+        name: "hanako".to_string(),
+        age: 17,
+    };
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&s.validate().unwrap_err().to_string()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "name": {
+                    "errors": ["The length of the value must be `<= 4`."]
+                },
+                "age": {
+                    "errors": ["The number must be `>= 18`."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn limit_const_expression_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(min_length = MAX_NAME_LENGTH / 2)]
+        #[validate(max_length = MAX_NAME_LENGTH * 2)]
+        name: String,
+        #[validate(exclusive_maximum = -(MIN_AGE as i32))]
+        offset: i32,
+    }
+
+    assert!(TestStruct {
+        name: "ab".to_string(),
+        offset: -19,
+    }
+    .validate()
+    .is_ok());
+    assert!(TestStruct {
+        name: "a".to_string(),
+        offset: -19,
+    }
+    .validate()
+    .is_err());
+    assert!(TestStruct {
+        name: "ab".to_string(),
+        offset: -18,
+    }
+    .validate()
+    .is_err());
+}
+
+#[test]
+fn limit_negative_literal_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(minimum = -1.5)]
+        #[validate(maximum = -1.0)]
+        val: f64,
+    }
+
+    assert!(TestStruct { val: -1.0 }.validate().is_ok());
+    assert!(TestStruct { val: -2.0 }.validate().is_err());
+}
+
+#[test]
+fn limit_getter_fn_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(max_properties = max_properties)]
+        #[validate(min_properties = max_properties())]
+        val: serde_json::Map<String, serde_json::Value>,
+    }
+
+    let mut map = serde_json::Map::new();
+    map.insert("key1".to_string(), json!(1));
+
+    assert!(TestStruct { val: map.clone() }.validate().is_ok());
+
+    map.insert("key2".to_string(), json!(2));
+
+    assert_eq!(
+        TestStruct { val: map }.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["The size of the properties must be `<= 1`."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
@@ -0,0 +1,256 @@
+use serde_json::json;
+use serde_valid::validation::{OutputFormat, ToOutput};
+use serde_valid::Validate;
+
+#[derive(Validate)]
+struct Child {
+    #[validate(maximum = 10)]
+    val: i32,
+}
+
+#[derive(Validate)]
+struct TestStruct {
+    #[validate(max_length = 3)]
+    #[validate(pattern = r"^[a-z]+$")]
+    name: String,
+    #[validate(max_items = 1)]
+    #[validate(minimum = 0)]
+    items: Vec<i32>,
+    #[validate]
+    child: Child,
+}
+
+fn invalid() -> TestStruct {
+    TestStruct {
+        name: "Taro".to_string(),
+        items: vec![1, -1],
+        child: Child { val: 11 },
+    }
+}
+
+#[test]
+fn output_flag_is_err() {
+    assert_eq!(
+        json!(invalid()
+            .validate()
+            .unwrap_err()
+            .to_output(OutputFormat::Flag)),
+        json!({ "valid": false })
+    );
+}
+
+#[test]
+fn output_basic_is_err() {
+    assert_eq!(
+        json!(invalid()
+            .validate()
+            .unwrap_err()
+            .to_output(OutputFormat::Basic)),
+        json!({
+            "valid": false,
+            "keywordLocation": "",
+            "instanceLocation": "",
+            "errors": [
+                {
+                    "valid": false,
+                    "keywordLocation": "/properties/name/maxLength",
+                    "instanceLocation": "/name",
+                    "error": "The length of the value must be `<= 3`."
+                },
+                {
+                    "valid": false,
+                    "keywordLocation": "/properties/name/pattern",
+                    "instanceLocation": "/name",
+                    "error": "The value must match the pattern of \"^[a-z]+$\"."
+                },
+                {
+                    "valid": false,
+                    "keywordLocation": "/properties/items/maxItems",
+                    "instanceLocation": "/items",
+                    "error": "The length of the items must be `<= 1`."
+                },
+                {
+                    "valid": false,
+                    "keywordLocation": "/properties/items/items/minimum",
+                    "instanceLocation": "/items/1",
+                    "error": "The number must be `>= 0`."
+                },
+                {
+                    "valid": false,
+                    "keywordLocation": "/properties/child/properties/val/maximum",
+                    "instanceLocation": "/child/val",
+                    "error": "The number must be `<= 10`."
+                }
+            ]
+        })
+    );
+}
+
+#[test]
+fn output_detailed_is_err() {
+    assert_eq!(
+        json!(invalid()
+            .validate()
+            .unwrap_err()
+            .to_output(OutputFormat::Detailed)),
+        json!({
+            "valid": false,
+            "keywordLocation": "",
+            "instanceLocation": "",
+            "errors": [
+                {
+                    "valid": false,
+                    "keywordLocation": "/properties/name",
+                    "instanceLocation": "/name",
+                    "errors": [
+                        {
+                            "valid": false,
+                            "keywordLocation": "/properties/name/maxLength",
+                            "instanceLocation": "/name",
+                            "error": "The length of the value must be `<= 3`."
+                        },
+                        {
+                            "valid": false,
+                            "keywordLocation": "/properties/name/pattern",
+                            "instanceLocation": "/name",
+                            "error": "The value must match the pattern of \"^[a-z]+$\"."
+                        }
+                    ]
+                },
+                {
+                    "valid": false,
+                    "keywordLocation": "/properties/items",
+                    "instanceLocation": "/items",
+                    "errors": [
+                        {
+                            "valid": false,
+                            "keywordLocation": "/properties/items/maxItems",
+                            "instanceLocation": "/items",
+                            "error": "The length of the items must be `<= 1`."
+                        },
+                        {
+                            "valid": false,
+                            "keywordLocation": "/properties/items/items/minimum",
+                            "instanceLocation": "/items/1",
+                            "error": "The number must be `>= 0`."
+                        }
+                    ]
+                },
+                {
+                    "valid": false,
+                    "keywordLocation": "/properties/child/properties/val/maximum",
+                    "instanceLocation": "/child/val",
+                    "error": "The number must be `<= 10`."
+                }
+            ]
+        })
+    );
+}
+
+#[test]
+fn output_verbose_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate]
+        child: Child,
+    }
+
+    let s = TestStruct {
+        child: Child { val: 11 },
+    };
+
+    assert_eq!(
+        json!(s.validate().unwrap_err().to_output(OutputFormat::Verbose)),
+        json!({
+            "valid": false,
+            "keywordLocation": "",
+            "instanceLocation": "",
+            "errors": [{
+                "valid": false,
+                "keywordLocation": "/properties/child",
+                "instanceLocation": "/child",
+                "errors": [{
+                    "valid": false,
+                    "keywordLocation": "/properties/child/properties/val",
+                    "instanceLocation": "/child/val",
+                    "errors": [{
+                        "valid": false,
+                        "keywordLocation": "/properties/child/properties/val/maximum",
+                        "instanceLocation": "/child/val",
+                        "error": "The number must be `<= 10`."
+                    }]
+                }]
+            }]
+        })
+    );
+}
+
+#[test]
+fn output_absolute_keyword_location_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(minimum = 0)]
+        val: i32,
+    }
+
+    assert_eq!(
+        json!(TestStruct { val: -1 }
+            .validate()
+            .unwrap_err()
+            .to_output_with_base_uri(OutputFormat::Basic, Some("https://example.com/schema"))),
+        json!({
+            "valid": false,
+            "keywordLocation": "",
+            "absoluteKeywordLocation": "https://example.com/schema#",
+            "instanceLocation": "",
+            "errors": [{
+                "valid": false,
+                "keywordLocation": "/properties/val/minimum",
+                "absoluteKeywordLocation": "https://example.com/schema#/properties/val/minimum",
+                "instanceLocation": "/val",
+                "error": "The number must be `>= 0`."
+            }]
+        })
+    );
+}
+
+#[test]
+fn output_combinator_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(any_of(maximum = 1, minimum = 10))]
+        val: i32,
+    }
+
+    assert_eq!(
+        json!(TestStruct { val: 5 }
+            .validate()
+            .unwrap_err()
+            .to_output(OutputFormat::Basic)),
+        json!({
+            "valid": false,
+            "keywordLocation": "",
+            "instanceLocation": "",
+            "errors": [
+                {
+                    "valid": false,
+                    "keywordLocation": "/properties/val/anyOf",
+                    "instanceLocation": "/val",
+                    "error": "The value must satisfy any of the validations. [0] The number must be `<= 1`. [1] The number must be `>= 10`."
+                },
+                {
+                    "valid": false,
+                    "keywordLocation": "/properties/val/anyOf/0/maximum",
+                    "instanceLocation": "/val",
+                    "error": "The number must be `<= 1`."
+                },
+                {
+                    "valid": false,
+                    "keywordLocation": "/properties/val/anyOf/1/minimum",
+                    "instanceLocation": "/val",
+                    "error": "The number must be `>= 10`."
+                }
+            ]
+        })
+    );
+}
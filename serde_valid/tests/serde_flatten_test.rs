@@ -0,0 +1,215 @@
+use serde::Deserialize;
+use serde_json::json;
+use serde_valid::json::FromJsonValue;
+use serde_valid::Validate;
+
+#[test]
+fn serde_flatten_is_ok() {
+    #[derive(Debug, Validate, Deserialize)]
+    struct CommonFields {
+        #[validate(min_length = 1)]
+        id: String,
+    }
+
+    #[derive(Debug, Validate, Deserialize)]
+    struct TestStruct {
+        #[serde(flatten)]
+        #[validate]
+        common: CommonFields,
+        #[validate(maximum = 100)]
+        val: i32,
+    }
+
+    let s = TestStruct::from_json_value(json!({ "id": "abc", "val": 10 }));
+
+    assert!(s.is_ok())
+}
+
+#[test]
+fn serde_flatten_is_err() {
+    #[derive(Debug, Validate, Deserialize)]
+    #[rule(id_rule(id))]
+    struct CommonFields {
+        #[validate(min_length = 1)]
+        id: String,
+        #[serde(rename = "createdAt")]
+        #[validate(min_length = 10)]
+        created_at: String,
+    }
+
+    fn id_rule(id: &str) -> Result<(), serde_valid::validation::Error> {
+        if id.is_empty() {
+            Err(serde_valid::validation::Error::Custom(
+                "id must not be empty.".to_owned(),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    #[derive(Debug, Validate, Deserialize)]
+    struct TestStruct {
+        #[serde(flatten)]
+        #[validate]
+        common: CommonFields,
+        #[validate(maximum = 100)]
+        val: i32,
+    }
+
+    let err = TestStruct::from_json_value(json!({ "id": "", "createdAt": "2024", "val": 123 }))
+        .unwrap_err();
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&err.to_string()).unwrap(),
+        json!({
+            "errors": ["id must not be empty."],
+            "properties": {
+                "id": {
+                    "errors": ["The length of the value must be `>= 1`."]
+                },
+                "createdAt": {
+                    "errors": ["The length of the value must be `>= 10`."]
+                },
+                "val": {
+                    "errors": ["The number must be `<= 100`."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn serde_flatten_merge_same_property_is_err() {
+    #[derive(Debug, Validate, Deserialize)]
+    struct Inner {
+        #[validate(maximum = 10)]
+        val: i32,
+    }
+
+    #[derive(Debug, Validate, Deserialize)]
+    struct OtherInner {
+        #[validate(minimum = 0)]
+        other: i32,
+    }
+
+    #[derive(Debug, Validate, Deserialize)]
+    struct First {
+        #[validate]
+        inner: Inner,
+    }
+
+    #[derive(Debug, Validate, Deserialize)]
+    struct Second {
+        #[validate]
+        inner: OtherInner,
+    }
+
+    #[derive(Debug, Validate, Deserialize)]
+    struct TestStruct {
+        #[serde(flatten)]
+        #[validate]
+        first: First,
+        #[serde(flatten)]
+        #[validate]
+        second: Second,
+    }
+
+    let s = TestStruct {
+        first: First {
+            inner: Inner { val: 123 },
+        },
+        second: Second {
+            inner: OtherInner { other: -1 },
+        },
+    };
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&s.validate().unwrap_err().to_string()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "inner": {
+                    "errors": [],
+                    "properties": {
+                        "val": {
+                            "errors": ["The number must be `<= 10`."]
+                        },
+                        "other": {
+                            "errors": ["The number must be `>= 0`."]
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn serde_flatten_hashmap_is_err() {
+    #[derive(Debug, Validate, Deserialize)]
+    struct Value {
+        #[validate(maximum = 100)]
+        val: i32,
+    }
+
+    #[derive(Debug, Validate, Deserialize)]
+    struct TestStruct {
+        #[validate(min_length = 1)]
+        name: String,
+        #[serde(flatten)]
+        #[validate]
+        extra: std::collections::HashMap<String, Value>,
+    }
+
+    let err =
+        TestStruct::from_json_value(json!({ "name": "a", "foo": { "val": 123 } })).unwrap_err();
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&err.to_string()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "foo": {
+                    "errors": [],
+                    "properties": {
+                        "val": {
+                            "errors": ["The number must be `<= 100`."]
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn serde_flatten_enum_variant_is_err() {
+    #[derive(Debug, Validate, Deserialize)]
+    struct CommonFields {
+        #[validate(min_length = 1)]
+        id: String,
+    }
+
+    #[derive(Debug, Validate, Deserialize)]
+    enum TestEnum {
+        Struct {
+            #[serde(flatten)]
+            #[validate]
+            common: CommonFields,
+        },
+    }
+
+    let err = TestEnum::from_json_value(json!({ "Struct": { "id": "" } })).unwrap_err();
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&err.to_string()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "id": {
+                    "errors": ["The length of the value must be `>= 1`."]
+                }
+            }
+        })
+    );
+}
@@ -122,3 +122,174 @@ fn serde_rename_enume_is_err() {
         })
     );
 }
+
+#[test]
+fn serde_rename_all_is_err() {
+    #[derive(Debug, Validate, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct TestStruct {
+        #[validate(max_length = 3)]
+        user_name: String,
+        #[validate(maximum = 100)]
+        #[serde(rename = "value")]
+        max_value: i32,
+    }
+
+    let err = TestStruct::from_json_value(json!({ "userName": "abcd", "value": 123 })).unwrap_err();
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&err.to_string()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "userName": {
+                    "errors": ["The length of the value must be `<= 3`."]
+                },
+                "value": {
+                    "errors": ["The number must be `<= 100`."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn serde_rename_all_case_conventions() {
+    macro_rules! assert_rename_all {
+        ($rule:literal, $key:literal) => {{
+            #[derive(Debug, Validate, Deserialize)]
+            #[serde(rename_all = $rule)]
+            struct TestStruct {
+                #[validate(maximum = 100)]
+                max_value: i32,
+            }
+
+            let err = TestStruct::from_json_value(json!({ $key: 123 })).unwrap_err();
+
+            assert_eq!(
+                serde_json::from_str::<serde_json::Value>(&err.to_string()).unwrap(),
+                json!({
+                    "errors": [],
+                    "properties": {
+                        $key: {
+                            "errors": ["The number must be `<= 100`."]
+                        }
+                    }
+                })
+            );
+        }};
+    }
+
+    assert_rename_all!("lowercase", "max_value");
+    assert_rename_all!("UPPERCASE", "MAX_VALUE");
+    assert_rename_all!("PascalCase", "MaxValue");
+    assert_rename_all!("camelCase", "maxValue");
+    assert_rename_all!("snake_case", "max_value");
+    assert_rename_all!("SCREAMING_SNAKE_CASE", "MAX_VALUE");
+    assert_rename_all!("kebab-case", "max-value");
+    assert_rename_all!("SCREAMING-KEBAB-CASE", "MAX-VALUE");
+}
+
+#[test]
+fn serde_rename_all_deserialize_is_err() {
+    #[derive(Debug, Validate, Deserialize)]
+    #[serde(rename_all(serialize = "SCREAMING_SNAKE_CASE", deserialize = "kebab-case"))]
+    struct TestStruct {
+        #[validate(maximum = 100)]
+        max_value: i32,
+    }
+
+    let err = TestStruct::from_json_value(json!({ "max-value": 123 })).unwrap_err();
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&err.to_string()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "max-value": {
+                    "errors": ["The number must be `<= 100`."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn serde_rename_all_enum_variant_is_err() {
+    #[derive(Debug, Validate, Deserialize)]
+    enum TestEnum {
+        #[serde(rename_all = "camelCase")]
+        Struct {
+            #[validate(maximum = 100)]
+            max_value: i32,
+        },
+    }
+
+    let err = TestEnum::from_json_value(json!({ "Struct": { "maxValue": 123 } })).unwrap_err();
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&err.to_string()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "maxValue": {
+                    "errors": ["The number must be `<= 100`."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn serde_rename_all_fields_enum_is_err() {
+    #[derive(Debug, Validate, Deserialize)]
+    #[serde(rename_all = "snake_case", rename_all_fields = "PascalCase")]
+    enum TestEnum {
+        TestStruct {
+            #[validate(maximum = 100)]
+            max_value: i32,
+        },
+    }
+
+    let err = TestEnum::from_json_value(json!({ "test_struct": { "MaxValue": 123 } })).unwrap_err();
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&err.to_string()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "MaxValue": {
+                    "errors": ["The number must be `<= 100`."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn serde_other_attributes_are_not_rename() {
+    fn default_value() -> i32 {
+        123
+    }
+
+    #[derive(Debug, Validate, Deserialize)]
+    struct TestStruct {
+        #[validate(maximum = 100)]
+        #[serde(default = "default_value")]
+        val: i32,
+    }
+
+    let err = TestStruct::from_json_value(json!({})).unwrap_err();
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&err.to_string()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["The number must be `<= 100`."]
+                }
+            }
+        })
+    );
+}
@@ -0,0 +1,243 @@
+use serde::Deserialize;
+use serde_json::json;
+use serde_valid::json::StreamValidator;
+use serde_valid::Validate;
+
+#[derive(Debug, Validate, Deserialize)]
+struct TestStruct {
+    #[validate(maximum = 10)]
+    val: i32,
+    #[validate(max_length = 8)]
+    name: String,
+}
+
+/// The reader which yields one byte at a time.
+struct ByteReader<'a>(&'a [u8]);
+
+impl std::io::Read for ByteReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        match (self.0.split_first(), buf.first_mut()) {
+            (Some((&byte, rest)), Some(slot)) => {
+                *slot = byte;
+                self.0 = rest;
+                Ok(1)
+            }
+            _ => Ok(0),
+        }
+    }
+}
+
+#[test]
+fn stream_ndjson_is_ok() {
+    let input = r#"
+{ "val": 1, "name": "a" }
+
+{ "val": 2, "name": "b" }
+"#;
+
+    let records = StreamValidator::<TestStruct>::from_reader(input.as_bytes())
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    assert_eq!(
+        records.iter().map(|record| record.val).collect::<Vec<_>>(),
+        vec![1, 2]
+    );
+}
+
+#[test]
+fn stream_ndjson_reports_errors_and_continues() {
+    let input = r#"{ "val": 1, "name": "a" }
+{ "val": 20, "name": "b" }
+{ "val": 3, "name":
+{ "val": 4, "name": ["d"] }
+{ "val": 5, "name": "e" }"#;
+
+    let mut records = StreamValidator::<TestStruct>::from_ndjson_reader(input.as_bytes());
+
+    assert_eq!(records.next().unwrap().unwrap().val, 1);
+    assert_eq!(records.line(), 1);
+
+    let (index, err) = records.next().unwrap().unwrap_err();
+    assert_eq!((index, records.line()), (1, 2));
+    assert_eq!(
+        serde_json::to_value(err.as_validation_errors().unwrap()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["The number must be `<= 10`."]
+                }
+            }
+        })
+    );
+
+    let (index, err) = records.next().unwrap().unwrap_err();
+    assert_eq!((index, records.line()), (2, 3));
+    assert!(err.is_serde_error());
+
+    let (index, err) = records.next().unwrap().unwrap_err();
+    assert_eq!((index, records.line()), (3, 4));
+    assert_eq!(err.as_serde_error_path().unwrap().to_string(), "/name");
+
+    assert_eq!(records.next().unwrap().unwrap().val, 5);
+    assert!(records.next().is_none());
+}
+
+#[test]
+fn stream_array_reports_errors_with_lines() {
+    let input = r#"[
+  { "val": 1, "name": "a,]}\"[" },
+  {
+    "val": 20,
+    "name": "b"
+  },
+  { "val": 3, "name": "too long name" }, { "val": 4, "name": "d" }
+]
+"#;
+
+    let check = |mut records: StreamValidator<TestStruct>| {
+        assert_eq!(records.next().unwrap().unwrap().name, "a,]}\"[");
+        assert_eq!(records.line(), 2);
+
+        let (index, err) = records.next().unwrap().unwrap_err();
+        assert_eq!((index, records.line()), (1, 3));
+        assert!(err.is_validation_errors());
+
+        let (index, err) = records.next().unwrap().unwrap_err();
+        assert_eq!((index, records.line()), (2, 7));
+        assert!(err.is_validation_errors());
+
+        assert_eq!(records.next().unwrap().unwrap().val, 4);
+        assert_eq!(records.line(), 7);
+        assert!(records.next().is_none());
+    };
+
+    check(StreamValidator::from_reader(input.as_bytes()));
+    check(StreamValidator::from_array_reader(ByteReader(
+        input.as_bytes(),
+    )));
+}
+
+#[test]
+fn stream_array_nested_items() {
+    #[derive(Debug, Validate, Deserialize)]
+    struct TestItems {
+        #[validate(max_items = 2)]
+        items: Vec<Vec<i32>>,
+    }
+
+    let input = r#"[{ "items": [[1, 2], [3]] }, { "items": [[], [], []] }]"#;
+
+    let results = StreamValidator::<TestItems>::from_reader(input.as_bytes()).collect::<Vec<_>>();
+
+    assert_eq!(results.len(), 2);
+    assert_eq!(
+        results[0].as_ref().unwrap().items,
+        vec![vec![1, 2], vec![3]]
+    );
+    assert_eq!(results[1].as_ref().unwrap_err().0, 1);
+}
+
+#[test]
+fn stream_empty_array() {
+    let mut records = StreamValidator::<TestStruct>::from_reader(" [ ] ".as_bytes());
+
+    assert!(records.next().is_none());
+}
+
+#[test]
+fn stream_array_syntax_error_ends_iteration() {
+    let input = r#"[{ "val": 1, "name": "a" }, { "val": 2, "#;
+
+    let mut records = StreamValidator::<TestStruct>::from_array_reader(input.as_bytes());
+
+    assert!(records.next().unwrap().is_ok());
+    let (index, err) = records.next().unwrap().unwrap_err();
+    assert_eq!(index, 1);
+    assert!(err.is_serde_error());
+    assert!(records.next().is_none());
+}
+
+#[test]
+fn stream_array_reports_malformed_element_and_continues() {
+    let input = r#"[{ "val": 1, "name": }, { "val": 2, "name": "b" }]"#;
+
+    let mut records = StreamValidator::<TestStruct>::from_array_reader(input.as_bytes());
+
+    let (index, err) = records.next().unwrap().unwrap_err();
+    assert_eq!(index, 0);
+    assert!(err.is_serde_error());
+    assert_eq!(records.next().unwrap().unwrap().val, 2);
+    assert!(records.next().is_none());
+}
+
+#[test]
+fn stream_array_trailing_characters() {
+    let input = r#"[{ "val": 1, "name": "a" }] 2"#;
+
+    let mut records = StreamValidator::<TestStruct>::from_array_reader(input.as_bytes());
+
+    assert!(records.next().unwrap().is_ok());
+    let (index, err) = records.next().unwrap().unwrap_err();
+    assert_eq!(index, 1);
+    assert!(err.is_serde_error());
+    assert!(records.next().is_none());
+}
+
+#[test]
+fn stream_array_requires_bracket() {
+    let mut records = StreamValidator::<TestStruct>::from_array_reader("{}".as_bytes());
+
+    let (index, err) = records.next().unwrap().unwrap_err();
+    assert_eq!(index, 0);
+    assert!(err.is_serde_error());
+    assert!(records.next().is_none());
+}
+
+#[test]
+fn stream_array_unbalanced_element_ends_iteration() {
+    let input = r#"[{ "val": 1, "name": "a" }, { "val": 2, { "val": 3, "name": "c" }]"#;
+
+    let mut records = StreamValidator::<TestStruct>::from_array_reader(input.as_bytes());
+
+    assert!(records.next().unwrap().is_ok());
+    let (index, err) = records.next().unwrap().unwrap_err();
+    assert_eq!(index, 1);
+    assert!(err.is_serde_error());
+    assert!(records.next().is_none());
+}
+
+#[test]
+fn stream_array_max_record_size_ends_iteration() {
+    let input = r#"[{ "val": 1, "name": "a" }, { "val": 2, [ "#.to_string() + &" ".repeat(1024);
+
+    let mut records =
+        StreamValidator::<TestStruct>::from_array_reader(input.as_bytes()).with_max_record_size(64);
+
+    assert!(records.next().unwrap().is_ok());
+    let (index, err) = records.next().unwrap().unwrap_err();
+    assert_eq!(index, 1);
+    assert_eq!(err.to_string(), "the record exceeds `64` bytes");
+    assert!(records.next().is_none());
+}
+
+#[test]
+fn stream_ndjson_max_record_size_ends_iteration() {
+    let input = format!(
+        "{}\n{{ \"val\": 2, \"name\": \"{}\" }}\n{}\n",
+        r#"{ "val": 1, "name": "a" }"#,
+        "b".repeat(64),
+        r#"{ "val": 3, "name": "c" }"#,
+    );
+
+    let mut records = StreamValidator::<TestStruct>::from_ndjson_reader(input.as_bytes())
+        .with_max_record_size(64);
+
+    assert!(records.next().unwrap().is_ok());
+    let (index, err) = records.next().unwrap().unwrap_err();
+    assert_eq!(index, 1);
+    assert_eq!(records.line(), 2);
+    assert_eq!(err.to_string(), "the record exceeds `64` bytes");
+    assert!(records.next().is_none());
+}
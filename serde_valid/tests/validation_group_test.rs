@@ -0,0 +1,218 @@
+use serde_json::json;
+use serde_valid::{Validate, ValidationOptions};
+
+#[derive(Validate)]
+struct User {
+    #[validate(max_length = 0, groups(create))]
+    id: String,
+    #[validate(min_length = 1, groups(create))]
+    #[validate(max_length = 8)]
+    name: String,
+}
+
+#[test]
+fn validation_group_is_ok() {
+    let s = User {
+        id: "".to_string(),
+        name: "alice".to_string(),
+    };
+
+    assert!(s.validate().is_ok());
+    assert!(s.validate_group("create").is_ok());
+    assert!(s.validate_group("update").is_ok());
+}
+
+#[test]
+fn validation_group_is_not_selected() {
+    let s = User {
+        id: "123".to_string(),
+        name: "".to_string(),
+    };
+
+    assert!(s.validate().is_ok());
+    assert!(s.validate_group("update").is_ok());
+}
+
+#[test]
+fn validation_group_err_message() {
+    let s = User {
+        id: "123".to_string(),
+        name: "".to_string(),
+    };
+
+    assert_eq!(
+        s.validate_group("create").unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "id": {
+                    "errors": ["The length of the value must be `<= 0`."]
+                },
+                "name": {
+                    "errors": ["The length of the value must be `>= 1`."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn validation_without_group_always_runs() {
+    let s = User {
+        id: "".to_string(),
+        name: "too long name".to_string(),
+    };
+
+    assert!(s.validate().is_err());
+    assert!(s.validate_group("create").is_err());
+    assert!(s.validate_group("update").is_err());
+}
+
+#[test]
+fn validation_multiple_groups_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(maximum = 10, groups(create, "update"))]
+        val: i32,
+    }
+
+    let s = TestStruct { val: 11 };
+
+    assert!(s.validate().is_ok());
+    assert!(s.validate_group("create").is_err());
+    assert!(s.validate_group("update").is_err());
+    assert!(s.validate_group("delete").is_ok());
+}
+
+#[test]
+fn validation_group_with_custom_message_err_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(min_length = 1, groups(create), message = "name is required.")]
+        name: String,
+    }
+
+    let s = TestStruct {
+        name: "".to_string(),
+    };
+
+    assert_eq!(
+        s.validate_group("create").unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "name": {
+                    "errors": ["name is required."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn validation_group_with_options_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(maximum = 10, groups(admin))]
+        val: i32,
+    }
+
+    let s = TestStruct { val: 11 };
+
+    assert!(s
+        .validate_with(&ValidationOptions::new().with_group("create"))
+        .is_ok());
+    assert!(s
+        .validate_with(
+            &ValidationOptions::new()
+                .with_group("create")
+                .with_group("admin")
+        )
+        .is_err());
+}
+
+#[test]
+fn validation_group_propagates_to_nested_struct() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate]
+        user: User,
+        #[validate]
+        users: Vec<User>,
+        #[validate]
+        optional_user: Option<User>,
+    }
+
+    let user = || User {
+        id: "123".to_string(),
+        name: "alice".to_string(),
+    };
+    let s = TestStruct {
+        user: user(),
+        users: vec![user()],
+        optional_user: Some(user()),
+    };
+
+    assert!(s.validate().is_ok());
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(
+            &s.validate_group("create").unwrap_err().to_string()
+        )
+        .unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "user": {
+                    "errors": [],
+                    "properties": {
+                        "id": {
+                            "errors": ["The length of the value must be `<= 0`."]
+                        }
+                    }
+                },
+                "users": {
+                    "errors": [],
+                    "items": {
+                        "0": {
+                            "errors": [],
+                            "properties": {
+                                "id": {
+                                    "errors": ["The length of the value must be `<= 0`."]
+                                }
+                            }
+                        }
+                    }
+                },
+                "optional_user": {
+                    "errors": [],
+                    "properties": {
+                        "id": {
+                            "errors": ["The length of the value must be `<= 0`."]
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn validation_group_enum_variant_is_err() {
+    #[derive(Validate)]
+    enum TestEnum {
+        Named {
+            #[validate(maximum = 10, groups(create))]
+            val: i32,
+        },
+        Unnamed(#[validate(maximum = 10, groups(create))] i32),
+    }
+
+    assert!(TestEnum::Named { val: 11 }.validate().is_ok());
+    assert!(TestEnum::Named { val: 11 }
+        .validate_group("create")
+        .is_err());
+    assert!(TestEnum::Unnamed(11).validate().is_ok());
+    assert!(TestEnum::Unnamed(11).validate_group("create").is_err());
+}
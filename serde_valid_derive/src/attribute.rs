@@ -76,6 +76,7 @@ macro_rules! enum_str {
 
 enum_str! {
     pub enum MetaPathStructValidation {
+        ExternallyTagged = "externally_tagged",
     }
 }
 
@@ -87,12 +88,14 @@ enum_str! {
 
 enum_str! {
     pub enum MetaNameValueStructValidation {
+        Context = "context",
     }
 }
 
 enum_str! {
     pub enum MetaPathFieldValidation {
         UniqueItems = "unique_items",
+        Required = "required",
     }
 }
 
@@ -100,6 +103,14 @@ enum_str! {
     pub enum MetaListFieldValidation {
         Enumerate = "enumerate",
         Custom = "custom",
+        CustomAsync = "custom_async",
+        Contains = "contains",
+        AnyOf = "any_of",
+        AllOf = "all_of",
+        OneOf = "one_of",
+        Not = "not",
+        When = "when",
+        RequiredIf = "required_if",
     }
 }
 
@@ -117,6 +128,29 @@ enum_str! {
         MaxProperties = "max_properties",
         MultipleOf = "multiple_of",
         Pattern = "pattern",
+        Format = "format",
+        Const = "const",
+        Equals = "equals",
+        NotEquals = "not_equals",
+        LessThan = "less_than",
+        LessThanOrEqual = "less_than_or_equal",
+        GreaterThan = "greater_than",
+        GreaterThanOrEqual = "greater_than_or_equal",
+    }
+}
+
+enum_str! {
+    pub enum StringFormat {
+        Email = "email",
+        Uri = "uri",
+        Uuid = "uuid",
+        Ipv4 = "ipv4",
+        Ipv6 = "ipv6",
+        Hostname = "hostname",
+        Date = "date",
+        Time = "time",
+        DateTime = "date-time",
+        Duration = "duration",
     }
 }
 
@@ -1,2 +1,4 @@
+pub mod error_budget;
+pub mod groups;
 pub mod lit;
 pub mod message_format;
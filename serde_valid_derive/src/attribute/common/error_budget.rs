@@ -0,0 +1,28 @@
+use crate::attribute::Validator;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Skip the validator when the error budget of the validation call is exhausted,
+/// and consume the budget by the errors it adds to `errors`.
+pub fn wrap_error_budget_validator(validator: Validator, errors: &TokenStream) -> Validator {
+    if validator.is_empty() {
+        return validator;
+    }
+
+    quote!(
+        if !::serde_valid::helpers::skip_validation(__budget) {
+            let __error_count = ::serde_valid::helpers::count_errors(__budget, &#errors);
+            #validator
+            ::serde_valid::helpers::consume_errors(__budget, __error_count, &#errors);
+        }
+    )
+}
+
+/// The nested validation consumes the error budget by itself.
+pub fn wrap_nested_error_budget_validator(validator: Validator) -> Validator {
+    quote!(
+        if !::serde_valid::helpers::skip_validation(__budget) {
+            #validator
+        }
+    )
+}
@@ -0,0 +1,51 @@
+use crate::attribute::Validator;
+use crate::types::{CommaSeparatedNestedMetas, NestedMeta};
+use quote::quote;
+
+pub fn is_groups_meta(meta: &syn::Meta) -> bool {
+    meta.path().is_ident("groups")
+}
+
+pub fn extract_groups(meta: &syn::Meta) -> Result<Vec<String>, crate::Errors> {
+    let syn::Meta::List(meta_list) = meta else {
+        return Err(vec![crate::Error::validate_groups_need_items(meta)]);
+    };
+
+    let nested = meta_list
+        .parse_args_with(CommaSeparatedNestedMetas::parse_terminated)
+        .map_err(|error| vec![crate::Error::validate_groups_parse_error(meta_list, &error)])?;
+
+    if nested.is_empty() {
+        return Err(vec![crate::Error::validate_groups_need_items(meta)]);
+    }
+
+    let mut errors = vec![];
+    let groups = nested
+        .iter()
+        .filter_map(|item| match item {
+            NestedMeta::Meta(syn::Meta::Path(path)) if path.get_ident().is_some() => {
+                Some(path.get_ident().unwrap().to_string())
+            }
+            NestedMeta::Lit(syn::Lit::Str(lit_str)) => Some(lit_str.value()),
+            _ => {
+                errors.push(crate::Error::validate_groups_allow_name_only(item));
+                None
+            }
+        })
+        .collect();
+
+    if errors.is_empty() {
+        Ok(groups)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Run the validator only when one of the groups is selected.
+pub fn wrap_groups_validator(validator: Validator, groups: &[String]) -> Validator {
+    quote!(
+        if __options.is_any_group_selected(&[#(#groups),*]) {
+            #validator
+        }
+    )
+}
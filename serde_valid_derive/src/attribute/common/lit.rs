@@ -1,5 +1,5 @@
 use proc_macro2::TokenStream;
-use quote::ToTokens;
+use quote::{quote, ToTokens};
 
 pub enum LitNumeric<'a> {
     Int(&'a syn::LitInt),
@@ -22,7 +22,7 @@ pub fn get_lit(expr: &syn::Expr) -> Result<&syn::Lit, crate::Errors> {
     }
 }
 
-pub fn get_numeric(lit: &syn::Lit) -> Result<LitNumeric, crate::Errors> {
+pub fn get_numeric(lit: &syn::Lit) -> Result<LitNumeric<'_>, crate::Errors> {
     match lit {
         syn::Lit::Int(int) => Ok(LitNumeric::Int(int)),
         syn::Lit::Float(float) => Ok(LitNumeric::Float(float)),
@@ -30,6 +30,42 @@ pub fn get_numeric(lit: &syn::Lit) -> Result<LitNumeric, crate::Errors> {
     }
 }
 
+/// Get the numeric limit, such as `10`, `-10`, `MAX_LENGTH`, `MAX_LENGTH * 2`, or `max_length` getter function.
+pub fn get_numeric_limit(expr: &syn::Expr) -> Result<TokenStream, crate::Errors> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit { lit, .. }) => match lit {
+            syn::Lit::Int(_) | syn::Lit::Float(_) => Ok(quote!(#lit)),
+            _ => Err(vec![crate::Error::numeric_limit_only(expr)]),
+        },
+        syn::Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr: inner,
+            ..
+        }) if matches!(
+            inner.as_ref(),
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Int(_) | syn::Lit::Float(_),
+                ..
+            })
+        ) =>
+        {
+            Ok(quote!(#expr))
+        }
+        syn::Expr::Path(_)
+        | syn::Expr::Binary(_)
+        | syn::Expr::Unary(_)
+        | syn::Expr::Paren(_)
+        | syn::Expr::Call(_)
+        | syn::Expr::MethodCall(_)
+        | syn::Expr::Field(_)
+        | syn::Expr::Index(_)
+        | syn::Expr::Cast(_)
+        | syn::Expr::Block(_)
+        | syn::Expr::Closure(_) => Ok(quote!(::serde_valid::helpers::Limit::limit(#expr))),
+        _ => Err(vec![crate::Error::numeric_limit_only(expr)]),
+    }
+}
+
 pub fn get_str(lit: &syn::Lit) -> Result<&syn::LitStr, crate::Errors> {
     match lit {
         syn::Lit::Str(lit_str) => Ok(lit_str),
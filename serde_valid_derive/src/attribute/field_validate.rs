@@ -1,5 +1,8 @@
 mod array;
+mod combinator;
+mod conditional;
 mod field;
+mod field_reference;
 mod generic;
 mod meta;
 mod numeric;
@@ -7,4 +10,5 @@ mod object;
 mod string;
 
 pub use field::FieldValidators;
+pub use field_reference::collect_referenced_fields;
 pub use meta::extract_field_validator;
@@ -0,0 +1,133 @@
+use crate::attribute::common::lit::{get_lit, get_numeric};
+use crate::attribute::common::message_format::MessageFormat;
+use crate::attribute::field_validate::generic::extract_custom_fn_name;
+use crate::attribute::Validator;
+use crate::serde::rename::RenameMap;
+use crate::types::{CommaSeparatedNestedMetas, Field, NestedMeta, SingleIdentPath};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+pub fn extract_array_contains_validator(
+    field: &impl Field,
+    meta_list: &syn::MetaList,
+    message_format: MessageFormat,
+    rename_map: &RenameMap,
+) -> Result<Validator, crate::Errors> {
+    inner_extract_array_contains_validator(field, meta_list, message_format, rename_map)
+}
+
+fn inner_extract_array_contains_validator(
+    field: &impl Field,
+    meta_list: &syn::MetaList,
+    message_format: MessageFormat,
+    rename_map: &RenameMap,
+) -> Result<TokenStream, crate::Errors> {
+    let field_name = field.name();
+    let field_ident = field.ident();
+    let field_key = field.key();
+    let rename = rename_map.get(field_name).unwrap_or(&field_key);
+    let errors = field.errors_variable();
+    let Contains {
+        predicate,
+        min_contains,
+        max_contains,
+    } = get_contains(meta_list)?;
+
+    Ok(quote!(
+        if let Err(error_params) = ::serde_valid::ValidateContains::validate_contains(
+            #field_ident,
+            #predicate,
+            #min_contains,
+            #max_contains,
+        ) {
+            use ::serde_valid::validation::error::FormatDefault;
+
+            #errors
+                .entry(#rename)
+                .or_default()
+                .push(::serde_valid::validation::Error::Contains(
+                    ::serde_valid::validation::error::Message::new(
+                        error_params,
+                        #message_format,
+                    )
+                ));
+        }
+    ))
+}
+
+struct Contains {
+    predicate: TokenStream,
+    min_contains: TokenStream,
+    max_contains: TokenStream,
+}
+
+fn get_contains(meta_list: &syn::MetaList) -> Result<Contains, crate::Errors> {
+    let mut errors = vec![];
+    let mut predicate = None;
+    let mut min_contains = quote!(1);
+    let mut max_contains = quote!(None);
+
+    let nested = meta_list
+        .parse_args_with(CommaSeparatedNestedMetas::parse_terminated)
+        .map_err(|error| {
+            vec![crate::Error::validate_contains_parse_error(
+                meta_list, &error,
+            )]
+        })?;
+
+    for item in nested.iter() {
+        match item {
+            NestedMeta::Meta(syn::Meta::List(custom)) if custom.path.is_ident("custom") => {
+                match get_predicate(custom) {
+                    Ok(custom_fn_name) => predicate = Some(custom_fn_name),
+                    Err(predicate_errors) => errors.extend(predicate_errors),
+                }
+            }
+            NestedMeta::Meta(syn::Meta::NameValue(name_value)) => {
+                let name = SingleIdentPath::new(&name_value.path).ident().to_string();
+                let value = get_lit(&name_value.value).and_then(get_numeric);
+                match (name.as_str(), value) {
+                    ("min_contains", Ok(value)) => min_contains = quote!(#value),
+                    ("max_contains", Ok(value)) => max_contains = quote!(Some(#value)),
+                    ("min_contains" | "max_contains", Err(value_errors)) => {
+                        errors.extend(value_errors)
+                    }
+                    _ => errors.push(crate::Error::validate_contains_unknown_item(item)),
+                }
+            }
+            _ => errors.push(crate::Error::validate_contains_unknown_item(item)),
+        }
+    }
+
+    match predicate {
+        Some(predicate) if errors.is_empty() => Ok(Contains {
+            predicate,
+            min_contains,
+            max_contains,
+        }),
+        Some(_) => Err(errors),
+        None => {
+            errors.push(crate::Error::validate_contains_need_custom(&meta_list.path));
+            Err(errors)
+        }
+    }
+}
+
+fn get_predicate(custom: &syn::MetaList) -> Result<TokenStream, crate::Errors> {
+    let path_ident = SingleIdentPath::new(&custom.path).ident();
+    let nested = custom
+        .parse_args_with(CommaSeparatedNestedMetas::parse_terminated)
+        .map_err(|error| vec![crate::Error::custom_message_parse_error(path_ident, &error)])?;
+
+    match nested.len() {
+        0 => Err(vec![
+            crate::Error::validate_custom_need_function_or_closure(&custom.path),
+        ]),
+        1 => extract_custom_fn_name(&nested[0]),
+        _ => Err(nested
+            .iter()
+            .skip(1)
+            .map(crate::Error::validate_custom_tail_error)
+            .collect()),
+    }
+}
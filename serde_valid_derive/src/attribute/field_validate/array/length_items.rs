@@ -1,4 +1,4 @@
-use crate::attribute::common::lit::get_numeric;
+use crate::attribute::common::lit::get_numeric_limit;
 use crate::attribute::common::message_format::MessageFormat;
 use crate::attribute::Validator;
 use crate::serde::rename::RenameMap;
@@ -14,7 +14,7 @@ macro_rules! extract_array_length_validator{
         paste::paste! {
             pub fn [<extract_array_ $ErrorType:snake _validator>](
                 field: &impl Field,
-                validation_value: &syn::Lit,
+                validation_value: &syn::Expr,
                 message_format: MessageFormat,
                 rename_map: &RenameMap,
             ) -> Result<Validator, crate::Errors> {
@@ -23,7 +23,7 @@ macro_rules! extract_array_length_validator{
 
             fn [<inner_extract_array_ $ErrorType:snake _validator>](
                 field: &impl Field,
-                validation_value: &syn::Lit,
+                validation_value: &syn::Expr,
                 message_format: MessageFormat,
                 rename_map: &RenameMap,
             ) -> Result<TokenStream, crate::Errors> {
@@ -31,7 +31,7 @@ macro_rules! extract_array_length_validator{
                 let field_ident = field.ident();
                 let field_key = field.key();
                 let rename = rename_map.get(field_name).unwrap_or(&field_key);
-                let [<$ErrorType:snake>] = get_numeric(validation_value)?;
+                let [<$ErrorType:snake>] = get_numeric_limit(validation_value)?;
                 let errors = field.errors_variable();
 
                 Ok(quote!(
@@ -0,0 +1,217 @@
+use crate::attribute::common::message_format::{default_message_format, MessageFormat};
+use crate::attribute::field_validate::meta::extract_field_validator_from_meta;
+use crate::attribute::{MetaListFieldValidation, Validator};
+use crate::serde::rename::RenameMap;
+use crate::types::{Field, ValidateTrait};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Combinator validation.
+///
+/// See <https://json-schema.org/understanding-json-schema/reference/combining>
+pub fn extract_combinator_validator(
+    field: &impl Field,
+    combinator_type: MetaListFieldValidation,
+    meta_list: &syn::MetaList,
+    message_format: MessageFormat,
+    rename_map: &RenameMap,
+    validate_trait: ValidateTrait,
+) -> Result<Validator, crate::Errors> {
+    let field_name = field.name();
+    let field_key = field.key();
+    let rename = rename_map.get(field_name).unwrap_or(&field_key);
+    let errors = field.errors_variable();
+    let branches = extract_branch_validators(
+        field,
+        &combinator_type,
+        meta_list,
+        rename_map,
+        validate_trait,
+    )?;
+    let branch_count = branches.len();
+
+    let combinator_error = match combinator_type {
+        MetaListFieldValidation::AnyOf => quote!(
+            if __combinator_branch_errors.len() == #branch_count {
+                Some(::serde_valid::validation::Error::AnyOf(Box::new(
+                    ::serde_valid::validation::error::Message::new(
+                        ::serde_valid::AnyOfError::new(__combinator_branch_errors),
+                        #message_format,
+                    )
+                )))
+            } else {
+                None
+            }
+        ),
+        MetaListFieldValidation::AllOf => quote!(
+            if !__combinator_branch_errors.is_empty() {
+                Some(::serde_valid::validation::Error::AllOf(Box::new(
+                    ::serde_valid::validation::error::Message::new(
+                        ::serde_valid::AllOfError::new(__combinator_branch_errors),
+                        #message_format,
+                    )
+                )))
+            } else {
+                None
+            }
+        ),
+        MetaListFieldValidation::OneOf => quote!(
+            let __valid_branches = (0..#branch_count)
+                .filter(|index| !__combinator_branch_errors.contains_key(index))
+                .collect::<Vec<_>>();
+            if __valid_branches.len() != 1 {
+                Some(::serde_valid::validation::Error::OneOf(Box::new(
+                    ::serde_valid::validation::error::Message::new(
+                        ::serde_valid::OneOfError::new(__combinator_branch_errors, __valid_branches),
+                        #message_format,
+                    )
+                )))
+            } else {
+                None
+            }
+        ),
+        MetaListFieldValidation::Not => quote!(
+            if __combinator_branch_errors.is_empty() {
+                Some(::serde_valid::validation::Error::Not(
+                    ::serde_valid::validation::error::Message::new(
+                        ::serde_valid::NotError,
+                        #message_format,
+                    )
+                ))
+            } else {
+                None
+            }
+        ),
+        MetaListFieldValidation::Enumerate
+        | MetaListFieldValidation::Custom
+        | MetaListFieldValidation::CustomAsync
+        | MetaListFieldValidation::Contains
+        | MetaListFieldValidation::When
+        | MetaListFieldValidation::RequiredIf => unreachable!(),
+    };
+
+    Ok(quote!(
+        {
+            use ::serde_valid::validation::error::FormatDefault;
+
+            let mut __combinator_branch_errors =
+                ::serde_valid::validation::ItemVecErrorsMap::<::serde_valid::validation::Error>::new();
+            #(#branches)*
+
+            let __combinator_error = { #combinator_error };
+            if let Some(__combinator_error) = __combinator_error {
+                #errors
+                    .entry(#rename)
+                    .or_default()
+                    .push(__combinator_error);
+            }
+        }
+    ))
+}
+
+fn extract_branch_validators(
+    field: &impl Field,
+    combinator_type: &MetaListFieldValidation,
+    meta_list: &syn::MetaList,
+    rename_map: &RenameMap,
+    validate_trait: ValidateTrait,
+) -> Result<Vec<TokenStream>, crate::Errors> {
+    let nested = meta_list
+        .parse_args_with(crate::types::parse_comma_separated_keyword_metas)
+        .map_err(|error| {
+            vec![crate::Error::validate_combinator_parse_error(
+                meta_list, &error,
+            )]
+        })?;
+
+    match (combinator_type, nested.len()) {
+        (_, 0) => Err(vec![crate::Error::validate_combinator_need_item(
+            &meta_list.path,
+        )])?,
+        (MetaListFieldValidation::Not, 2..) => Err(nested
+            .iter()
+            .skip(1)
+            .map(crate::Error::validate_not_tail_error)
+            .collect::<Vec<_>>())?,
+        _ => {}
+    }
+
+    let mut errors = vec![];
+    let branch_field = BranchField(field);
+    let branches = nested
+        .iter()
+        .enumerate()
+        .filter_map(|(index, meta)| {
+            if meta.path().is_ident("validate") {
+                errors.push(crate::Error::validate_combinator_nested_validate(meta));
+                return None;
+            }
+            if meta.path().is_ident("custom_async") {
+                errors.push(crate::Error::validate_combinator_custom_async(meta));
+                return None;
+            }
+
+            match extract_field_validator_from_meta(
+                &branch_field,
+                meta,
+                default_message_format(),
+                rename_map,
+                validate_trait,
+            ) {
+                Ok(validator) => Some(quote!(
+                    {
+                        let mut __branch_vec_errors_map =
+                            ::std::collections::HashMap::<_, ::serde_valid::validation::VecErrors>::new();
+                        #validator
+                        let __branch_vec_errors = __branch_vec_errors_map
+                            .into_values()
+                            .flatten()
+                            .collect::<::serde_valid::validation::VecErrors>();
+                        if !__branch_vec_errors.is_empty() {
+                            __combinator_branch_errors.insert(#index, __branch_vec_errors);
+                        }
+                    }
+                )),
+                Err(branch_errors) => {
+                    errors.extend(branch_errors);
+                    None
+                }
+            }
+        })
+        .collect();
+
+    if errors.is_empty() {
+        Ok(branches)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Field proxy that collects the errors of a combinator branch into a local map.
+struct BranchField<'a>(&'a dyn Field);
+
+impl<'a> Field for BranchField<'a> {
+    fn name(&self) -> &String {
+        self.0.name()
+    }
+
+    fn ident(&self) -> &syn::Ident {
+        self.0.ident()
+    }
+
+    fn key(&self) -> TokenStream {
+        self.0.key()
+    }
+
+    fn errors_variable(&self) -> TokenStream {
+        quote!(__branch_vec_errors_map)
+    }
+
+    fn getter_token(&self) -> TokenStream {
+        self.0.getter_token()
+    }
+
+    fn attrs(&self) -> &Vec<syn::Attribute> {
+        self.0.attrs()
+    }
+}
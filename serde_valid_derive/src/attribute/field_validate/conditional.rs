@@ -0,0 +1,193 @@
+use crate::attribute::common::message_format::{default_message_format, MessageFormat};
+use crate::attribute::field_validate::generic::extract_generic_required_validator;
+use crate::attribute::field_validate::meta::extract_field_validator_from_meta;
+use crate::attribute::{MetaListFieldValidation, Validator};
+use crate::serde::rename::RenameMap;
+use crate::types::{Field, SingleIdentPath, ValidateTrait};
+use quote::quote;
+
+/// Conditional validation.
+///
+/// See <https://json-schema.org/understanding-json-schema/reference/conditionals>
+pub fn extract_conditional_validator(
+    field: &impl Field,
+    conditional_type: MetaListFieldValidation,
+    meta_list: &syn::MetaList,
+    message_format: MessageFormat,
+    rename_map: &RenameMap,
+    validate_trait: ValidateTrait,
+) -> Result<Validator, crate::Errors> {
+    let Conditional {
+        condition_field,
+        equals,
+        then_metas,
+        else_metas,
+    } = get_conditional(&conditional_type, meta_list)?;
+
+    let (then_validators, else_validators) = match conditional_type {
+        MetaListFieldValidation::RequiredIf => (
+            vec![extract_generic_required_validator(
+                field,
+                message_format,
+                rename_map,
+            )],
+            vec![],
+        ),
+        MetaListFieldValidation::When => {
+            let mut errors = vec![];
+            let mut extract_validators = |metas: &[syn::Meta]| {
+                metas
+                    .iter()
+                    .filter_map(|meta| {
+                        match extract_field_validator_from_meta(
+                            field,
+                            meta,
+                            default_message_format(),
+                            rename_map,
+                            validate_trait,
+                        ) {
+                            Ok(validator) => Some(validator),
+                            Err(validator_errors) => {
+                                errors.extend(validator_errors);
+                                None
+                            }
+                        }
+                    })
+                    .collect::<Vec<_>>()
+            };
+            let validators = (
+                extract_validators(&then_metas),
+                extract_validators(&else_metas),
+            );
+
+            if !errors.is_empty() {
+                return Err(errors);
+            }
+            validators
+        }
+        _ => unreachable!(),
+    };
+
+    let else_tokens = if else_validators.is_empty() {
+        quote!()
+    } else {
+        quote!(else { #(#else_validators)* })
+    };
+
+    Ok(quote!(
+        if *#condition_field == #equals {
+            #(#then_validators)*
+        } #else_tokens
+    ))
+}
+
+struct Conditional {
+    condition_field: syn::Ident,
+    equals: syn::Expr,
+    then_metas: Vec<syn::Meta>,
+    else_metas: Vec<syn::Meta>,
+}
+
+fn get_conditional(
+    conditional_type: &MetaListFieldValidation,
+    meta_list: &syn::MetaList,
+) -> Result<Conditional, crate::Errors> {
+    let mut errors = vec![];
+    let mut condition_field = None;
+    let mut equals = None;
+    let mut then_metas = None;
+    let mut else_metas = vec![];
+
+    let nested = meta_list
+        .parse_args_with(crate::types::parse_comma_separated_keyword_metas)
+        .map_err(|error| {
+            vec![crate::Error::validate_conditional_parse_error(
+                meta_list, &error,
+            )]
+        })?;
+
+    for meta in nested.iter() {
+        let name = match meta {
+            syn::Meta::Path(path) => path,
+            syn::Meta::List(list) => &list.path,
+            syn::Meta::NameValue(name_value) => &name_value.path,
+        };
+
+        match (
+            SingleIdentPath::new(name).ident().to_string().as_str(),
+            meta,
+            conditional_type,
+        ) {
+            ("field", syn::Meta::NameValue(name_value), _) => {
+                match get_condition_field(&name_value.value) {
+                    Ok(field) => condition_field = Some(field),
+                    Err(field_errors) => errors.extend(field_errors),
+                }
+            }
+            ("equals", syn::Meta::NameValue(name_value), _) => {
+                equals = Some(name_value.value.clone())
+            }
+            ("then", syn::Meta::List(list), MetaListFieldValidation::When) => {
+                match list.parse_args_with(crate::types::parse_comma_separated_keyword_metas) {
+                    Ok(metas) => then_metas = Some(metas.into_iter().collect()),
+                    Err(error) => {
+                        errors.push(crate::Error::validate_conditional_parse_error(list, &error))
+                    }
+                }
+            }
+            ("else", syn::Meta::List(list), MetaListFieldValidation::When) => {
+                match list.parse_args_with(crate::types::parse_comma_separated_keyword_metas) {
+                    Ok(metas) => else_metas = metas.into_iter().collect(),
+                    Err(error) => {
+                        errors.push(crate::Error::validate_conditional_parse_error(list, &error))
+                    }
+                }
+            }
+            _ => errors.push(crate::Error::validate_conditional_unknown_item(
+                &meta_list.path,
+                meta,
+            )),
+        }
+    }
+
+    if condition_field.is_none() {
+        errors.push(crate::Error::validate_conditional_need_field(
+            &meta_list.path,
+        ));
+    }
+    if equals.is_none() {
+        errors.push(crate::Error::validate_conditional_need_equals(
+            &meta_list.path,
+        ));
+    }
+    if matches!(conditional_type, MetaListFieldValidation::When) && then_metas.is_none() {
+        errors.push(crate::Error::validate_when_need_then(&meta_list.path));
+    }
+
+    match (condition_field, equals) {
+        (Some(condition_field), Some(equals)) if errors.is_empty() => Ok(Conditional {
+            condition_field,
+            equals,
+            then_metas: then_metas.unwrap_or_default(),
+            else_metas,
+        }),
+        _ => Err(errors),
+    }
+}
+
+pub fn get_condition_field(expr: &syn::Expr) -> Result<syn::Ident, crate::Errors> {
+    match expr {
+        syn::Expr::Path(syn::ExprPath { path, .. }) if path.get_ident().is_some() => {
+            Ok(path.get_ident().unwrap().clone())
+        }
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(lit_str),
+            ..
+        }) => lit_str
+            .parse::<syn::Ident>()
+            .map_err(|_| vec![crate::Error::validate_conditional_field_name_only(expr)]),
+        _ => Err(vec![crate::Error::validate_conditional_field_name_only(
+            expr,
+        )]),
+    }
+}
@@ -0,0 +1,71 @@
+use crate::attribute::field_validate::conditional::get_condition_field;
+use std::collections::HashSet;
+
+/// Get the field name of `field(other_field)`.
+pub fn get_field_reference(expr: &syn::Expr) -> Result<&syn::Ident, crate::Errors> {
+    if let syn::Expr::Call(syn::ExprCall { func, args, .. }) = expr {
+        if let syn::Expr::Path(syn::ExprPath { path, .. }) = func.as_ref() {
+            if path.is_ident("field") && args.len() == 1 {
+                if let syn::Expr::Path(syn::ExprPath { path, .. }) = &args[0] {
+                    if let Some(ident) = path.get_ident() {
+                        return Ok(ident);
+                    }
+                }
+            }
+        }
+    }
+
+    Err(vec![crate::Error::validate_field_reference_only(expr)])
+}
+
+/// Collect the fields referenced by other field validations.
+///
+/// The references are `when(field = ...)`, `required_if(field = ...)` and `... = field(...)`.
+/// These fields must be bound as local variables before any field validation runs.
+pub fn collect_referenced_fields<'a>(
+    fields: impl IntoIterator<Item = &'a syn::Field>,
+) -> HashSet<syn::Ident> {
+    let mut referenced_fields = HashSet::new();
+
+    for attribute in fields.into_iter().flat_map(|field| field.attrs.iter()) {
+        if let syn::Meta::List(list) = &attribute.meta {
+            if attribute.path().is_ident("validate") {
+                collect_referenced_fields_from_meta_list(list, &mut referenced_fields);
+            }
+        }
+    }
+
+    referenced_fields
+}
+
+fn collect_referenced_fields_from_meta_list(
+    meta_list: &syn::MetaList,
+    referenced_fields: &mut HashSet<syn::Ident>,
+) {
+    let Ok(nested) = meta_list.parse_args_with(crate::types::parse_comma_separated_keyword_metas)
+    else {
+        return;
+    };
+    let is_conditional = meta_list.path.is_ident("when") || meta_list.path.is_ident("required_if");
+
+    for meta in nested.iter() {
+        match meta {
+            syn::Meta::NameValue(name_value)
+                if is_conditional && name_value.path.is_ident("field") =>
+            {
+                if let Ok(field) = get_condition_field(&name_value.value) {
+                    referenced_fields.insert(field);
+                }
+            }
+            syn::Meta::NameValue(name_value) => {
+                if let Ok(field) = get_field_reference(&name_value.value) {
+                    referenced_fields.insert(field.clone());
+                }
+            }
+            syn::Meta::List(list) => {
+                collect_referenced_fields_from_meta_list(list, referenced_fields)
+            }
+            _ => {}
+        }
+    }
+}
@@ -1,7 +1,19 @@
+mod constant;
 mod custom;
 mod enumerate;
+mod field_comparison;
+mod required;
 mod validate;
 
-pub use custom::extract_generic_custom_validator;
+pub use constant::extract_generic_const_validator;
+pub use custom::{
+    extract_custom_fn_name, extract_generic_custom_async_validator,
+    extract_generic_custom_validator,
+};
 pub use enumerate::extract_generic_enumerate_validator;
-pub use validate::extract_generic_validate_validator;
+pub use field_comparison::{
+    extract_generic_field_comparison_validator,
+    extract_generic_redacted_field_comparison_validator, is_field_comparison, is_redact_meta,
+};
+pub use required::extract_generic_required_validator;
+pub use validate::{extract_generic_nested_async_validator, extract_generic_validate_validator};
@@ -0,0 +1,54 @@
+use crate::attribute::common::message_format::MessageFormat;
+use crate::attribute::Validator;
+use crate::serde::rename::RenameMap;
+use crate::types::Field;
+use quote::quote;
+
+pub fn extract_generic_const_validator(
+    field: &impl Field,
+    validation_value: &syn::Lit,
+    message_format: MessageFormat,
+    rename_map: &RenameMap,
+) -> Result<Validator, crate::Errors> {
+    inner_extract_generic_const_validator(field, validation_value, message_format, rename_map)
+}
+
+fn inner_extract_generic_const_validator(
+    field: &impl Field,
+    validation_value: &syn::Lit,
+    message_format: MessageFormat,
+    rename_map: &RenameMap,
+) -> Result<Validator, crate::Errors> {
+    let field_name = field.name();
+    let field_ident = field.ident();
+    let field_key = field.key();
+    let rename = rename_map.get(field_name).unwrap_or(&field_key);
+    let errors = field.errors_variable();
+    let const_value = get_const(validation_value)?;
+
+    Ok(quote!(
+        if let Err(__composited_error_params) = ::serde_valid::validation::ValidateCompositedConst::validate_composited_const(
+            #field_ident,
+            #const_value,
+        ) {
+            use ::serde_valid::validation::IntoError;
+            use ::serde_valid::validation::error::FormatDefault;
+
+            #errors
+                .entry(#rename)
+                .or_default()
+                .push(__composited_error_params.into_error_by(#message_format));
+        }
+    ))
+}
+
+fn get_const(lit: &syn::Lit) -> Result<&syn::Lit, crate::Errors> {
+    match lit {
+        syn::Lit::Str(_)
+        | syn::Lit::Char(_)
+        | syn::Lit::Int(_)
+        | syn::Lit::Float(_)
+        | syn::Lit::Bool(_) => Ok(lit),
+        _ => Err(vec![crate::Error::literal_only(lit)]),
+    }
+}
@@ -1,7 +1,7 @@
 use crate::attribute::common::message_format::MessageFormat;
 use crate::attribute::Validator;
 use crate::serde::rename::RenameMap;
-use crate::types::{CommaSeparatedNestedMetas, Field, SingleIdentPath};
+use crate::types::{CommaSeparatedNestedMetas, Field, SingleIdentPath, ValidateTrait};
 use proc_macro2::TokenStream;
 use quote::quote;
 
@@ -10,14 +10,90 @@ pub fn extract_generic_custom_validator(
     meta_list: &syn::MetaList,
     _message_format: MessageFormat,
     rename_map: &RenameMap,
+    validate_trait: ValidateTrait,
 ) -> Result<Validator, crate::Errors> {
-    let path = &meta_list.path;
-    let path_ident = SingleIdentPath::new(path).ident();
     let field_name = field.name();
     let field_ident = field.ident();
     let field_key = field.key();
     let rename = rename_map.get(field_name).unwrap_or(&field_key);
     let errors = field.errors_variable();
+    let (custom_fn_name, custom_fn) = extract_custom_fn(meta_list)?;
+    let custom_error = extract_custom_error(&custom_fn);
+
+    let custom_call = match (validate_trait, custom_fn) {
+        (ValidateTrait::ValidateWithContext(_), crate::types::NestedMeta::Closure(_)) => quote!(
+            ::serde_valid::helpers::wrap_closure_validation_with_context(
+                #field_ident,
+                __context,
+                #custom_fn_name,
+            )
+        ),
+        (ValidateTrait::ValidateWithContext(_), _) => {
+            quote!(#custom_fn_name(#field_ident, __context))
+        }
+        (ValidateTrait::Validate | ValidateTrait::AsyncValidate, _) => {
+            quote!(#custom_fn_name(#field_ident))
+        }
+    };
+
+    Ok(quote!(
+        if let Err(__error) = #custom_call {
+            #errors
+                .entry(#rename)
+                .or_default()
+                .push(#custom_error);
+        };
+    ))
+}
+
+/// `custom_async` validations run only in `AsyncValidate`, and are skipped by `Validate`.
+pub fn extract_generic_custom_async_validator(
+    field: &impl Field,
+    meta_list: &syn::MetaList,
+    _message_format: MessageFormat,
+    rename_map: &RenameMap,
+    validate_trait: ValidateTrait,
+) -> Result<Validator, crate::Errors> {
+    if let ValidateTrait::ValidateWithContext(_) = validate_trait {
+        return Err(vec![crate::Error::validate_custom_async_with_context(
+            meta_list,
+        )]);
+    }
+
+    let field_name = field.name();
+    let field_ident = field.ident();
+    let field_key = field.key();
+    let rename = rename_map.get(field_name).unwrap_or(&field_key);
+    let errors = field.errors_variable();
+    let (custom_fn_name, custom_fn) = extract_custom_fn(meta_list)?;
+
+    if !matches!(validate_trait, ValidateTrait::AsyncValidate) {
+        return Ok(quote!());
+    }
+
+    let custom_error = extract_custom_error(&custom_fn);
+    let custom_call = match custom_fn {
+        crate::types::NestedMeta::Closure(_) => quote!(
+            ::serde_valid::helpers::wrap_closure_async_validation(#field_ident, #custom_fn_name)
+        ),
+        _ => quote!(#custom_fn_name(#field_ident)),
+    };
+
+    Ok(quote!(
+        if let Err(__error) = #custom_call.await {
+            #errors
+                .entry(#rename)
+                .or_default()
+                .push(#custom_error);
+        };
+    ))
+}
+
+fn extract_custom_fn(
+    meta_list: &syn::MetaList,
+) -> Result<(TokenStream, crate::types::NestedMeta), crate::Errors> {
+    let path = &meta_list.path;
+    let path_ident = SingleIdentPath::new(path).ident();
     let nested = meta_list
         .parse_args_with(CommaSeparatedNestedMetas::parse_terminated)
         .map_err(|error| vec![crate::Error::custom_message_parse_error(path_ident, &error)])?;
@@ -34,17 +110,19 @@ pub fn extract_generic_custom_validator(
             .collect()),
     }?;
 
-    Ok(quote!(
-        if let Err(__error) = #custom_fn_name(#field_ident) {
-            #errors
-                .entry(#rename)
-                .or_default()
-                .push(__error);
-        };
-    ))
+    Ok((custom_fn_name, nested.into_iter().next().unwrap()))
+}
+
+/// Custom functions may return any error convertible into `serde_valid::validation::Error`,
+/// such as `serde_valid::CustomError`.
+fn extract_custom_error(custom_fn: &crate::types::NestedMeta) -> TokenStream {
+    match custom_fn {
+        crate::types::NestedMeta::Closure(_) => quote!(__error),
+        _ => quote!(::serde_valid::validation::Error::from(__error)),
+    }
 }
 
-fn extract_custom_fn_name(
+pub fn extract_custom_fn_name(
     nested_meta: &crate::types::NestedMeta,
 ) -> Result<TokenStream, crate::Errors> {
     match nested_meta {
@@ -44,7 +44,7 @@ fn inner_extract_generic_enumerate_validator(
     ))
 }
 
-fn get_enumerate(meta_list: &syn::MetaList) -> Result<Lits, crate::Errors> {
+fn get_enumerate(meta_list: &syn::MetaList) -> Result<Lits<'_>, crate::Errors> {
     let mut errors = vec![];
     let mut enumerate = Lits::new();
     let nested = meta_list
@@ -0,0 +1,136 @@
+use crate::attribute::common::message_format::MessageFormat;
+use crate::attribute::field_validate::field_reference::get_field_reference;
+use crate::attribute::{MetaNameValueFieldValidation, Validator};
+use crate::serde::rename::RenameMap;
+use crate::types::Field;
+use quote::quote;
+
+use std::str::FromStr;
+
+/// Whether the meta is `redact` of `#[validate(equals = field(password), redact)]`.
+pub fn is_redact_meta(meta: &syn::Meta) -> bool {
+    matches!(meta, syn::Meta::Path(path) if path.is_ident("redact"))
+}
+
+/// Comparison validation with `redact`, which does not record the values.
+pub fn extract_generic_redacted_field_comparison_validator(
+    field: &impl Field,
+    meta: &syn::Meta,
+    redact: &syn::Meta,
+    message_format: MessageFormat,
+    rename_map: &RenameMap,
+) -> Result<Validator, crate::Errors> {
+    if let syn::Meta::NameValue(validation) = meta {
+        if let Some(validation_type) = validation
+            .path
+            .get_ident()
+            .and_then(|ident| MetaNameValueFieldValidation::from_str(&ident.to_string()).ok())
+            .filter(is_field_comparison)
+        {
+            return extract_generic_field_comparison_validator(
+                field,
+                validation_type,
+                validation,
+                message_format,
+                rename_map,
+                true,
+            );
+        }
+    }
+
+    Err(vec![crate::Error::validate_redact_field_comparison_only(
+        redact,
+    )])
+}
+
+pub fn is_field_comparison(validation_type: &MetaNameValueFieldValidation) -> bool {
+    matches!(
+        validation_type,
+        MetaNameValueFieldValidation::Equals
+            | MetaNameValueFieldValidation::NotEquals
+            | MetaNameValueFieldValidation::LessThan
+            | MetaNameValueFieldValidation::LessThanOrEqual
+            | MetaNameValueFieldValidation::GreaterThan
+            | MetaNameValueFieldValidation::GreaterThanOrEqual
+    )
+}
+
+/// Comparison validation against another field, like `less_than_or_equal = field(end_date)`.
+///
+/// Both values are recorded in the error params unless `redact` is given.
+pub fn extract_generic_field_comparison_validator(
+    field: &impl Field,
+    validation_type: MetaNameValueFieldValidation,
+    validation: &syn::MetaNameValue,
+    message_format: MessageFormat,
+    rename_map: &RenameMap,
+    redact: bool,
+) -> Result<Validator, crate::Errors> {
+    let field_name = field.name();
+    let field_ident = field.ident();
+    let field_key = field.key();
+    let rename = rename_map.get(field_name).unwrap_or(&field_key);
+    let errors = field.errors_variable();
+
+    let other_ident = get_field_reference(&validation.value)?;
+    let other_name = other_ident.to_string();
+    let other_key = quote!(#other_name.to_string());
+    let other_rename = rename_map.get(&other_name).unwrap_or(&other_key);
+
+    let equality = quote!(::serde_valid::ValidateFieldEquality::validate_field_equality);
+    let ordering = quote!(::serde_valid::ValidateFieldOrdering::validate_field_ordering);
+    let (validate_fn, operator) = match validation_type {
+        MetaNameValueFieldValidation::Equals => (
+            equality,
+            quote!(::serde_valid::validation::EqualityOperator::Equals),
+        ),
+        MetaNameValueFieldValidation::NotEquals => (
+            equality,
+            quote!(::serde_valid::validation::EqualityOperator::NotEquals),
+        ),
+        MetaNameValueFieldValidation::LessThan => (
+            ordering,
+            quote!(::serde_valid::validation::OrderingOperator::LessThan),
+        ),
+        MetaNameValueFieldValidation::LessThanOrEqual => (
+            ordering,
+            quote!(::serde_valid::validation::OrderingOperator::LessThanOrEqual),
+        ),
+        MetaNameValueFieldValidation::GreaterThan => (
+            ordering,
+            quote!(::serde_valid::validation::OrderingOperator::GreaterThan),
+        ),
+        MetaNameValueFieldValidation::GreaterThanOrEqual => (
+            ordering,
+            quote!(::serde_valid::validation::OrderingOperator::GreaterThanOrEqual),
+        ),
+        _ => unreachable!(),
+    };
+
+    let with_values = if redact {
+        quote!()
+    } else {
+        quote!(.map_err(|error| error.with_values(#field_ident, #other_ident)))
+    };
+
+    Ok(quote!(
+        if let Err(error_params) = #validate_fn(
+            #field_ident,
+            #operator,
+            #other_rename,
+            #other_ident,
+        )#with_values {
+            use ::serde_valid::validation::error::FormatDefault;
+
+            #errors
+                .entry(#rename)
+                .or_default()
+                .push(::serde_valid::validation::Error::FieldComparison(Box::new(
+                    ::serde_valid::validation::error::Message::new(
+                        error_params,
+                        #message_format,
+                    )
+                )));
+        }
+    ))
+}
@@ -0,0 +1,35 @@
+use crate::attribute::common::message_format::MessageFormat;
+use crate::attribute::Validator;
+use crate::serde::rename::RenameMap;
+use crate::types::Field;
+use quote::quote;
+
+pub fn extract_generic_required_validator(
+    field: &impl Field,
+    message_format: MessageFormat,
+    rename_map: &RenameMap,
+) -> Validator {
+    let field_name = field.name();
+    let field_ident = field.ident();
+    let field_key = field.key();
+    let rename = rename_map.get(field_name).unwrap_or(&field_key);
+    let errors = field.errors_variable();
+
+    quote!(
+        if let Err(error_params) = ::serde_valid::ValidateRequired::validate_required(
+            #field_ident
+        ) {
+            use ::serde_valid::validation::error::FormatDefault;
+
+            #errors
+                .entry(#rename)
+                .or_default()
+                .push(::serde_valid::validation::Error::Required(
+                    ::serde_valid::validation::error::Message::new(
+                        error_params,
+                        #message_format,
+                    )
+                ));
+        }
+    )
+}
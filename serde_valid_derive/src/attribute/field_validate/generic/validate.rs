@@ -1,20 +1,31 @@
 use crate::attribute::Validator;
+use crate::serde::flatten::is_serde_flatten;
 use crate::serde::rename::RenameMap;
-use crate::types::Field;
+use crate::types::{Field, ValidateTrait};
+use proc_macro2::TokenStream;
 use quote::quote;
 
 pub fn extract_generic_validate_validator(
     field: &impl Field,
     rename_map: &RenameMap,
+    validate_trait: ValidateTrait,
 ) -> Result<Validator, crate::Errors> {
-    let field_ident = field.ident();
     let field_name = field.name();
     let field_key = field.key();
     let rename = rename_map.get(field_name).unwrap_or(&field_key);
     let errors = field.errors_variable();
+    let nested_validate = nested_validate_tokens(field, validate_trait);
+
+    if is_serde_flatten(field) {
+        return Ok(extract_flatten_validate_validator(
+            field,
+            rename,
+            validate_trait,
+        ));
+    }
 
     Ok(quote!(
-        if let Err(__inner_errors) = #field_ident.validate() {
+        if let Err(__inner_errors) = #nested_validate {
             match __inner_errors {
                 ::serde_valid::validation::Errors::Object(__object_errors) => {
                     #errors.entry(#rename).or_default().push(
@@ -33,3 +44,92 @@ pub fn extract_generic_validate_validator(
         }
     ))
 }
+
+/// `#[validate(nested_async)]` awaits the nested `AsyncValidate` in `AsyncValidate`,
+/// while `#[validate]` runs only the nested `Validate`.
+pub fn extract_generic_nested_async_validator(
+    field: &impl Field,
+    meta_list: &syn::MetaList,
+    rename_map: &RenameMap,
+    validate_trait: ValidateTrait,
+) -> Result<Validator, crate::Errors> {
+    if let ValidateTrait::ValidateWithContext(_) = validate_trait {
+        return Err(vec![crate::Error::validate_nested_async_with_context(
+            meta_list,
+        )]);
+    }
+
+    extract_generic_validate_validator(field, rename_map, validate_trait)
+}
+
+/// The errors of `#[serde(flatten)]` field are merged into the parent object,
+/// because the field key never appears in the serialized format.
+fn extract_flatten_validate_validator(
+    field: &impl Field,
+    rename: &TokenStream,
+    validate_trait: ValidateTrait,
+) -> Validator {
+    let errors = field.errors_variable();
+    let nested_validate = nested_validate_tokens(field, validate_trait);
+
+    quote!(
+        if let Err(__inner_errors) = #nested_validate {
+            match __inner_errors {
+                ::serde_valid::validation::Errors::Object(__object_errors) => {
+                    __rule_vec_errors.extend(__object_errors.errors);
+
+                    for (__property, __property_errors) in __object_errors.properties {
+                        let __property_vec_errors = #errors.entry(__property).or_default();
+                        match __property_errors {
+                            ::serde_valid::validation::Errors::Object(__object_errors) => {
+                                __property_vec_errors.push(
+                                    ::serde_valid::validation::Error::Properties(__object_errors)
+                                );
+                            }
+                            ::serde_valid::validation::Errors::Array(__array_errors) => {
+                                __property_vec_errors.push(
+                                    ::serde_valid::validation::Error::Items(__array_errors)
+                                );
+                            }
+                            ::serde_valid::validation::Errors::NewType(__new_type_errors) => {
+                                __property_vec_errors.extend(__new_type_errors);
+                            }
+                        }
+                    }
+                }
+                ::serde_valid::validation::Errors::Array(__array_errors) => {
+                    #errors.entry(#rename).or_default().push(
+                        ::serde_valid::validation::Error::Items(__array_errors)
+                    );
+                }
+                ::serde_valid::validation::Errors::NewType(__new_type_errors) => {
+                    __rule_vec_errors.extend(__new_type_errors);
+                }
+            }
+        }
+    )
+}
+
+/// Nested validation passes down the options, the error budget and the context.
+fn nested_validate_tokens(field: &impl Field, validate_trait: ValidateTrait) -> TokenStream {
+    let field_ident = field.ident();
+
+    match validate_trait {
+        ValidateTrait::ValidateWithContext(context) => quote!(
+            ::serde_valid::ValidateWithContext::<#context>::validate_with_context_and_budget(
+                #field_ident,
+                __context,
+                __options,
+                __budget,
+            )
+        ),
+        ValidateTrait::Validate => quote!({
+            use ::serde_valid::Validate as _;
+            #field_ident.validate_with_budget(__options, __budget)
+        }),
+        ValidateTrait::AsyncValidate => quote!({
+            use ::serde_valid::AsyncValidate as _;
+            #field_ident.validate_async_with_budget(__options, __budget).await
+        }),
+    }
+}
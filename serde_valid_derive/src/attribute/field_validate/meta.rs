@@ -2,8 +2,12 @@ mod meta_list;
 mod meta_name_value;
 mod meta_path;
 
+use crate::attribute::common::error_budget::{
+    wrap_error_budget_validator, wrap_nested_error_budget_validator,
+};
+use crate::attribute::common::groups::{extract_groups, is_groups_meta, wrap_groups_validator};
 use crate::attribute::common::message_format::{
-    default_message_format, extract_custom_message_format,
+    default_message_format, extract_custom_message_format, MessageFormat,
 };
 use crate::attribute::{
     MetaListFieldValidation, MetaNameValueFieldValidation, MetaPathFieldValidation, Validator,
@@ -11,21 +15,40 @@ use crate::attribute::{
 use crate::serde::rename::RenameMap;
 use crate::types::Field;
 use crate::types::SingleIdentPath;
+use crate::types::ValidateTrait;
 use meta_list::extract_field_validator_from_meta_list;
 use meta_name_value::extract_field_validator_from_meta_name_value;
 use meta_path::extract_field_validator_from_meta_path;
 use std::str::FromStr;
 
-use super::generic::extract_generic_validate_validator;
+use super::generic::{
+    extract_generic_nested_async_validator, extract_generic_redacted_field_comparison_validator,
+    extract_generic_validate_validator, is_redact_meta,
+};
 
 pub fn extract_field_validator(
     field: &impl Field,
     attribute: &syn::Attribute,
     rename_map: &RenameMap,
+    validate_trait: ValidateTrait,
 ) -> Result<Validator, crate::Errors> {
     match &attribute.meta {
-        syn::Meta::List(list) => inner_extract_field_validator(field, attribute, list, rename_map),
-        syn::Meta::Path(_) => extract_generic_validate_validator(field, rename_map),
+        syn::Meta::List(list) if is_nested_meta_list(list, "nested_async") => {
+            extract_generic_nested_async_validator(field, list, rename_map, validate_trait)
+                .map(wrap_nested_error_budget_validator)
+        }
+        syn::Meta::List(list) if is_nested_meta_list(list, "nested_without_context") => {
+            extract_generic_validate_validator(field, rename_map, ValidateTrait::Validate)
+                .map(wrap_nested_error_budget_validator)
+        }
+        syn::Meta::List(list) => {
+            inner_extract_field_validator(field, attribute, list, rename_map, validate_trait)
+                .map(|validator| wrap_error_budget_validator(validator, &field.errors_variable()))
+        }
+        syn::Meta::Path(_) => {
+            extract_generic_validate_validator(field, rename_map, validate_trait.without_async())
+                .map(wrap_nested_error_budget_validator)
+        }
         syn::Meta::NameValue(name_value) => {
             Err(vec![crate::Error::validate_meta_name_value_not_supported(
                 name_value,
@@ -34,53 +57,127 @@ pub fn extract_field_validator(
     }
 }
 
+/// Whether the attribute is `#[validate(nested_async)]` or `#[validate(nested_without_context)]`.
+fn is_nested_meta_list(meta_list: &syn::MetaList, name: &str) -> bool {
+    meta_list
+        .parse_args::<syn::Path>()
+        .is_ok_and(|path| path.is_ident(name))
+}
+
 fn inner_extract_field_validator(
     field: &impl Field,
     attribute: &syn::Attribute,
     meta_list: &syn::MetaList,
     rename_map: &RenameMap,
+    validate_trait: ValidateTrait,
 ) -> Result<Validator, crate::Errors> {
     let mut errors = vec![];
     let nested = meta_list
-        .parse_args_with(crate::types::CommaSeparatedMetas::parse_terminated)
+        .parse_args_with(crate::types::parse_comma_separated_keyword_metas)
         .map_err(|error| {
             vec![crate::Error::validate_attribute_parse_error(
                 attribute, &error,
             )]
         })?;
 
-    let message_format = match nested.len() {
-        0 => Err(vec![crate::Error::field_validation_type_required(
+    if nested.is_empty() {
+        Err(vec![crate::Error::field_validation_type_required(
             attribute,
-        )])?,
-        1 => None,
-        2 => match extract_custom_message_format(&nested[1]) {
-            Ok(custom_message) => {
-                if nested[0].path().is_ident("custom") {
-                    errors.push(
-                        crate::Error::validate_custom_does_not_support_custom_message(&nested[1]),
-                    );
-                    None
-                } else {
-                    Some(custom_message)
-                }
+        )])?
+    }
+
+    let mut message_format = None;
+    let mut groups = None;
+    let mut redact = None;
+    let mut has_message = false;
+    for meta in nested.iter().skip(1) {
+        if is_redact_meta(meta) {
+            if redact.is_some() {
+                errors.push(crate::Error::validate_redact_duplicated(meta));
             }
-            Err(message_fn_errors) => {
-                errors.extend(message_fn_errors);
-                None
+            redact = Some(meta);
+        } else if is_groups_meta(meta) {
+            if groups.is_some() {
+                errors.push(crate::Error::validate_groups_duplicated(meta));
+                continue;
             }
-        },
-        _ => {
-            for meta in nested.iter().skip(2) {
-                errors.push(crate::Error::too_many_list_items(meta));
+            match extract_groups(meta) {
+                Ok(group_names) => groups = Some(group_names),
+                Err(groups_errors) => {
+                    groups = Some(vec![]);
+                    errors.extend(groups_errors);
+                }
+            }
+        } else if has_message {
+            errors.push(crate::Error::too_many_list_items(meta));
+        } else {
+            has_message = true;
+            match extract_custom_message_format(meta) {
+                Ok(custom_message) => {
+                    if nested[0].path().is_ident("custom")
+                        || nested[0].path().is_ident("custom_async")
+                    {
+                        errors.push(
+                            crate::Error::validate_custom_does_not_support_custom_message(meta),
+                        );
+                    } else if nested[0].path().is_ident("when") {
+                        errors.push(crate::Error::validate_when_does_not_support_custom_message(
+                            meta,
+                        ));
+                    } else {
+                        message_format = Some(custom_message);
+                    }
+                }
+                Err(message_fn_errors) => {
+                    errors.extend(message_fn_errors);
+                }
             }
-            None
         }
     }
-    .unwrap_or_else(default_message_format);
+    let message_format = message_format.unwrap_or_else(default_message_format);
+
+    let validator = match redact {
+        Some(redact) => extract_generic_redacted_field_comparison_validator(
+            field,
+            &nested[0],
+            redact,
+            message_format,
+            rename_map,
+        ),
+        None => extract_field_validator_from_meta(
+            field,
+            &nested[0],
+            message_format,
+            rename_map,
+            validate_trait,
+        ),
+    };
 
-    let meta = &nested[0];
+    match validator {
+        Ok(validator) => {
+            if errors.is_empty() {
+                Ok(match groups {
+                    Some(groups) => wrap_groups_validator(validator, &groups),
+                    None => validator,
+                })
+            } else {
+                Err(errors)
+            }
+        }
+        Err(validator_errors) => {
+            errors.extend(validator_errors);
+            Err(errors)
+        }
+    }
+}
 
+pub fn extract_field_validator_from_meta(
+    field: &impl Field,
+    meta: &syn::Meta,
+    message_format: MessageFormat,
+    rename_map: &RenameMap,
+    validate_trait: ValidateTrait,
+) -> Result<Validator, crate::Errors> {
     let validation_path = match meta {
         syn::Meta::Path(path) => path,
         syn::Meta::List(list) => &list.path,
@@ -89,7 +186,7 @@ fn inner_extract_field_validator(
 
     let validation_name = SingleIdentPath::new(validation_path).ident().to_string();
 
-    let validator = match (
+    match (
         MetaPathFieldValidation::from_str(&validation_name),
         MetaListFieldValidation::from_str(&validation_name),
         MetaNameValueFieldValidation::from_str(&validation_name),
@@ -112,6 +209,7 @@ fn inner_extract_field_validator(
                 validation,
                 message_format,
                 rename_map,
+                validate_trait,
             )
         }
 
@@ -144,19 +242,5 @@ fn inner_extract_field_validator(
             validation_path,
             &validation_name,
         )]),
-    };
-
-    match validator {
-        Ok(validator) => {
-            if errors.is_empty() {
-                Ok(validator)
-            } else {
-                Err(errors)
-            }
-        }
-        Err(validator_errors) => {
-            errors.extend(validator_errors);
-            Err(errors)
-        }
     }
 }
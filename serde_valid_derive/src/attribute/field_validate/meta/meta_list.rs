@@ -1,11 +1,15 @@
 use crate::attribute::common::message_format::MessageFormat;
+use crate::attribute::field_validate::array::extract_array_contains_validator;
+use crate::attribute::field_validate::combinator::extract_combinator_validator;
+use crate::attribute::field_validate::conditional::extract_conditional_validator;
 use crate::attribute::field_validate::generic::{
-    extract_generic_custom_validator, extract_generic_enumerate_validator,
+    extract_generic_custom_async_validator, extract_generic_custom_validator,
+    extract_generic_enumerate_validator,
 };
 use crate::attribute::MetaListFieldValidation;
 use crate::attribute::Validator;
 use crate::serde::rename::RenameMap;
-use crate::types::Field;
+use crate::types::{Field, ValidateTrait};
 
 pub fn extract_field_validator_from_meta_list(
     field: &impl Field,
@@ -13,13 +17,49 @@ pub fn extract_field_validator_from_meta_list(
     validation: &syn::MetaList,
     message_format: MessageFormat,
     rename_map: &RenameMap,
+    validate_trait: ValidateTrait,
 ) -> Result<Validator, crate::Errors> {
     match validation_type {
         MetaListFieldValidation::Enumerate => {
             extract_generic_enumerate_validator(field, validation, message_format, rename_map)
         }
-        MetaListFieldValidation::Custom => {
-            extract_generic_custom_validator(field, validation, message_format, rename_map)
+        MetaListFieldValidation::Custom => extract_generic_custom_validator(
+            field,
+            validation,
+            message_format,
+            rename_map,
+            validate_trait,
+        ),
+        MetaListFieldValidation::CustomAsync => extract_generic_custom_async_validator(
+            field,
+            validation,
+            message_format,
+            rename_map,
+            validate_trait,
+        ),
+        MetaListFieldValidation::Contains => {
+            extract_array_contains_validator(field, validation, message_format, rename_map)
+        }
+        MetaListFieldValidation::AnyOf
+        | MetaListFieldValidation::AllOf
+        | MetaListFieldValidation::OneOf
+        | MetaListFieldValidation::Not => extract_combinator_validator(
+            field,
+            validation_type,
+            validation,
+            message_format,
+            rename_map,
+            validate_trait,
+        ),
+        MetaListFieldValidation::When | MetaListFieldValidation::RequiredIf => {
+            extract_conditional_validator(
+                field,
+                validation_type,
+                validation,
+                message_format,
+                rename_map,
+                validate_trait,
+            )
         }
     }
 }
@@ -3,6 +3,10 @@ use crate::attribute::common::message_format::MessageFormat;
 use crate::attribute::field_validate::array::{
     extract_array_max_items_validator, extract_array_min_items_validator,
 };
+use crate::attribute::field_validate::generic::{
+    extract_generic_const_validator, extract_generic_field_comparison_validator,
+    is_field_comparison,
+};
 use crate::attribute::field_validate::numeric::{
     extract_numeric_exclusive_maximum_validator, extract_numeric_exclusive_minimum_validator,
     extract_numeric_maximum_validator, extract_numeric_minimum_validator,
@@ -12,8 +16,8 @@ use crate::attribute::field_validate::object::{
     extract_object_max_properties_validator, extract_object_min_properties_validator,
 };
 use crate::attribute::field_validate::string::{
-    extract_string_max_length_validator, extract_string_min_length_validator,
-    extract_string_pattern_validator,
+    extract_string_format_validator, extract_string_max_length_validator,
+    extract_string_min_length_validator, extract_string_pattern_validator,
 };
 use crate::attribute::{MetaNameValueFieldValidation, Validator};
 use crate::serde::rename::RenameMap;
@@ -26,7 +30,18 @@ pub fn extract_field_validator_from_meta_name_value(
     message_format: MessageFormat,
     rename_map: &RenameMap,
 ) -> Result<Validator, crate::Errors> {
-    let validation_value = get_lit(&validation.value)?;
+    if is_field_comparison(&validation_type) {
+        return extract_generic_field_comparison_validator(
+            field,
+            validation_type,
+            validation,
+            message_format,
+            rename_map,
+            false,
+        );
+    }
+
+    let validation_value = &validation.value;
 
     match validation_type {
         MetaNameValueFieldValidation::Minimum => {
@@ -81,8 +96,29 @@ pub fn extract_field_validator_from_meta_name_value(
             message_format,
             rename_map,
         ),
-        MetaNameValueFieldValidation::Pattern => {
-            extract_string_pattern_validator(field, validation_value, message_format, rename_map)
-        }
+        MetaNameValueFieldValidation::Pattern => extract_string_pattern_validator(
+            field,
+            get_lit(validation_value)?,
+            message_format,
+            rename_map,
+        ),
+        MetaNameValueFieldValidation::Format => extract_string_format_validator(
+            field,
+            get_lit(validation_value)?,
+            message_format,
+            rename_map,
+        ),
+        MetaNameValueFieldValidation::Const => extract_generic_const_validator(
+            field,
+            get_lit(validation_value)?,
+            message_format,
+            rename_map,
+        ),
+        MetaNameValueFieldValidation::Equals
+        | MetaNameValueFieldValidation::NotEquals
+        | MetaNameValueFieldValidation::LessThan
+        | MetaNameValueFieldValidation::LessThanOrEqual
+        | MetaNameValueFieldValidation::GreaterThan
+        | MetaNameValueFieldValidation::GreaterThanOrEqual => unreachable!(),
     }
 }
@@ -1,5 +1,6 @@
 use crate::attribute::common::message_format::MessageFormat;
 use crate::attribute::field_validate::array::extract_array_unique_items_validator;
+use crate::attribute::field_validate::generic::extract_generic_required_validator;
 use crate::attribute::{MetaPathFieldValidation, Validator};
 use crate::serde::rename::RenameMap;
 use crate::types::Field;
@@ -17,5 +18,10 @@ pub fn extract_field_validator_from_meta_path(
             message_format,
             rename_map,
         )),
+        MetaPathFieldValidation::Required => Ok(extract_generic_required_validator(
+            field,
+            message_format,
+            rename_map,
+        )),
     }
 }
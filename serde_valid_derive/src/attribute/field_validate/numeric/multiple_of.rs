@@ -1,4 +1,4 @@
-use crate::attribute::common::lit::get_numeric;
+use crate::attribute::common::lit::get_numeric_limit;
 use crate::attribute::common::message_format::MessageFormat;
 use crate::attribute::Validator;
 use crate::serde::rename::RenameMap;
@@ -8,7 +8,7 @@ use quote::quote;
 
 pub fn extract_numeric_multiple_of_validator(
     field: &impl Field,
-    validation_value: &syn::Lit,
+    validation_value: &syn::Expr,
     message_format: MessageFormat,
     rename_map: &RenameMap,
 ) -> Result<Validator, crate::Errors> {
@@ -17,7 +17,7 @@ pub fn extract_numeric_multiple_of_validator(
 
 fn inner_extract_numeric_multiple_of_validator(
     field: &impl Field,
-    validation_value: &syn::Lit,
+    validation_value: &syn::Expr,
     message_format: MessageFormat,
     rename_map: &RenameMap,
 ) -> Result<TokenStream, crate::Errors> {
@@ -26,7 +26,7 @@ fn inner_extract_numeric_multiple_of_validator(
     let field_key = field.key();
     let rename = rename_map.get(field_name).unwrap_or(&field_key);
     let errors = field.errors_variable();
-    let multiple_of = get_numeric(validation_value)?;
+    let multiple_of = get_numeric_limit(validation_value)?;
 
     Ok(quote!(
         if let Err(__composited_error_params) = ::serde_valid::validation::ValidateCompositedMultipleOf::validate_composited_multiple_of(
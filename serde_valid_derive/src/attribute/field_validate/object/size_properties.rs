@@ -1,4 +1,4 @@
-use crate::attribute::common::lit::get_numeric;
+use crate::attribute::common::lit::get_numeric_limit;
 use crate::attribute::common::message_format::MessageFormat;
 use crate::attribute::Validator;
 use crate::serde::rename::RenameMap;
@@ -14,7 +14,7 @@ macro_rules! extract_object_size_validator {
         paste::paste! {
             pub fn [<extract_object_ $ErrorType:snake _validator>](
                 field: &impl Field,
-                validation_value: &syn::Lit,
+                validation_value: &syn::Expr,
                 message_format: MessageFormat,
                 rename_map: &RenameMap,
             ) -> Result<Validator, crate::Errors> {
@@ -23,7 +23,7 @@ macro_rules! extract_object_size_validator {
 
             fn [<inner_extract_object_ $ErrorType:snake _validator>](
                 field: &impl Field,
-                validation_value: &syn::Lit,
+                validation_value: &syn::Expr,
                 message_format: MessageFormat,
                 rename_map: &RenameMap,
             ) -> Result<TokenStream, crate::Errors> {
@@ -32,7 +32,7 @@ macro_rules! extract_object_size_validator {
                 let field_key = field.key();
                 let rename = rename_map.get(field_name).unwrap_or(&field_key);
                 let errors = field.errors_variable();
-                let [<$ErrorType:snake>] = get_numeric(validation_value)?;
+                let [<$ErrorType:snake>] = get_numeric_limit(validation_value)?;
 
                 Ok(quote!(
                     if let Err(__composited_error_params) = ::serde_valid::validation::[<ValidateComposited $ErrorType>]::[<validate_composited_ $ErrorType:snake>](
@@ -1,4 +1,6 @@
+mod format;
 mod length;
 mod pattern;
+pub use format::extract_string_format_validator;
 pub use length::{extract_string_max_length_validator, extract_string_min_length_validator};
 pub use pattern::extract_string_pattern_validator;
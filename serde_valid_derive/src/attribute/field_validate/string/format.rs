@@ -0,0 +1,66 @@
+use crate::attribute::common::lit::get_str;
+use crate::attribute::common::message_format::MessageFormat;
+use crate::attribute::{StringFormat, Validator};
+use crate::serde::rename::RenameMap;
+use crate::types::Field;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+pub fn extract_string_format_validator(
+    field: &impl Field,
+    validation_value: &syn::Lit,
+    message_format: MessageFormat,
+    rename_map: &RenameMap,
+) -> Result<Validator, crate::Errors> {
+    inner_extract_string_format_validator(field, validation_value, message_format, rename_map)
+}
+
+fn inner_extract_string_format_validator(
+    field: &impl Field,
+    validation_value: &syn::Lit,
+    message_format: MessageFormat,
+    rename_map: &RenameMap,
+) -> Result<TokenStream, crate::Errors> {
+    let field_name = field.name();
+    let field_ident = field.ident();
+    let field_key = field.key();
+    let rename = rename_map.get(field_name).unwrap_or(&field_key);
+    let errors = field.errors_variable();
+    let format = get_format(validation_value)?;
+
+    Ok(quote!(
+        if let Err(__composited_error_params) = ::serde_valid::validation::ValidateCompositedFormat::validate_composited_format(
+            #field_ident,
+            #format,
+        ) {
+            use ::serde_valid::validation::IntoError;
+            use ::serde_valid::validation::error::FormatDefault;
+
+            #errors
+                .entry(#rename)
+                .or_default()
+                .push(__composited_error_params.into_error_by(#message_format));
+        }
+    ))
+}
+
+fn get_format(lit: &syn::Lit) -> Result<TokenStream, crate::Errors> {
+    let lit_str = get_str(lit)?;
+    let format = lit_str
+        .value()
+        .parse::<StringFormat>()
+        .map_err(|_| vec![crate::Error::validate_format_unknown(lit_str)])?;
+
+    Ok(match format {
+        StringFormat::Email => quote!(::serde_valid::validation::StringFormat::Email),
+        StringFormat::Uri => quote!(::serde_valid::validation::StringFormat::Uri),
+        StringFormat::Uuid => quote!(::serde_valid::validation::StringFormat::Uuid),
+        StringFormat::Ipv4 => quote!(::serde_valid::validation::StringFormat::Ipv4),
+        StringFormat::Ipv6 => quote!(::serde_valid::validation::StringFormat::Ipv6),
+        StringFormat::Hostname => quote!(::serde_valid::validation::StringFormat::Hostname),
+        StringFormat::Date => quote!(::serde_valid::validation::StringFormat::Date),
+        StringFormat::Time => quote!(::serde_valid::validation::StringFormat::Time),
+        StringFormat::DateTime => quote!(::serde_valid::validation::StringFormat::DateTime),
+        StringFormat::Duration => quote!(::serde_valid::validation::StringFormat::Duration),
+    })
+}
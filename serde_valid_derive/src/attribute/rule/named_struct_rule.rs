@@ -4,6 +4,7 @@ use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
 use syn::spanned::Spanned;
 
+use crate::attribute::common::error_budget::wrap_error_budget_validator;
 use crate::types::{CommaSeparatedNestedMetas, CommaSeparatedTokenStreams};
 
 pub fn collect_rules_from_named_struct(
@@ -19,7 +20,10 @@ pub fn collect_rules_from_named_struct(
             syn::Meta::List(list) => match collect_rule(list) {
                 Ok((field_ident, stream)) => {
                     rule_fields.extend(field_ident);
-                    Some(stream)
+                    Some(wrap_error_budget_validator(
+                        stream,
+                        &quote!(__rule_vec_errors),
+                    ))
                 }
                 Err(rule_errors) => {
                     errors.extend(rule_errors);
@@ -128,7 +132,7 @@ fn extract_rule_from_meta_list(
         arg_idents,
         quote!(
             if let Err(__error) = #rule_fn_name(#rule_fn_args) {
-                __rule_vec_errors.push(__error);
+                __rule_vec_errors.push(::serde_valid::validation::Error::from(__error));
             };
         ),
     ))
@@ -1,12 +1,16 @@
 mod generic;
 mod meta;
 
+use crate::attribute::common::error_budget::wrap_error_budget_validator;
 use crate::attribute::Validator;
+use crate::types::ValidateTrait;
+use quote::quote;
 
 use self::meta::extract_struct_validator;
 
 pub fn collect_struct_custom_from_named_struct(
     attributes: &[syn::Attribute],
+    validate_trait: ValidateTrait,
 ) -> Result<Validator, crate::Errors> {
     let mut errors = vec![];
 
@@ -14,8 +18,11 @@ pub fn collect_struct_custom_from_named_struct(
         .iter()
         .filter_map(|attribute| {
             if attribute.path().is_ident("validate") {
-                match extract_struct_validator(attribute) {
-                    Ok(validator) => Some(validator),
+                match extract_struct_validator(attribute, validate_trait) {
+                    Ok(validator) => Some(wrap_error_budget_validator(
+                        validator,
+                        &quote!(__rule_vec_errors),
+                    )),
                     Err(validator_error) => {
                         errors.extend(validator_error);
                         None
@@ -33,3 +40,73 @@ pub fn collect_struct_custom_from_named_struct(
         Err(errors)
     }
 }
+
+/// Find the context type of `#[validate(context = MyContext)]`.
+pub fn find_struct_validate_context(
+    attributes: &[syn::Attribute],
+) -> Result<Option<syn::Type>, crate::Errors> {
+    let mut errors = vec![];
+    let mut context = None;
+
+    for attribute in attributes {
+        if !attribute.path().is_ident("validate") {
+            continue;
+        }
+        let Ok(nested) =
+            attribute.parse_args_with(crate::types::CommaSeparatedMetas::parse_terminated)
+        else {
+            continue;
+        };
+
+        for meta in nested.iter() {
+            if let syn::Meta::NameValue(name_value) = meta {
+                if name_value.path.is_ident("context") {
+                    if context.is_some() {
+                        errors.push(crate::Error::validate_context_duplicated(name_value));
+                        continue;
+                    }
+                    match get_context_type(&name_value.value) {
+                        Ok(context_type) => context = Some(context_type),
+                        Err(context_errors) => errors.extend(context_errors),
+                    }
+                }
+            }
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(context)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Whether the enum has `#[validate(externally_tagged)]`.
+pub fn has_enum_externally_tagged(attributes: &[syn::Attribute]) -> bool {
+    attributes
+        .iter()
+        .filter(|attribute| attribute.path().is_ident("validate"))
+        .filter_map(|attribute| {
+            attribute
+                .parse_args_with(crate::types::CommaSeparatedMetas::parse_terminated)
+                .ok()
+        })
+        .flatten()
+        .any(|meta| matches!(meta, syn::Meta::Path(path) if path.is_ident("externally_tagged")))
+}
+
+fn get_context_type(expr: &syn::Expr) -> Result<syn::Type, crate::Errors> {
+    match expr {
+        syn::Expr::Path(syn::ExprPath { qself, path, .. }) => Ok(syn::Type::Path(syn::TypePath {
+            qself: qself.clone(),
+            path: path.clone(),
+        })),
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(lit_str),
+            ..
+        }) => lit_str
+            .parse::<syn::Type>()
+            .map_err(|_| vec![crate::Error::validate_context_type_only(expr)]),
+        _ => Err(vec![crate::Error::validate_context_type_only(expr)]),
+    }
+}
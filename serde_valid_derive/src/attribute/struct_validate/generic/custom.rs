@@ -1,11 +1,12 @@
 use crate::attribute::common::message_format::MessageFormat;
 use crate::attribute::Validator;
-use crate::types::CommaSeparatedNestedMetas;
+use crate::types::{CommaSeparatedNestedMetas, ValidateTrait};
 use quote::quote;
 
 pub fn extract_generic_struct_custom_validator(
     meta_list: &syn::MetaList,
     _message_format: MessageFormat,
+    validate_trait: ValidateTrait,
 ) -> Result<Validator, crate::Errors> {
     let mut errors = vec![];
 
@@ -26,9 +27,11 @@ pub fn extract_generic_struct_custom_validator(
 
     let rule = match &nested[0] {
         crate::types::NestedMeta::Meta(syn::Meta::Path(path)) => {
-            extract_struct_custom_from_meta_path(path)
+            extract_struct_custom_from_meta_path(path, validate_trait)
+        }
+        crate::types::NestedMeta::Closure(closure) => {
+            extract_struct_custom_from_closure(closure, validate_trait)
         }
-        crate::types::NestedMeta::Closure(closure) => extract_struct_custom_from_closure(closure),
         _ => Err(vec![
             crate::Error::validate_custom_need_function_or_closure(&nested[0]),
         ]),
@@ -46,19 +49,35 @@ pub fn extract_generic_struct_custom_validator(
     }
 }
 
-fn extract_struct_custom_from_meta_path(meta_path: &syn::Path) -> Result<Validator, crate::Errors> {
+fn extract_struct_custom_from_meta_path(
+    meta_path: &syn::Path,
+    validate_trait: ValidateTrait,
+) -> Result<Validator, crate::Errors> {
     let rule_fn_name = &meta_path;
+    let context_arg = match validate_trait {
+        ValidateTrait::ValidateWithContext(_) => quote!(, __context),
+        ValidateTrait::Validate | ValidateTrait::AsyncValidate => quote!(),
+    };
 
     Ok(quote!(
-        if let Err(__error) = #rule_fn_name(self) {
-            __rule_vec_errors.push(__error);
+        if let Err(__error) = #rule_fn_name(self #context_arg) {
+            __rule_vec_errors.push(::serde_valid::validation::Error::from(__error));
         };
     ))
 }
 
 fn extract_struct_custom_from_closure(
     closure: &syn::ExprClosure,
+    validate_trait: ValidateTrait,
 ) -> Result<Validator, crate::Errors> {
+    if let ValidateTrait::ValidateWithContext(_) = validate_trait {
+        return Ok(quote!(
+            if let Err(__error) = serde_valid::helpers::wrap_closure_validation_with_context(self, __context, #closure) {
+                __rule_vec_errors.push(__error);
+            };
+        ));
+    }
+
     Ok(quote!(
         if let Err(__error) = serde_valid::helpers::wrap_closure_validation(self, #closure) {
             __rule_vec_errors.push(__error);
@@ -8,7 +8,7 @@ use crate::{
         MetaListStructValidation, MetaNameValueStructValidation, MetaPathStructValidation,
         Validator,
     },
-    types::SingleIdentPath,
+    types::{SingleIdentPath, ValidateTrait},
 };
 use quote::quote;
 use std::str::FromStr;
@@ -19,10 +19,13 @@ use self::{
     meta_path::extract_struct_validator_from_meta_path,
 };
 
-pub fn extract_struct_validator(attribute: &syn::Attribute) -> Result<Validator, crate::Errors> {
+pub fn extract_struct_validator(
+    attribute: &syn::Attribute,
+    validate_trait: ValidateTrait,
+) -> Result<Validator, crate::Errors> {
     match &attribute.meta {
         syn::Meta::Path(_) => Ok(quote!()),
-        syn::Meta::List(list) => inner_extract_struct_validator(attribute, list),
+        syn::Meta::List(list) => inner_extract_struct_validator(attribute, list, validate_trait),
         syn::Meta::NameValue(name_value) => {
             Err(vec![crate::Error::validate_meta_name_value_not_supported(
                 name_value,
@@ -34,6 +37,7 @@ pub fn extract_struct_validator(attribute: &syn::Attribute) -> Result<Validator,
 fn inner_extract_struct_validator(
     attribute: &syn::Attribute,
     meta_list: &syn::MetaList,
+    validate_trait: ValidateTrait,
 ) -> Result<Validator, crate::Errors> {
     let mut errors = vec![];
     let nested = meta_list
@@ -93,7 +97,12 @@ fn inner_extract_struct_validator(
         }
 
         (_, Ok(validation_type), _, syn::Meta::List(validation)) => {
-            extract_struct_validator_from_meta_list(validation_type, validation, message_format)
+            extract_struct_validator_from_meta_list(
+                validation_type,
+                validation,
+                message_format,
+                validate_trait,
+            )
         }
 
         (_, _, Ok(validation_type), syn::Meta::NameValue(validation)) => {
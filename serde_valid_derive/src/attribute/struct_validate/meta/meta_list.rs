@@ -3,15 +3,17 @@ use crate::attribute::{
     struct_validate::generic::extract_generic_struct_custom_validator, MetaListStructValidation,
     Validator,
 };
+use crate::types::ValidateTrait;
 
 pub fn extract_struct_validator_from_meta_list(
     validation_type: MetaListStructValidation,
     validation: &syn::MetaList,
     message_format: MessageFormat,
+    validate_trait: ValidateTrait,
 ) -> Result<Validator, crate::Errors> {
     match validation_type {
         MetaListStructValidation::Custom => {
-            extract_generic_struct_custom_validator(validation, message_format)
+            extract_generic_struct_custom_validator(validation, message_format, validate_trait)
         }
     }
 }
@@ -1,6 +1,7 @@
 use crate::attribute::{
     common::message_format::MessageFormat, MetaNameValueStructValidation, Validator,
 };
+use quote::quote;
 
 #[inline]
 pub fn extract_struct_validator_from_meta_name_value(
@@ -8,5 +9,8 @@ pub fn extract_struct_validator_from_meta_name_value(
     _validation: &syn::MetaNameValue,
     _message_format: MessageFormat,
 ) -> Result<Validator, crate::Errors> {
-    match validation_type {}
+    match validation_type {
+        // The context type is collected by `find_struct_validate_context`.
+        MetaNameValueStructValidation::Context => Ok(quote!()),
+    }
 }
@@ -5,8 +5,15 @@ use crate::attribute::{
 #[inline]
 pub fn extract_struct_validator_from_meta_path(
     validation_type: MetaPathStructValidation,
-    _validation: &syn::Path,
+    validation: &syn::Path,
     _message_format: MessageFormat,
 ) -> Result<Validator, crate::Errors> {
-    match validation_type {}
+    match validation_type {
+        // The enums collect it by `has_enum_externally_tagged`.
+        MetaPathStructValidation::ExternallyTagged => {
+            Err(vec![crate::Error::validate_externally_tagged_enum_only(
+                validation,
+            )])
+        }
+    }
 }
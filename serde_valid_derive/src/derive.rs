@@ -2,21 +2,310 @@ mod enum_derive;
 mod named_struct_derive;
 mod unnamed_struct_derive;
 
-use enum_derive::expand_enum_validate_derive;
-use named_struct_derive::expand_named_struct_derive;
+use crate::attribute::struct_validate::find_struct_validate_context;
+use crate::types::ValidateTrait;
+use enum_derive::expand_enum_validations;
+use named_struct_derive::expand_named_struct_validations;
 use proc_macro2::TokenStream;
-use unnamed_struct_derive::expand_unnamed_struct_derive;
+use quote::quote;
+use unnamed_struct_derive::expand_unnamed_struct_validations;
 
 pub fn expand_derive(input: &syn::DeriveInput) -> Result<TokenStream, crate::Errors> {
+    let context = find_struct_validate_context(&input.attrs);
+    let validations = expand_validations(
+        input,
+        ValidateTrait::new(context.as_ref().ok().and_then(Option::as_ref)),
+    );
+
+    match (context, validations) {
+        (Ok(Some(context)), Ok(validations)) => Ok(expand_validate_with_context_impl(
+            input,
+            &context,
+            validations,
+        )),
+        (Ok(None), Ok(validations)) => {
+            let async_validate_impl = if has_async_validations(input) {
+                // The errors are already reported by the `Validate` validations.
+                let async_validations = expand_validations(input, ValidateTrait::AsyncValidate)?;
+
+                expand_async_validate_impl(input, async_validations)
+            } else {
+                expand_trivial_async_validate_impl(input)
+            };
+            let validate_impl = expand_validate_impl(input, validations);
+
+            Ok(quote!(
+                #validate_impl
+                #async_validate_impl
+            ))
+        }
+        (context, validations) => Err(context
+            .err()
+            .into_iter()
+            .chain(validations.err())
+            .flatten()
+            .collect()),
+    }
+}
+
+fn expand_validations(
+    input: &syn::DeriveInput,
+    validate_trait: ValidateTrait,
+) -> Result<TokenStream, crate::Errors> {
     match &input.data {
         syn::Data::Struct(syn::DataStruct { ref fields, .. }) => match fields {
-            syn::Fields::Named(fields) => expand_named_struct_derive(input, fields),
-            syn::Fields::Unnamed(fields) => expand_unnamed_struct_derive(input, fields),
+            syn::Fields::Named(fields) => {
+                expand_named_struct_validations(input, fields, validate_trait)
+            }
+            syn::Fields::Unnamed(fields) => {
+                expand_unnamed_struct_validations(input, fields, validate_trait)
+            }
             syn::Fields::Unit => Err(vec![crate::Error::unit_struct_not_supported(input)]),
         },
         syn::Data::Enum(syn::DataEnum { variants, .. }) => {
-            expand_enum_validate_derive(input, variants)
+            expand_enum_validations(input, variants, validate_trait)
         }
         syn::Data::Union(_) => Err(vec![crate::Error::union_not_supported(input)]),
     }
 }
+
+/// Implement `ValidateWithContext<Context>` with `#[validate(context = Context)]`.
+///
+/// The `validations` can use `__options`, `__budget` and `__context`.
+fn expand_validate_with_context_impl(
+    input: &syn::DeriveInput,
+    context: &syn::Type,
+    validations: TokenStream,
+) -> TokenStream {
+    let ident = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    quote!(
+        impl #impl_generics ::serde_valid::ValidateWithContext<#context> for #ident #type_generics #where_clause {
+            fn validate_with_context_and_options(
+                &self,
+                __context: &#context,
+                __options: &::serde_valid::validation::ValidationOptions,
+            ) -> std::result::Result<(), ::serde_valid::validation::Errors> {
+                ::serde_valid::helpers::validate_with_error_budget(__options, |__budget| {
+                    ::serde_valid::ValidateWithContext::<#context>::validate_with_context_and_budget(
+                        self,
+                        __context,
+                        __options,
+                        __budget,
+                    )
+                })
+            }
+
+            #[allow(unused_variables)]
+            fn validate_with_context_and_budget(
+                &self,
+                __context: &#context,
+                __options: &::serde_valid::validation::ValidationOptions,
+                __budget: &::serde_valid::validation::ErrorBudget,
+            ) -> std::result::Result<(), ::serde_valid::validation::Errors> {
+                '__validation: {
+                    #validations
+                }
+            }
+        }
+    )
+}
+
+/// Implement `Validate`, and `ValidateWithContext<Context>` for any context.
+///
+/// The `validations` can use `__options` and `__budget`.
+fn expand_validate_impl(input: &syn::DeriveInput, validations: TokenStream) -> TokenStream {
+    let ident = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    let mut context_generics = input.generics.clone();
+    context_generics
+        .params
+        .push(syn::parse_quote!(__Context: ?Sized));
+    let (context_impl_generics, _, _) = context_generics.split_for_impl();
+
+    quote!(
+        impl #impl_generics ::serde_valid::Validate for #ident #type_generics #where_clause {
+            fn validate(&self) -> std::result::Result<(), ::serde_valid::validation::Errors> {
+                ::serde_valid::Validate::validate_with(
+                    self,
+                    &::serde_valid::validation::ValidationOptions::default(),
+                )
+            }
+
+            fn validate_with(
+                &self,
+                __options: &::serde_valid::validation::ValidationOptions,
+            ) -> std::result::Result<(), ::serde_valid::validation::Errors> {
+                ::serde_valid::helpers::validate_with_error_budget(__options, |__budget| {
+                    ::serde_valid::Validate::validate_with_budget(self, __options, __budget)
+                })
+            }
+
+            #[allow(unused_variables)]
+            fn validate_with_budget(
+                &self,
+                __options: &::serde_valid::validation::ValidationOptions,
+                __budget: &::serde_valid::validation::ErrorBudget,
+            ) -> std::result::Result<(), ::serde_valid::validation::Errors> {
+                '__validation: {
+                    #validations
+                }
+            }
+        }
+
+        impl #context_impl_generics ::serde_valid::ValidateWithContext<__Context> for #ident #type_generics #where_clause {
+            fn validate_with_context_and_options(
+                &self,
+                _context: &__Context,
+                options: &::serde_valid::validation::ValidationOptions,
+            ) -> std::result::Result<(), ::serde_valid::validation::Errors> {
+                ::serde_valid::Validate::validate_with(self, options)
+            }
+
+            fn validate_with_context_and_budget(
+                &self,
+                _context: &__Context,
+                options: &::serde_valid::validation::ValidationOptions,
+                budget: &::serde_valid::validation::ErrorBudget,
+            ) -> std::result::Result<(), ::serde_valid::validation::Errors> {
+                ::serde_valid::Validate::validate_with_budget(self, options, budget)
+            }
+        }
+    )
+}
+
+/// Implement `AsyncValidate` running only the `Validate` validations,
+/// so that every derived type can be awaited.
+fn expand_trivial_async_validate_impl(input: &syn::DeriveInput) -> TokenStream {
+    let ident = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    quote!(
+        impl #impl_generics ::serde_valid::AsyncValidate for #ident #type_generics #where_clause {}
+    )
+}
+
+/// Implement `AsyncValidate` with `#[validate(custom_async(...))]` or `#[validate(nested_async)]`.
+///
+/// The `async_validations` can use `__options` and `__budget`.
+fn expand_async_validate_impl(
+    input: &syn::DeriveInput,
+    async_validations: TokenStream,
+) -> TokenStream {
+    let ident = &input.ident;
+    let (_, type_generics, _) = input.generics.split_for_impl();
+
+    // The future borrows `self`, so the generic types must be `Sync`.
+    let mut async_generics = input.generics.clone();
+    if input.generics.type_params().next().is_some() {
+        async_generics
+            .make_where_clause()
+            .predicates
+            .push(syn::parse_quote!(#ident #type_generics: Sync));
+    }
+    for field_type in collect_generic_nested_async_types(input) {
+        async_generics
+            .make_where_clause()
+            .predicates
+            .push(syn::parse_quote!(#field_type: ::serde_valid::AsyncValidate));
+    }
+    let (impl_generics, _, where_clause) = async_generics.split_for_impl();
+
+    quote!(
+        impl #impl_generics ::serde_valid::AsyncValidate for #ident #type_generics #where_clause {
+            #[allow(unused_variables)]
+            fn validate_async_with_budget<'__a>(
+                &'__a self,
+                __options: &'__a ::serde_valid::validation::ValidationOptions,
+                __budget: &'__a ::serde_valid::validation::ErrorBudget,
+            ) -> ::serde_valid::ValidateFuture<'__a> {
+                Box::pin(async move {
+                    '__validation: {
+                        #async_validations
+                    }
+                })
+            }
+        }
+    )
+}
+
+/// Whether any field has `#[validate(custom_async(...))]` or `#[validate(nested_async)]`.
+fn has_async_validations(input: &syn::DeriveInput) -> bool {
+    collect_fields(input).into_iter().any(|field| {
+        field.attrs.iter().any(|attribute| {
+            is_field_validation(attribute, "custom_async")
+                || is_field_validation(attribute, "nested_async")
+        })
+    })
+}
+
+/// The generic types of `#[validate(nested_async)]`, such as `Vec<T>` of `#[validate(nested_async)] items: Vec<T>`.
+///
+/// They are bounded by `AsyncValidate`, because the type parameters are usually bounded only by `Validate`.
+fn collect_generic_nested_async_types(input: &syn::DeriveInput) -> Vec<&syn::Type> {
+    let type_params = input
+        .generics
+        .type_params()
+        .map(|param| &param.ident)
+        .collect::<Vec<_>>();
+    if type_params.is_empty() {
+        return vec![];
+    }
+
+    collect_fields(input)
+        .into_iter()
+        .filter(|field| {
+            field
+                .attrs
+                .iter()
+                .any(|attribute| is_field_validation(attribute, "nested_async"))
+        })
+        .map(|field| {
+            let mut field_type = &field.ty;
+            while let syn::Type::Reference(reference) = field_type {
+                field_type = &reference.elem;
+            }
+            field_type
+        })
+        .filter(|field_type| contains_any_ident(quote!(#field_type), &type_params))
+        .collect()
+}
+
+fn collect_fields(input: &syn::DeriveInput) -> Vec<&syn::Field> {
+    match &input.data {
+        syn::Data::Struct(data) => data.fields.iter().collect(),
+        syn::Data::Enum(data) => data
+            .variants
+            .iter()
+            .flat_map(|variant| variant.fields.iter())
+            .collect(),
+        syn::Data::Union(_) => vec![],
+    }
+}
+
+/// Whether the attribute is `#[validate(name...)]`.
+fn is_field_validation(attribute: &syn::Attribute, name: &str) -> bool {
+    let syn::Meta::List(list) = &attribute.meta else {
+        return false;
+    };
+    if !list.path.is_ident("validate") {
+        return false;
+    }
+
+    list.parse_args_with(crate::types::parse_comma_separated_keyword_metas)
+        .is_ok_and(|nested| {
+            nested
+                .first()
+                .is_some_and(|meta| meta.path().is_ident(name))
+        })
+}
+
+fn contains_any_ident(tokens: TokenStream, idents: &[&syn::Ident]) -> bool {
+    tokens.into_iter().any(|token| match token {
+        proc_macro2::TokenTree::Ident(ident) => idents.contains(&&ident),
+        proc_macro2::TokenTree::Group(group) => contains_any_ident(group.stream(), idents),
+        _ => false,
+    })
+}
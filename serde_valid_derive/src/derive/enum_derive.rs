@@ -1,9 +1,15 @@
 use super::named_struct_derive::collect_named_fields_validators_list;
 use super::unnamed_struct_derive::collect_unnamed_fields_validators_list;
+use crate::attribute::field_validate::collect_referenced_fields;
 use crate::attribute::rule::{collect_rules_from_named_struct, collect_rules_from_unnamed_struct};
+use crate::attribute::struct_validate::has_enum_externally_tagged;
 use crate::error::{array_errors_tokens, new_type_errors_tokens, object_errors_tokens};
-use crate::serde::rename::collect_serde_rename_map;
-use crate::types::CommaSeparatedTokenStreams;
+use crate::serde::rename::{
+    collect_serde_rename_map, find_serde_rename, find_serde_rename_all_rule,
+};
+use crate::serde::rename_rule::RenameRule;
+use crate::serde::tagging::{find_serde_enum_tagging, is_serde_untagged_variant, EnumTagging};
+use crate::types::{CommaSeparatedTokenStreams, ValidateTrait};
 use proc_macro2::TokenStream;
 use quote::quote;
 use std::collections::HashSet;
@@ -11,19 +17,32 @@ use std::iter::FromIterator;
 
 pub type Variants = syn::punctuated::Punctuated<syn::Variant, syn::token::Comma>;
 
-pub fn expand_enum_validate_derive(
+pub fn expand_enum_validations(
     input: &syn::DeriveInput,
     variants: &Variants,
+    validate_trait: ValidateTrait,
 ) -> Result<TokenStream, crate::Errors> {
     let ident = &input.ident;
-    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    let rename_all_fields = find_serde_rename_all_rule(&input.attrs, "rename_all_fields");
+    let enum_serde = EnumSerde {
+        tagging: find_serde_enum_tagging(&input.attrs),
+        externally_tagged: has_enum_externally_tagged(&input.attrs),
+        rename_all: find_serde_rename_all_rule(&input.attrs, "rename_all"),
+    };
 
     let mut errors = vec![];
 
     let validations_and_rules =
         TokenStream::from_iter(variants.iter().map(|variant| match &variant.fields {
             syn::Fields::Named(named_fields) => {
-                match expand_enum_variant_named_fields(ident, variant, named_fields) {
+                match expand_enum_variant_named_fields(
+                    ident,
+                    variant,
+                    named_fields,
+                    rename_all_fields,
+                    &enum_serde,
+                    validate_trait,
+                ) {
                     Ok(variant_varidates_and_rules) => variant_varidates_and_rules,
                     Err(variant_errors) => {
                         errors.extend(variant_errors);
@@ -32,8 +51,13 @@ pub fn expand_enum_validate_derive(
                 }
             }
             syn::Fields::Unnamed(unnamed_fields) => {
-                match expand_enum_variant_unnamed_fields_varidation(ident, variant, unnamed_fields)
-                {
+                match expand_enum_variant_unnamed_fields_varidation(
+                    ident,
+                    variant,
+                    unnamed_fields,
+                    &enum_serde,
+                    validate_trait,
+                ) {
                     Ok(variant_varidates_and_rules) => variant_varidates_and_rules,
                     Err(variant_errors) => {
                         errors.extend(variant_errors);
@@ -46,13 +70,9 @@ pub fn expand_enum_validate_derive(
 
     if errors.is_empty() {
         Ok(quote!(
-            impl #impl_generics ::serde_valid::Validate for #ident #type_generics #where_clause {
-                fn validate(&self) -> std::result::Result<(), ::serde_valid::validation::Errors> {
-                    #validations_and_rules
+            #validations_and_rules
 
-                    Ok(())
-                }
-            }
+            Ok(())
         ))
     } else {
         Err(errors)
@@ -63,46 +83,54 @@ fn expand_enum_variant_named_fields(
     ident: &syn::Ident,
     variant: &syn::Variant,
     named_fields: &syn::FieldsNamed,
+    rename_all_fields: Option<RenameRule>,
+    enum_serde: &EnumSerde,
+    validate_trait: ValidateTrait,
 ) -> Result<TokenStream, crate::Errors> {
     let mut errors = vec![];
 
     let variant_ident = &variant.ident;
     let mut fields_idents = CommaSeparatedTokenStreams::new();
-    let rename_map = collect_serde_rename_map(named_fields);
+    let rename_map = collect_serde_rename_map(
+        named_fields,
+        find_serde_rename_all_rule(&variant.attrs, "rename_all").or(rename_all_fields),
+    );
 
-    let (rule_fields, rules) = match collect_rules_from_named_struct(&variant.attrs) {
+    let (mut rule_fields, rules) = match collect_rules_from_named_struct(&variant.attrs) {
         Ok(field_rules) => field_rules,
         Err(variant_errors) => {
             errors.extend(variant_errors);
             (HashSet::new(), quote!())
         }
     };
+    rule_fields.extend(collect_referenced_fields(&named_fields.named));
 
-    let validates = match collect_named_fields_validators_list(named_fields, &rename_map) {
-        Ok(field_validators_list) => {
-            TokenStream::from_iter(field_validators_list.iter().map(|validators| {
-                let field_ident = validators.ident();
+    let validates =
+        match collect_named_fields_validators_list(named_fields, &rename_map, validate_trait) {
+            Ok(field_validators_list) => {
+                TokenStream::from_iter(field_validators_list.iter().map(|validators| {
+                    let field_ident = validators.ident();
 
-                if let Some(token) = validators.get_tokens() {
-                    fields_idents.push(quote!(#field_ident));
-                    quote!(#token)
-                } else {
-                    if rule_fields.contains(field_ident) {
+                    if let Some(token) = validators.get_tokens() {
                         fields_idents.push(quote!(#field_ident));
+                        quote!(#token)
                     } else {
-                        fields_idents.push(quote!(#field_ident: _));
+                        if rule_fields.contains(field_ident) {
+                            fields_idents.push(quote!(#field_ident));
+                        } else {
+                            fields_idents.push(quote!(#field_ident: _));
+                        }
+                        quote!()
                     }
-                    quote!()
-                }
-            }))
-        }
-        Err(fields_errors) => {
-            errors.extend(fields_errors);
-            quote!()
-        }
-    };
+                }))
+            }
+            Err(fields_errors) => {
+                errors.extend(fields_errors);
+                quote!()
+            }
+        };
 
-    let variant_errors = object_errors_tokens();
+    let variant_errors = enum_serde.variant_errors_tokens(variant, object_errors_tokens());
 
     if errors.is_empty() {
         Ok(quote!(
@@ -114,7 +142,7 @@ fn expand_enum_variant_named_fields(
                 #rules
 
                 if !(__rule_vec_errors.is_empty() && __property_vec_errors_map.is_empty()) {
-                    Err(#variant_errors)?
+                    break '__validation Err(#variant_errors);
                 }
             }
         ))
@@ -127,6 +155,8 @@ fn expand_enum_variant_unnamed_fields_varidation(
     ident: &syn::Ident,
     variant: &syn::Variant,
     unnamed_fields: &syn::FieldsUnnamed,
+    enum_serde: &EnumSerde,
+    validate_trait: ValidateTrait,
 ) -> Result<TokenStream, crate::Errors> {
     let mut errors = vec![];
 
@@ -141,7 +171,7 @@ fn expand_enum_variant_unnamed_fields_varidation(
         }
     };
 
-    let validates = match collect_unnamed_fields_validators_list(unnamed_fields) {
+    let validates = match collect_unnamed_fields_validators_list(unnamed_fields, validate_trait) {
         Ok(field_validators_list) => {
             TokenStream::from_iter(field_validators_list.iter().map(|validators| {
                 let field_ident = validators.ident();
@@ -165,11 +195,14 @@ fn expand_enum_variant_unnamed_fields_varidation(
         }
     };
 
-    let variant_errors = if unnamed_fields.unnamed.len() != 1 {
-        array_errors_tokens()
-    } else {
-        new_type_errors_tokens()
-    };
+    let variant_errors = enum_serde.variant_errors_tokens(
+        variant,
+        if unnamed_fields.unnamed.len() != 1 {
+            array_errors_tokens()
+        } else {
+            new_type_errors_tokens()
+        },
+    );
 
     if errors.is_empty() {
         Ok(quote!(
@@ -181,7 +214,7 @@ fn expand_enum_variant_unnamed_fields_varidation(
                 #rules
 
                 if !(__rule_vec_errors.is_empty() && __item_vec_errors_map.is_empty()) {
-                    Err(#variant_errors)?
+                    break '__validation Err(#variant_errors);
                 }
             }
         ))
@@ -189,3 +222,50 @@ fn expand_enum_variant_unnamed_fields_varidation(
         Err(errors)
     }
 }
+
+/// Serde representation of the enum, to locate the variant errors like the serialized format.
+///
+/// The externally tagged enums nest the errors under the variant name
+/// only with `#[validate(externally_tagged)]`, to keep the errors of the existing enums.
+struct EnumSerde {
+    tagging: EnumTagging,
+    externally_tagged: bool,
+    rename_all: Option<RenameRule>,
+}
+
+impl EnumSerde {
+    fn variant_errors_tokens(&self, variant: &syn::Variant, errors: TokenStream) -> TokenStream {
+        if is_serde_untagged_variant(&variant.attrs) {
+            return errors;
+        }
+
+        let key = match &self.tagging {
+            EnumTagging::External if self.externally_tagged => {
+                match find_serde_rename(&variant.attrs) {
+                    Some(rename) => rename.value(),
+                    None => {
+                        let variant_name = variant.ident.to_string();
+                        match self.rename_all {
+                            Some(rename_all) => rename_all.apply_to_variant(&variant_name),
+                            None => variant_name,
+                        }
+                    }
+                }
+            }
+            EnumTagging::Adjacent { content } => content.value(),
+            EnumTagging::External | EnumTagging::Internal | EnumTagging::Untagged => return errors,
+        };
+
+        quote!(
+            ::serde_valid::validation::Errors::Object(
+                ::serde_valid::validation::ObjectErrors::new(
+                    vec![],
+                    ::serde_valid::validation::PropertyErrorsMap::from_iter([(
+                        #key.to_string(),
+                        #errors,
+                    )]),
+                )
+            )
+        )
+    }
+}
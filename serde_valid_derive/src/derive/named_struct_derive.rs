@@ -1,22 +1,26 @@
-use crate::attribute::field_validate::{extract_field_validator, FieldValidators};
+use crate::attribute::field_validate::{
+    collect_referenced_fields, extract_field_validator, FieldValidators,
+};
 use crate::attribute::rule::collect_rules_from_named_struct;
 use crate::attribute::struct_validate::collect_struct_custom_from_named_struct;
 use crate::error::object_errors_tokens;
-use crate::serde::rename::{collect_serde_rename_map, RenameMap};
-use crate::types::{Field, NamedField};
+use crate::serde::rename::{collect_serde_rename_map, find_serde_rename_all_rule, RenameMap};
+use crate::types::{Field, NamedField, ValidateTrait};
 use proc_macro2::TokenStream;
 use quote::quote;
 use std::borrow::Cow;
 use std::collections::HashSet;
 use std::iter::FromIterator;
 
-pub fn expand_named_struct_derive(
+pub fn expand_named_struct_validations(
     input: &syn::DeriveInput,
     fields: &syn::FieldsNamed,
+    validate_trait: ValidateTrait,
 ) -> Result<TokenStream, crate::Errors> {
-    let ident = &input.ident;
-    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
-    let rename_map = collect_serde_rename_map(fields);
+    let rename_map = collect_serde_rename_map(
+        fields,
+        find_serde_rename_all_rule(&input.attrs, "rename_all"),
+    );
 
     let mut errors = vec![];
 
@@ -27,48 +31,56 @@ pub fn expand_named_struct_derive(
             (HashSet::new(), quote!())
         }
     };
-    let struct_validations = match collect_struct_custom_from_named_struct(&input.attrs) {
-        Ok(validations) => TokenStream::from_iter(validations),
-        Err(rule_errors) => {
-            errors.extend(rule_errors);
-            quote!()
-        }
-    };
+    let struct_validations =
+        match collect_struct_custom_from_named_struct(&input.attrs, validate_trait) {
+            Ok(validations) => TokenStream::from_iter(validations),
+            Err(rule_errors) => {
+                errors.extend(rule_errors);
+                quote!()
+            }
+        };
 
-    let field_validates = match collect_named_fields_validators_list(fields, &rename_map) {
-        Ok(field_validators) => TokenStream::from_iter(field_validators.iter().map(|validator| {
-            if validator.is_empty() && rule_fields.contains(validator.ident()) {
-                validator.get_field_variable_token()
-            } else {
-                validator.generate_tokens()
+    let referenced_fields = collect_referenced_fields(&fields.named);
+    let (referenced_variables, field_validates) =
+        match collect_named_fields_validators_list(fields, &rename_map, validate_trait) {
+            Ok(field_validators) => (
+                TokenStream::from_iter(
+                    field_validators
+                        .iter()
+                        .filter(|validator| referenced_fields.contains(validator.ident()))
+                        .map(|validator| validator.get_field_variable_token()),
+                ),
+                TokenStream::from_iter(field_validators.iter().map(|validator| {
+                    if validator.is_empty() && rule_fields.contains(validator.ident()) {
+                        validator.get_field_variable_token()
+                    } else {
+                        validator.generate_tokens()
+                    }
+                })),
+            ),
+            Err(validation_errors) => {
+                errors.extend(validation_errors);
+                (quote!(), quote!())
             }
-        })),
-        Err(validation_errors) => {
-            errors.extend(validation_errors);
-            quote!()
-        }
-    };
+        };
 
     let fields_errors = object_errors_tokens();
 
     if errors.is_empty() {
         Ok(quote!(
-            impl #impl_generics ::serde_valid::Validate for #ident #type_generics #where_clause {
-                fn validate(&self) -> std::result::Result<(), ::serde_valid::validation::Errors> {
-                    let mut __rule_vec_errors = ::serde_valid::validation::VecErrors::new();
-                    let mut __property_vec_errors_map = ::serde_valid::validation::PropertyVecErrorsMap::new();
+                let mut __rule_vec_errors = ::serde_valid::validation::VecErrors::new();
+                let mut __property_vec_errors_map = ::serde_valid::validation::PropertyVecErrorsMap::new();
 
-                    #field_validates
-                    #struct_validations
-                    #rules
+                #referenced_variables
+                #field_validates
+                #struct_validations
+                #rules
 
-                    if __rule_vec_errors.is_empty() && __property_vec_errors_map.is_empty() {
-                        Ok(())
-                    } else {
-                        Err(#fields_errors)
-                    }
+                if __rule_vec_errors.is_empty() && __property_vec_errors_map.is_empty() {
+                    Ok(())
+                } else {
+                    Err(#fields_errors)
                 }
-            }
         ))
     } else {
         Err(errors)
@@ -78,21 +90,22 @@ pub fn expand_named_struct_derive(
 pub fn collect_named_fields_validators_list<'a>(
     fields: &'a syn::FieldsNamed,
     rename_map: &RenameMap,
+    validate_trait: ValidateTrait,
 ) -> Result<Vec<FieldValidators<'a, NamedField<'a>>>, crate::Errors> {
     let mut errors = vec![];
 
     let validators = fields
         .named
         .iter()
-        .filter_map(
-            |field| match collect_named_field_validators(field, rename_map) {
+        .filter_map(|field| {
+            match collect_named_field_validators(field, rename_map, validate_trait) {
                 Ok(validators) => Some(validators),
                 Err(ref mut error) => {
                     errors.append(error);
                     None
                 }
-            },
-        )
+            }
+        })
         .collect();
 
     if errors.is_empty() {
@@ -105,6 +118,7 @@ pub fn collect_named_fields_validators_list<'a>(
 fn collect_named_field_validators<'a>(
     field: &'a syn::Field,
     rename_map: &RenameMap,
+    validate_trait: ValidateTrait,
 ) -> Result<FieldValidators<'a, NamedField<'a>>, crate::Errors> {
     let mut errors = vec![];
 
@@ -114,7 +128,7 @@ fn collect_named_field_validators<'a>(
         .iter()
         .filter_map(|attribute| {
             if attribute.path().is_ident("validate") {
-                match extract_field_validator(&named_field, attribute, rename_map) {
+                match extract_field_validator(&named_field, attribute, rename_map, validate_trait) {
                     Ok(validator) => Some(validator),
                     Err(validator_error) => {
                         errors.extend(validator_error);
@@ -2,20 +2,18 @@ use crate::attribute::field_validate::{extract_field_validator, FieldValidators}
 use crate::attribute::rule::collect_rules_from_unnamed_struct;
 use crate::attribute::struct_validate::collect_struct_custom_from_named_struct;
 use crate::error::{array_errors_tokens, new_type_errors_tokens};
-use crate::types::{Field, UnnamedField};
+use crate::types::{Field, UnnamedField, ValidateTrait};
 use proc_macro2::TokenStream;
 use quote::quote;
 use std::borrow::Cow;
 use std::collections::{HashMap, HashSet};
 use std::iter::FromIterator;
 
-pub fn expand_unnamed_struct_derive(
+pub fn expand_unnamed_struct_validations(
     input: &syn::DeriveInput,
     fields: &syn::FieldsUnnamed,
+    validate_trait: ValidateTrait,
 ) -> Result<TokenStream, crate::Errors> {
-    let ident = &input.ident;
-    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
-
     let mut errors = vec![];
 
     let (rule_fields, rules) = match collect_rules_from_unnamed_struct(&input.attrs) {
@@ -26,15 +24,16 @@ pub fn expand_unnamed_struct_derive(
         }
     };
 
-    let struct_validations = match collect_struct_custom_from_named_struct(&input.attrs) {
-        Ok(validations) => TokenStream::from_iter(validations),
-        Err(rule_errors) => {
-            errors.extend(rule_errors);
-            quote!()
-        }
-    };
+    let struct_validations =
+        match collect_struct_custom_from_named_struct(&input.attrs, validate_trait) {
+            Ok(validations) => TokenStream::from_iter(validations),
+            Err(rule_errors) => {
+                errors.extend(rule_errors);
+                quote!()
+            }
+        };
 
-    let field_validates = match collect_unnamed_fields_validators_list(fields) {
+    let field_validates = match collect_unnamed_fields_validators_list(fields, validate_trait) {
         Ok(field_validators) => TokenStream::from_iter(field_validators.iter().map(|validator| {
             if validator.is_empty() && rule_fields.contains(validator.ident()) {
                 validator.get_field_variable_token()
@@ -56,44 +55,43 @@ pub fn expand_unnamed_struct_derive(
 
     if errors.is_empty() {
         Ok(quote!(
-            impl #impl_generics ::serde_valid::Validate for #ident #type_generics #where_clause {
-                fn validate(&self) -> std::result::Result<(), ::serde_valid::validation::Errors> {
-                    let mut __rule_vec_errors = ::serde_valid::validation::VecErrors::new();
-                    let mut __item_vec_errors_map = ::serde_valid::validation::ItemVecErrorsMap::new();
-
-                    #field_validates
-                    #struct_validations
-                    #rules
-
-                    if __rule_vec_errors.is_empty() && __item_vec_errors_map.is_empty() {
-                        Ok(())
-                    } else {
-                        Err(#fields_errors)
-                    }
+                let mut __rule_vec_errors = ::serde_valid::validation::VecErrors::new();
+                let mut __item_vec_errors_map = ::serde_valid::validation::ItemVecErrorsMap::new();
+
+                #field_validates
+                #struct_validations
+                #rules
+
+                if __rule_vec_errors.is_empty() && __item_vec_errors_map.is_empty() {
+                    Ok(())
+                } else {
+                    Err(#fields_errors)
                 }
-            }
         ))
     } else {
         Err(errors)
     }
 }
 
-pub fn collect_unnamed_fields_validators_list(
-    fields: &syn::FieldsUnnamed,
-) -> Result<Vec<FieldValidators<UnnamedField>>, crate::Errors> {
+pub fn collect_unnamed_fields_validators_list<'a>(
+    fields: &'a syn::FieldsUnnamed,
+    validate_trait: ValidateTrait,
+) -> Result<Vec<FieldValidators<'a, UnnamedField<'a>>>, crate::Errors> {
     let mut errors = vec![];
 
     let validators = fields
         .unnamed
         .iter()
         .enumerate()
-        .filter_map(|field| match collect_unnamed_field_validators(field) {
-            Ok(validators) => Some(validators),
-            Err(ref mut error) => {
-                errors.append(error);
-                None
-            }
-        })
+        .filter_map(
+            |field| match collect_unnamed_field_validators(field, validate_trait) {
+                Ok(validators) => Some(validators),
+                Err(ref mut error) => {
+                    errors.append(error);
+                    None
+                }
+            },
+        )
         .collect();
 
     if !errors.is_empty() {
@@ -103,9 +101,10 @@ pub fn collect_unnamed_fields_validators_list(
     Ok(validators)
 }
 
-fn collect_unnamed_field_validators(
-    (index, field): (usize, &syn::Field),
-) -> Result<FieldValidators<UnnamedField>, crate::Errors> {
+fn collect_unnamed_field_validators<'a>(
+    (index, field): (usize, &'a syn::Field),
+    validate_trait: ValidateTrait,
+) -> Result<FieldValidators<'a, UnnamedField<'a>>, crate::Errors> {
     let mut errors = vec![];
 
     let unnamed_field = UnnamedField::new(index, field);
@@ -115,7 +114,12 @@ fn collect_unnamed_field_validators(
         .iter()
         .filter_map(|attribute| {
             if attribute.path().is_ident("validate") {
-                match extract_field_validator(&unnamed_field, attribute, &HashMap::new()) {
+                match extract_field_validator(
+                    &unnamed_field,
+                    attribute,
+                    &HashMap::new(),
+                    validate_trait,
+                ) {
                     Ok(validator) => Some(validator),
                     Err(validator_errors) => {
                         errors.extend(validator_errors);
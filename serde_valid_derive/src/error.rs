@@ -1,7 +1,7 @@
 use crate::attribute::{
     MetaListCustomMessage, MetaListFieldValidation, MetaListStructValidation,
     MetaNameValueCustomMessage, MetaNameValueFieldValidation, MetaNameValueStructValidation,
-    MetaPathCustomMessage, MetaPathFieldValidation, MetaPathStructValidation,
+    MetaPathCustomMessage, MetaPathFieldValidation, MetaPathStructValidation, StringFormat,
 };
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
@@ -24,7 +24,15 @@ pub fn object_errors_tokens() -> TokenStream {
                                 None
                             }
                             ::serde_valid::validation::Error::Properties(__object_errors) => {
-                                __field_properties_errors = Some(__object_errors);
+                                __field_properties_errors = match __field_properties_errors.take() {
+                                    Some(__field_properties_errors) => {
+                                        Some(::serde_valid::validation::ObjectErrors::merge(
+                                            __field_properties_errors,
+                                            __object_errors,
+                                        ))
+                                    }
+                                    None => Some(__object_errors),
+                                };
                                 None
                             }
                             _ => Some(error),
@@ -88,7 +96,15 @@ pub fn array_errors_tokens() -> TokenStream {
                                 None
                             }
                             ::serde_valid::validation::Error::Properties(__object_errors) => {
-                                __field_properties_errors = Some(__object_errors);
+                                __field_properties_errors = match __field_properties_errors.take() {
+                                    Some(__field_properties_errors) => {
+                                        Some(::serde_valid::validation::ObjectErrors::merge(
+                                            __field_properties_errors,
+                                            __object_errors,
+                                        ))
+                                    }
+                                    None => Some(__object_errors),
+                                };
                                 None
                             }
                             _ => Some(error),
@@ -367,6 +383,219 @@ impl Error {
         Self::new(path.span(), "#[validate(enumerate(???))] needs items.")
     }
 
+    pub fn validate_format_unknown(lit: &syn::LitStr) -> Self {
+        let unknown = lit.value();
+        let candidates = &StringFormat::iter().map(|x| x.name()).collect::<Vec<_>>();
+
+        let filterd_candidates =
+            did_you_mean(&unknown, candidates).unwrap_or_else(|| candidates.to_vec());
+
+        Self::new(
+            lit.span(),
+            format!("`{unknown}` is unknown format. Is it one of the following?\n{filterd_candidates:#?}"),
+        )
+    }
+
+    pub fn validate_contains_parse_error(meta_list: &syn::MetaList, error: &syn::Error) -> Self {
+        Self::new(
+            meta_list.span(),
+            format!("#[validate(contains(???))] parse error: {error}"),
+        )
+    }
+
+    pub fn validate_contains_need_custom(path: &syn::Path) -> Self {
+        Self::new(
+            path.span(),
+            "#[validate(contains(???))] needs `custom(...)` predicate.",
+        )
+    }
+
+    pub fn validate_contains_unknown_item(nested: &crate::types::NestedMeta) -> Self {
+        Self::new(
+            nested.span(),
+            "#[validate(contains(???))] allows only `custom(...)`, `min_contains = ...` and `max_contains = ...`.",
+        )
+    }
+
+    pub fn validate_combinator_parse_error(meta_list: &syn::MetaList, error: &syn::Error) -> Self {
+        Self::new(
+            meta_list.span(),
+            format!(
+                "#[validate({}(???))] parse error: {error}",
+                meta_list.path.to_token_stream()
+            ),
+        )
+    }
+
+    pub fn validate_combinator_need_item(path: &syn::Path) -> Self {
+        Self::new(
+            path.span(),
+            format!(
+                "#[validate({}(???))] needs validations.",
+                path.to_token_stream()
+            ),
+        )
+    }
+
+    pub fn validate_combinator_nested_validate(meta: &syn::Meta) -> Self {
+        Self::new(
+            meta.span(),
+            "#[validate(..., validate, ...)] nested validation is not supported in combinators.",
+        )
+    }
+
+    pub fn validate_combinator_custom_async(meta: &syn::Meta) -> Self {
+        Self::new(
+            meta.span(),
+            "#[validate(..., custom_async(...), ...)] async validation is not supported in combinators.",
+        )
+    }
+
+    pub fn validate_not_tail_error(meta: &syn::Meta) -> Self {
+        Self::new(meta.span(), "#[validate(not(???))] supports only 1 item.")
+    }
+
+    pub fn validate_conditional_parse_error(meta_list: &syn::MetaList, error: &syn::Error) -> Self {
+        Self::new(
+            meta_list.span(),
+            format!(
+                "#[validate({}(???))] parse error: {error}",
+                meta_list.path.to_token_stream()
+            ),
+        )
+    }
+
+    pub fn validate_conditional_need_field(path: &syn::Path) -> Self {
+        Self::new(
+            path.span(),
+            format!(
+                "#[validate({}(???))] needs `field = ...` condition.",
+                path.to_token_stream()
+            ),
+        )
+    }
+
+    pub fn validate_conditional_need_equals(path: &syn::Path) -> Self {
+        Self::new(
+            path.span(),
+            format!(
+                "#[validate({}(???))] needs `equals = ...` condition.",
+                path.to_token_stream()
+            ),
+        )
+    }
+
+    pub fn validate_conditional_field_name_only(expr: &syn::Expr) -> Self {
+        Self::new(
+            expr.span(),
+            "#[validate(..., field = ???)] allows only field name or str literal.",
+        )
+    }
+
+    pub fn validate_conditional_unknown_item(path: &syn::Path, meta: &syn::Meta) -> Self {
+        let candidates = if path.is_ident("when") {
+            "`field = ...`, `equals = ...`, `then(...)` and `else(...)`"
+        } else {
+            "`field = ...` and `equals = ...`"
+        };
+
+        Self::new(
+            meta.span(),
+            format!(
+                "#[validate({}(???))] allows only {candidates}.",
+                path.to_token_stream()
+            ),
+        )
+    }
+
+    pub fn validate_field_reference_only(expr: &syn::Expr) -> Self {
+        Self::new(expr.span(), "Allow `field(other_field)` only.")
+    }
+
+    pub fn validate_when_need_then(path: &syn::Path) -> Self {
+        Self::new(
+            path.span(),
+            "#[validate(when(???))] needs `then(...)` validations.",
+        )
+    }
+
+    pub fn validate_groups_parse_error(meta_list: &syn::MetaList, error: &syn::Error) -> Self {
+        Self::new(
+            meta_list.span(),
+            format!("#[validate(..., groups(???))] parse error: {error}"),
+        )
+    }
+
+    pub fn validate_groups_need_items(meta: &syn::Meta) -> Self {
+        Self::new(
+            meta.span(),
+            "#[validate(..., groups(???))] needs group names.",
+        )
+    }
+
+    pub fn validate_groups_allow_name_only(nested_meta: &crate::types::NestedMeta) -> Self {
+        Self::new(
+            nested_meta.span(),
+            "#[validate(..., groups(???))] allows only group name or str literal.",
+        )
+    }
+
+    pub fn validate_redact_duplicated(meta: &syn::Meta) -> Self {
+        Self::new(
+            meta.span(),
+            "#[validate(..., redact, ???)] redact is already specified.",
+        )
+    }
+
+    pub fn validate_redact_field_comparison_only(meta: &syn::Meta) -> Self {
+        Self::new(
+            meta.span(),
+            "#[validate(..., redact)] is only supported for the field comparisons, like `equals = field(...)`.",
+        )
+    }
+
+    pub fn validate_groups_duplicated(meta: &syn::Meta) -> Self {
+        Self::new(
+            meta.span(),
+            "#[validate(..., groups(...), ???)] groups are already specified.",
+        )
+    }
+
+    pub fn validate_context_type_only(expr: &syn::Expr) -> Self {
+        Self::new(
+            expr.span(),
+            "#[validate(context = ???)] allows only type path or str literal.",
+        )
+    }
+
+    pub fn validate_context_duplicated(name_value: &syn::MetaNameValue) -> Self {
+        Self::new(
+            name_value.span(),
+            "#[validate(context = ???)] context is already specified.",
+        )
+    }
+
+    pub fn validate_externally_tagged_enum_only(path: &syn::Path) -> Self {
+        Self::new(
+            path.span(),
+            "#[validate(externally_tagged)] is only supported for enums.",
+        )
+    }
+
+    pub fn validate_custom_async_with_context(meta_list: &syn::MetaList) -> Self {
+        Self::new(
+            meta_list.span(),
+            "#[validate(custom_async(...))] is not supported with #[validate(context = ...)].",
+        )
+    }
+
+    pub fn validate_nested_async_with_context(meta_list: &syn::MetaList) -> Self {
+        Self::new(
+            meta_list.span(),
+            "#[validate(nested_async)] is not supported with #[validate(context = ...)].",
+        )
+    }
+
     pub fn validate_custom_need_function_or_closure(span: impl Spanned) -> Self {
         Self::new(
             span.span(),
@@ -453,6 +682,13 @@ impl Error {
         Self::new(lit.span(), "Allow numeric literal only.")
     }
 
+    pub fn numeric_limit_only(expr: &syn::Expr) -> Self {
+        Self::new(
+            expr.span(),
+            "Allow numeric literal, const expression, or getter function only.",
+        )
+    }
+
     pub fn str_literal_only(lit: &syn::Lit) -> Self {
         Self::new(lit.span(), "Allow str literal only.")
     }
@@ -478,6 +714,13 @@ impl Error {
             "#[validate(custon(...), ???)] does not support custom error message.",
         )
     }
+
+    pub fn validate_when_does_not_support_custom_message(meta: &syn::Meta) -> Self {
+        Self::new(
+            meta.span(),
+            "#[validate(when(...), ???)] does not support custom error message.",
+        )
+    }
 }
 
 fn did_you_mean<'a, T, I>(unknown: &'a str, candidates: I) -> Option<Vec<&'a str>>
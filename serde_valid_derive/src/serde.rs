@@ -1 +1,4 @@
+pub mod flatten;
 pub mod rename;
+pub mod rename_rule;
+pub mod tagging;
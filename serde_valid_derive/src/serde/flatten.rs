@@ -0,0 +1,22 @@
+use crate::types::{CommaSeparatedMetas, Field};
+
+/// Whether the field has `#[serde(flatten)]`.
+pub fn is_serde_flatten(field: &impl Field) -> bool {
+    field.attrs().iter().any(|attribute| {
+        if !attribute.path().is_ident("serde") {
+            return false;
+        }
+        let syn::Meta::List(serde_list) = &attribute.meta else {
+            return false;
+        };
+
+        serde_list
+            .parse_args_with(CommaSeparatedMetas::parse_terminated)
+            .map(|serde_metas| {
+                serde_metas
+                    .iter()
+                    .any(|serde_meta| matches!(serde_meta, syn::Meta::Path(path) if path.is_ident("flatten")))
+            })
+            .unwrap_or(false)
+    })
+}
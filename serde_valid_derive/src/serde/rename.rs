@@ -1,36 +1,90 @@
 use std::collections::HashMap;
 
+use super::rename_rule::RenameRule;
 use crate::types::{CommaSeparatedMetas, Field, NamedField};
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
+use syn::ext::IdentExt;
 
 pub type RenameMap = HashMap<String, TokenStream>;
 
-pub fn collect_serde_rename_map(fields: &syn::FieldsNamed) -> RenameMap {
+/// Collect the serde names of the fields.
+///
+/// `rename_all` is the container rule, like `#[serde(rename_all = "camelCase")]`.
+/// A field level `#[serde(rename = "...")]` takes precedence over it.
+pub fn collect_serde_rename_map(
+    fields: &syn::FieldsNamed,
+    rename_all: Option<RenameRule>,
+) -> RenameMap {
     let mut renames = RenameMap::new();
     for field in fields.named.iter() {
         let named_field = NamedField::new(field);
-        for attribute in named_field.attrs() {
-            if attribute.path().is_ident("serde") {
-                if let Some(rename) = find_rename_from_serde_attributes(attribute) {
-                    renames.insert(
-                        field.ident.to_token_stream().to_string(),
-                        quote!(#rename.to_string()),
-                    );
-                }
-            }
+        let field_name = field.ident.to_token_stream().to_string();
+
+        if let Some(rename_all) = rename_all {
+            let rename = rename_all.apply_to_field(&named_field.ident().unraw().to_string());
+            renames.insert(field_name.clone(), quote!(#rename.to_string()));
+        }
+
+        if let Some(rename) = find_serde_rename(named_field.attrs()) {
+            renames.insert(field_name.clone(), quote!(#rename.to_string()));
         }
     }
     renames
 }
 
-fn find_rename_from_serde_attributes(attribute: &syn::Attribute) -> Option<TokenStream> {
+/// Find the rule of `#[serde(rename_all = "...")]` like container attributes.
+///
+/// `key` is `rename_all` or `rename_all_fields`.
+/// For `#[serde(rename_all(serialize = "...", deserialize = "..."))]`, the deserialize rule is used.
+pub fn find_serde_rename_all_rule(attrs: &[syn::Attribute], key: &str) -> Option<RenameRule> {
+    for attribute in attrs {
+        if !attribute.path().is_ident("serde") {
+            continue;
+        }
+        let syn::Meta::List(serde_list) = &attribute.meta else {
+            continue;
+        };
+        let Ok(serde_nested_meta) =
+            serde_list.parse_args_with(CommaSeparatedMetas::parse_terminated)
+        else {
+            continue;
+        };
+
+        for serde_meta in serde_nested_meta {
+            if !serde_meta.path().is_ident(key) {
+                continue;
+            }
+            if let Some(rule) = find_deserialize_lit_str(&serde_meta)
+                .and_then(|lit_str| lit_str.value().parse::<RenameRule>().ok())
+            {
+                return Some(rule);
+            }
+        }
+    }
+    None
+}
+
+/// Find the name of `#[serde(rename = "...")]`.
+///
+/// For `#[serde(rename(serialize = "...", deserialize = "..."))]`, the deserialize name is used.
+pub fn find_serde_rename(attrs: &[syn::Attribute]) -> Option<syn::LitStr> {
+    attrs
+        .iter()
+        .filter(|attribute| attribute.path().is_ident("serde"))
+        .find_map(find_rename_from_serde_attributes)
+}
+
+fn find_rename_from_serde_attributes(attribute: &syn::Attribute) -> Option<syn::LitStr> {
     if let syn::Meta::List(serde_list) = &attribute.meta {
         if let Ok(serde_nested_meta) =
             serde_list.parse_args_with(CommaSeparatedMetas::parse_terminated)
         {
             for serde_meta in serde_nested_meta {
-                if let Some(rename) = find_rename_from_serde_rename_attributes(&serde_meta) {
+                if !serde_meta.path().is_ident("rename") {
+                    continue;
+                }
+                if let Some(rename) = find_deserialize_lit_str(&serde_meta) {
                     return Some(rename);
                 }
             }
@@ -39,32 +93,32 @@ fn find_rename_from_serde_attributes(attribute: &syn::Attribute) -> Option<Token
     None
 }
 
-fn find_rename_from_serde_rename_attributes(serde_meta: &syn::Meta) -> Option<TokenStream> {
+fn find_deserialize_lit_str(serde_meta: &syn::Meta) -> Option<syn::LitStr> {
     match serde_meta {
-        syn::Meta::NameValue(rename_name_value) => {
+        syn::Meta::NameValue(name_value) => {
             if let syn::Expr::Lit(syn::ExprLit {
                 lit: syn::Lit::Str(lit_str),
                 ..
-            }) = &rename_name_value.value
+            }) = &name_value.value
             {
-                Some(lit_str.to_token_stream())
+                Some(lit_str.clone())
             } else {
                 None
             }
         }
-        syn::Meta::List(rename_list) => {
-            if let Ok(nested) = rename_list.parse_args_with(CommaSeparatedMetas::parse_terminated) {
-                for rename_meta in nested {
-                    if !rename_meta.path().is_ident("deserialize") {
+        syn::Meta::List(list) => {
+            if let Ok(nested) = list.parse_args_with(CommaSeparatedMetas::parse_terminated) {
+                for meta in nested {
+                    if !meta.path().is_ident("deserialize") {
                         continue;
                     }
-                    if let syn::Meta::NameValue(deserialize_name_value) = rename_meta {
+                    if let syn::Meta::NameValue(deserialize_name_value) = meta {
                         if let syn::Expr::Lit(syn::ExprLit {
                             lit: syn::Lit::Str(lit_str),
                             ..
                         }) = &deserialize_name_value.value
                         {
-                            return Some(lit_str.to_token_stream());
+                            return Some(lit_str.clone());
                         }
                     }
                 }
@@ -0,0 +1,66 @@
+use crate::types::CommaSeparatedMetas;
+
+/// The enum representations of serde.
+///
+/// See <https://serde.rs/enum-representations.html>
+pub enum EnumTagging {
+    /// `{"Variant": {...}}`
+    External,
+    /// `{"tag": "Variant", ...}`
+    Internal,
+    /// `{"tag": "Variant", "content": {...}}`
+    Adjacent { content: syn::LitStr },
+    /// `{...}`
+    Untagged,
+}
+
+pub fn find_serde_enum_tagging(attrs: &[syn::Attribute]) -> EnumTagging {
+    let mut tag = None;
+    let mut content = None;
+
+    for serde_meta in serde_metas(attrs) {
+        match &serde_meta {
+            syn::Meta::Path(path) if path.is_ident("untagged") => return EnumTagging::Untagged,
+            syn::Meta::NameValue(name_value) if name_value.path.is_ident("tag") => {
+                tag = Some(());
+            }
+            syn::Meta::NameValue(name_value) if name_value.path.is_ident("content") => {
+                if let syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(lit_str),
+                    ..
+                }) = &name_value.value
+                {
+                    content = Some(lit_str.clone());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    match (tag, content) {
+        (Some(_), Some(content)) => EnumTagging::Adjacent { content },
+        (Some(_), None) => EnumTagging::Internal,
+        _ => EnumTagging::External,
+    }
+}
+
+/// Whether the variant has `#[serde(untagged)]`.
+pub fn is_serde_untagged_variant(attrs: &[syn::Attribute]) -> bool {
+    serde_metas(attrs)
+        .iter()
+        .any(|serde_meta| matches!(serde_meta, syn::Meta::Path(path) if path.is_ident("untagged")))
+}
+
+fn serde_metas(attrs: &[syn::Attribute]) -> Vec<syn::Meta> {
+    attrs
+        .iter()
+        .filter(|attribute| attribute.path().is_ident("serde"))
+        .filter_map(|attribute| match &attribute.meta {
+            syn::Meta::List(serde_list) => serde_list
+                .parse_args_with(CommaSeparatedMetas::parse_terminated)
+                .ok(),
+            _ => None,
+        })
+        .flatten()
+        .collect()
+}
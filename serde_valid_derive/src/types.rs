@@ -1,11 +1,15 @@
 mod field;
+mod keyword_meta;
 mod nested_meta;
 mod single_ident_path;
+mod validate_trait;
 
 pub use field::{Field, NamedField, UnnamedField};
+pub use keyword_meta::parse_comma_separated_keyword_metas;
 pub use nested_meta::NestedMeta;
 use proc_macro2::TokenStream;
 pub use single_ident_path::SingleIdentPath;
+pub use validate_trait::ValidateTrait;
 
 pub type CommaSeparatedTokenStreams = syn::punctuated::Punctuated<TokenStream, syn::token::Comma>;
 pub type CommaSeparatedNestedMetas = syn::punctuated::Punctuated<NestedMeta, syn::token::Comma>;
@@ -16,8 +16,4 @@ pub trait Field {
     fn getter_token(&self) -> proc_macro2::TokenStream;
 
     fn attrs(&self) -> &Vec<syn::Attribute>;
-
-    fn vis(&self) -> &syn::Visibility;
-
-    fn ty(&self) -> &syn::Type;
 }
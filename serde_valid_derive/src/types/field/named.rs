@@ -48,12 +48,4 @@ impl<'a> Field for NamedField<'a> {
     fn attrs(&self) -> &Vec<syn::Attribute> {
         self.field.attrs.as_ref()
     }
-
-    fn vis(&self) -> &syn::Visibility {
-        &self.field.vis
-    }
-
-    fn ty(&self) -> &syn::Type {
-        &self.field.ty
-    }
 }
@@ -0,0 +1,35 @@
+use syn::ext::IdentExt;
+use syn::parse::ParseStream;
+
+/// Parse `syn::Meta` allowing Rust keywords (e.g. `const`) as the validation name.
+pub fn parse_keyword_meta(input: ParseStream) -> syn::Result<syn::Meta> {
+    if input.peek(syn::Ident) {
+        return input.parse();
+    }
+
+    let path = syn::Path::from(syn::Ident::parse_any(input)?);
+
+    if input.peek(syn::Token![=]) {
+        Ok(syn::Meta::NameValue(syn::MetaNameValue {
+            path,
+            eq_token: input.parse()?,
+            value: input.parse()?,
+        }))
+    } else if input.peek(syn::token::Paren) {
+        let content;
+        let paren_token = syn::parenthesized!(content in input);
+        Ok(syn::Meta::List(syn::MetaList {
+            path,
+            delimiter: syn::MacroDelimiter::Paren(paren_token),
+            tokens: content.parse()?,
+        }))
+    } else {
+        Ok(syn::Meta::Path(path))
+    }
+}
+
+pub fn parse_comma_separated_keyword_metas(
+    input: ParseStream,
+) -> syn::Result<super::CommaSeparatedMetas> {
+    super::CommaSeparatedMetas::parse_terminated_with(input, parse_keyword_meta)
+}
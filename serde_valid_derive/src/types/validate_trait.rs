@@ -0,0 +1,24 @@
+/// The trait whose validation body is being generated.
+#[derive(Debug, Clone, Copy)]
+pub enum ValidateTrait<'a> {
+    Validate,
+    ValidateWithContext(&'a syn::Type),
+    AsyncValidate,
+}
+
+impl<'a> ValidateTrait<'a> {
+    pub fn new(context: Option<&'a syn::Type>) -> Self {
+        match context {
+            Some(context) => Self::ValidateWithContext(context),
+            None => Self::Validate,
+        }
+    }
+
+    /// The trait of `#[validate]`, which does not await the nested validations.
+    pub fn without_async(self) -> Self {
+        match self {
+            Self::AsyncValidate => Self::Validate,
+            _ => self,
+        }
+    }
+}